@@ -0,0 +1,167 @@
+//! Benchmarks comparing access by type against direct field access.
+//!
+//! Each group measures reading and updating the first, middle and last field
+//! of tuples of increasing arity, both through [`TupleSet`] and through the
+//! positional field. Run them with `cargo bench`.
+//!
+//! The `lookup_64` group compares the checked accessors, which locate the
+//! field in a single pass bailing out on a second match, against counting the
+//! occurrences first and then searching the field again, on a 64-tuple with
+//! the target type last, duplicated or missing. Optimized builds fold the
+//! `TypeId` comparisons either way, so the gap shows in unoptimized builds,
+//! e.g. with `cargo bench --profile dev -- lookup_64`.
+//!
+//! The `#[inline(never)]` functions at the bottom of this file isolate the
+//! same accesses for codegen comparisons, e.g. with
+//! `cargo asm --bench access access::by_type_last` against
+//! `access::by_position_last`: in optimized builds the two should compile to
+//! the same instructions.
+
+use std::hint::black_box;
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use tuple_set::TupleSet;
+
+/// Field type made distinct per position by its const parameter.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct F<const N: usize>(pub u64);
+
+// Defines a tuple of distinct field types and its constructor
+macro_rules! define_arity {
+    ($Tuple:ident, $new:ident => $($n:literal),+) => {
+        type $Tuple = ($(F<$n>,)+);
+
+        fn $new() -> $Tuple {
+            ($(F::<$n>(0),)+)
+        }
+    };
+}
+
+define_arity!(Arity4, arity4 => 0, 1, 2, 3);
+define_arity!(Arity16, arity16 => 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+define_arity!(
+    Arity64, arity64 =>
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25,
+    26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49,
+    50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63
+);
+// The first and last fields share their type
+define_arity!(
+    Duplicated64, duplicated64 =>
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25,
+    26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49,
+    50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 0
+);
+
+// Benchmarks the accesses to the fields at the given positions of a tuple
+macro_rules! bench_arity {
+    ($c:expr, $new:ident, $arity:literal, [$($label:literal: $idx:tt),+]) => {{
+        let mut group = $c.benchmark_group(concat!("arity_", $arity));
+        $(
+            group.bench_function(BenchmarkId::new("get_by_type", $label), |b| {
+                let tuple = $new();
+                b.iter(|| black_box(&tuple).get::<F<$idx>>().map(|field| field.0));
+            });
+            group.bench_function(BenchmarkId::new("get_by_position", $label), |b| {
+                let tuple = $new();
+                b.iter(|| black_box(&tuple).$idx.0);
+            });
+            group.bench_function(BenchmarkId::new("map_by_type", $label), |b| {
+                let mut tuple = $new();
+                b.iter(|| black_box(&mut tuple).map(|field: &mut F<$idx>| field.0 += 1));
+            });
+            group.bench_function(BenchmarkId::new("map_by_position", $label), |b| {
+                let mut tuple = $new();
+                b.iter(|| black_box(&mut tuple).$idx.0 += 1);
+            });
+        )+
+        group.finish();
+    }};
+}
+
+fn access(c: &mut Criterion) {
+    bench_arity!(c, arity4, 4, ["first": 0, "middle": 2, "last": 3]);
+    bench_arity!(c, arity16, 16, ["first": 0, "middle": 8, "last": 15]);
+    bench_arity!(c, arity64, 64, ["first": 0, "middle": 32, "last": 63]);
+}
+
+// Reads a field by counting its occurrences first, then searching it again
+fn get_two_pass<S: TupleSet, T: 'static>(tuple: &S) -> Option<&T> {
+    if !tuple.contains_unique::<T>() {
+        return None;
+    }
+    // SAFETY: `T` appears exactly once in the tuple
+    Some(unsafe { tuple.get_unchecked() })
+}
+
+// Updates a field by counting its occurrences first, then searching it again
+fn map_two_pass<S: TupleSet, T: 'static, R>(
+    tuple: &mut S,
+    f: impl FnOnce(&mut T) -> R,
+) -> Option<R> {
+    if !tuple.contains_unique::<T>() {
+        return None;
+    }
+    // SAFETY: `T` appears exactly once in the tuple
+    Some(unsafe { tuple.map_unchecked(f) })
+}
+
+// Benchmarks the single-pass accessors against two passes for a field type
+macro_rules! bench_lookup {
+    ($group:expr, $tuple:expr, $label:literal: $Target:ty) => {{
+        $group.bench_function(BenchmarkId::new("get_single_pass", $label), |b| {
+            let tuple = $tuple;
+            b.iter(|| black_box(&tuple).get::<$Target>().map(|field| field.0));
+        });
+        $group.bench_function(BenchmarkId::new("get_two_pass", $label), |b| {
+            let tuple = $tuple;
+            b.iter(|| get_two_pass::<_, $Target>(black_box(&tuple)).map(|field| field.0));
+        });
+        $group.bench_function(BenchmarkId::new("map_single_pass", $label), |b| {
+            let mut tuple = $tuple;
+            b.iter(|| black_box(&mut tuple).map(|field: &mut $Target| field.0 += 1));
+        });
+        $group.bench_function(BenchmarkId::new("map_two_pass", $label), |b| {
+            let mut tuple = $tuple;
+            b.iter(|| map_two_pass(black_box(&mut tuple), |field: &mut $Target| field.0 += 1));
+        });
+    }};
+}
+
+fn lookup(c: &mut Criterion) {
+    let mut group = c.benchmark_group("lookup_64");
+    bench_lookup!(group, arity64(), "last": F<63>);
+    bench_lookup!(group, arity64(), "missing": F<64>);
+    bench_lookup!(group, duplicated64(), "duplicated": F<0>);
+    group.finish();
+}
+
+criterion_group!(benches, access, lookup);
+criterion_main!(benches);
+
+/// Reads the last field of a 16-tuple by type.
+#[inline(never)]
+#[must_use]
+pub fn by_type_last(tuple: &Arity16) -> Option<u64> {
+    tuple.get::<F<15>>().map(|field| field.0)
+}
+
+/// Reads the last field of a 16-tuple by position.
+#[inline(never)]
+#[must_use]
+pub fn by_position_last(tuple: &Arity16) -> Option<u64> {
+    Some(tuple.15.0)
+}
+
+/// Increments the middle field of a 16-tuple by type.
+#[inline(never)]
+pub fn by_type_increment(tuple: &mut Arity16) -> Option<()> {
+    tuple.map(|field: &mut F<8>| field.0 += 1)
+}
+
+/// Increments the middle field of a 16-tuple by position.
+#[inline(never)]
+pub fn by_position_increment(tuple: &mut Arity16) -> Option<()> {
+    tuple.8.0 += 1;
+    Some(())
+}
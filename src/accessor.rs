@@ -0,0 +1,105 @@
+//! Tokens granting unchecked access to a field, validated once.
+
+use core::{fmt, marker::PhantomData};
+
+use crate::InlineFields;
+
+/// Token giving access to the field of type `T` in tuples of type `S`.
+///
+/// The token is only minted after checking that `T` appears exactly once in
+/// `S`, and carries the byte offset of its field: as the offset only depends
+/// on the type of the tuple, [`get`](Accessor::get),
+/// [`get_mut`](Accessor::get_mut) and [`set`](Accessor::set) work on any
+/// tuple of type `S` without checking the type again, with the speed of
+/// [`get_unchecked`](crate::TupleSet::get_unchecked) behind a safe API. Reusing the
+/// offset is only sound for types whose fields are stored inline, hence the
+/// [`InlineFields`] bound.
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::Accessor;
+///
+/// let mut particles = [(1.0f32, 0u32), (2.0, 0), (3.0, 0)];
+///
+/// let position = Accessor::<_, f32>::new(&particles[0]).unwrap();
+/// let steps = Accessor::<_, u32>::new(&particles[0]).unwrap();
+/// for particle in &mut particles {
+///     *position.get_mut(particle) *= 2.0;
+///     steps.set(particle, 1);
+/// }
+///
+/// assert_eq!(particles, [(2.0, 1), (4.0, 1), (6.0, 1)]);
+/// assert!(Accessor::<_, bool>::new(&particles[0]).is_none());
+/// ```
+pub struct Accessor<S, T> {
+    offset: usize,
+    _marker: PhantomData<fn(&S) -> &T>,
+}
+
+impl<S: InlineFields, T: 'static> Accessor<S, T> {
+    /// Creates a token for the field of type `T` in tuples shaped as
+    /// `tuple`.
+    ///
+    /// Returns `None` if `T` is not found or appears multiple times in
+    /// `tuple`.
+    #[must_use]
+    pub fn new(tuple: &S) -> Option<Self> {
+        let offset = tuple.as_ptr_of::<T>()?.addr() - (&raw const *tuple).addr();
+        Some(Self { offset, _marker: PhantomData })
+    }
+
+    /// Returns the byte offset of the field from the start of the tuple.
+    #[must_use]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns a reference to the field of type `T` in `tuple`.
+    #[must_use]
+    pub fn get<'a>(&self, tuple: &'a S) -> &'a T {
+        // SAFETY: the offset was resolved on a value of type `S`, to its only
+        // field of type `T`, and is the same for every value of `S` as `S`
+        // implements `InlineFields`
+        unsafe { &*(&raw const *tuple).byte_add(self.offset).cast::<T>() }
+    }
+
+    /// Returns a mutable reference to the field of type `T` in `tuple`.
+    pub fn get_mut<'a>(&self, tuple: &'a mut S) -> &'a mut T {
+        // SAFETY: the offset was resolved on a value of type `S`, to its only
+        // field of type `T`, and is the same for every value of `S` as `S`
+        // implements `InlineFields`
+        unsafe { &mut *(&raw mut *tuple).byte_add(self.offset).cast::<T>() }
+    }
+
+    /// Replaces the field of type `T` in `tuple` with `value`, returning the
+    /// previous value.
+    pub fn set(&self, tuple: &mut S, value: T) -> T {
+        core::mem::replace(self.get_mut(tuple), value)
+    }
+}
+
+impl<S, T> Clone for Accessor<S, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S, T> Copy for Accessor<S, T> {}
+
+impl<S, T> PartialEq for Accessor<S, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.offset == other.offset
+    }
+}
+
+impl<S, T> Eq for Accessor<S, T> {}
+
+impl<S, T> fmt::Debug for Accessor<S, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Accessor")
+            .field("type", &core::any::type_name::<T>())
+            .field("offset", &self.offset)
+            .finish()
+    }
+}
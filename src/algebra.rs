@@ -0,0 +1,396 @@
+//! Compile-time type-set algebra between tuple types.
+//!
+//! Stable Rust cannot express type inequality, so the traits in this module
+//! locate types through an inferred [`Position`] parameter instead: a bound
+//! such as `S: Pluck<u32, I>` holds for exactly one `I` when `u32` appears
+//! once in `S`, and becomes ambiguous (a compile error) when it appears
+//! multiple times. Generic code simply threads these index parameters through
+//! and lets the compiler infer them.
+
+/// Type-level marker for the position of an element inside a tuple.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Position<const N: usize>;
+
+/// Trait for removing the element of type `T` at position `I` from a tuple.
+pub trait Pluck<T, I> {
+    /// The tuple left after removing `T`.
+    type Remainder;
+
+    /// Splits the tuple into the value of type `T` and the remaining
+    /// elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::Pluck;
+    ///
+    /// let (value, rest): (f64, _) = (42i32, 2.5f64, "hello").pluck();
+    /// assert_eq!(value, 2.5);
+    /// assert_eq!(rest, (42, "hello"));
+    /// ```
+    fn pluck(self) -> (T, Self::Remainder);
+}
+
+/// Trait for prepending an element to a tuple.
+pub trait Prepend<T> {
+    /// The tuple with `T` as its first element.
+    type Output;
+
+    /// Returns the tuple with `value` inserted at the front.
+    fn prepend(self, value: T) -> Self::Output;
+}
+
+/// Trait for concatenating two tuples positionally.
+pub trait Concat<Other> {
+    /// The tuple holding the elements of `Self` followed by those of `Other`.
+    type Output;
+
+    /// Concatenates `self` and `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::Concat;
+    ///
+    /// assert_eq!((1u8, 2u16).concat((3u32,)), (1u8, 2u16, 3u32));
+    /// ```
+    fn concat(self, other: Other) -> Self::Output;
+}
+
+/// Trait for tuples containing every type of the tuple `Sub`.
+///
+/// `Indices` records, as nested pairs of [`Position`] markers, where each type
+/// of `Sub` was found, and is inferred by the compiler.
+pub trait Subset<Sub, Indices> {
+    /// The tuple left after removing every type of `Sub`.
+    type Remainder;
+
+    /// Splits the tuple into the elements of `Sub` and the remaining ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::Subset;
+    ///
+    /// let ((flag, value), rest): ((bool, i32), _) = (42i32, "hello", true).split_subset();
+    /// assert_eq!((flag, value), (true, 42));
+    /// assert_eq!(rest, ("hello",));
+    /// ```
+    fn split_subset(self) -> (Sub, Self::Remainder);
+}
+
+/// Marker trait for tuples containing the type `T` at the inferred position
+/// `I`.
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::{Contains, TupleSet};
+///
+/// fn retries<C: Contains<u32, I> + TupleSet, I>(context: &C) -> u32 {
+///     *context.get::<u32>().unwrap()
+/// }
+///
+/// assert_eq!(retries(&("localhost", 3u32)), 3);
+/// ```
+pub trait Contains<T, I> {}
+
+impl<S, T, I> Contains<T, I> for S where S: Pluck<T, I> {}
+
+/// Marker trait for tuples containing every type of the tuple `Types`.
+///
+/// This replaces stacking one [`Contains`] bound per required type with a
+/// single bound, whose `Indices` are inferred by the compiler.
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::{ContainsAll, TupleSet};
+///
+/// fn describe<C: ContainsAll<(u32, bool), I> + TupleSet, I>(context: &C) -> (u32, bool) {
+///     (*context.get::<u32>().unwrap(), *context.get::<bool>().unwrap())
+/// }
+///
+/// assert_eq!(describe(&(true, "localhost", 3u32)), (3, true));
+/// ```
+pub trait ContainsAll<Types, Indices> {}
+
+impl<S, Types, Indices> ContainsAll<Types, Indices> for S where S: Subset<Types, Indices> {}
+
+/// Marker trait describing the types of `Self` that do not appear in `Other`.
+///
+/// Every type of `Other` must appear in `Self`.
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::Difference;
+///
+/// fn exclusive<A: Difference<B, I>, B, I>() -> &'static str {
+///     core::any::type_name::<A::Output>()
+/// }
+///
+/// assert_eq!(exclusive::<(i32, bool, char), (char, i32), _>(), "(bool,)");
+/// ```
+pub trait Difference<Other, Indices> {
+    /// The types of `Self` not in `Other`, in positional order.
+    type Output;
+}
+
+impl<S, Other, Indices> Difference<Other, Indices> for S
+where
+    S: Subset<Other, Indices>,
+{
+    type Output = S::Remainder;
+}
+
+/// Marker trait asserting that both `Self` and `Other` contain every type of
+/// `Shared`, and describing the types exclusive to each side.
+///
+/// Since type inequality cannot be expressed on stable Rust, the shared types
+/// are named by the caller rather than computed.
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::Intersection;
+///
+/// fn exclusive<A: Intersection<B, S, I>, B, S, I>() -> (&'static str, &'static str) {
+///     (core::any::type_name::<A::SelfOnly>(), core::any::type_name::<A::OtherOnly>())
+/// }
+///
+/// assert_eq!(exclusive::<(i32, bool), (char, i32), (i32,), _>(), ("(bool,)", "(char,)"));
+/// ```
+pub trait Intersection<Other, Shared, Indices> {
+    /// The types of `Self` not in `Shared`.
+    type SelfOnly;
+    /// The types of `Other` not in `Shared`.
+    type OtherOnly;
+}
+
+impl<S, Other, Shared, IS, IO> Intersection<Other, Shared, (IS, IO)> for S
+where
+    S: Subset<Shared, IS>,
+    Other: Subset<Shared, IO>,
+{
+    type SelfOnly = S::Remainder;
+    type OtherOnly = Other::Remainder;
+}
+
+/// Trait merging two tuples sharing the types of `Shared` into a tuple where
+/// each type appears once.
+///
+/// The merged tuple holds every element of `Self`, followed by the elements
+/// exclusive to `Other`. Shared values are taken from `Self`.
+pub trait Union<Other, Shared, Indices> {
+    /// The merged tuple type.
+    type Output;
+
+    /// Merges `self` with the elements exclusive to `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::Union;
+    ///
+    /// let merged: (i32, bool, char) = Union::<_, (i32,), _>::union((1i32, true), ('x', 2i32));
+    /// assert_eq!(merged, (1, true, 'x'));
+    /// ```
+    fn union(self, other: Other) -> Self::Output;
+}
+
+impl<S, Other, Shared, IS, IO> Union<Other, Shared, (IS, IO)> for S
+where
+    S: Subset<Shared, IS> + Concat<<Other as Subset<Shared, IO>>::Remainder>,
+    Other: Subset<Shared, IO>,
+{
+    type Output = <S as Concat<Other::Remainder>>::Output;
+
+    #[inline]
+    fn union(self, other: Other) -> Self::Output {
+        let (_, exclusive) = other.split_subset();
+        self.concat(exclusive)
+    }
+}
+
+/// Trait for tuples whose first elements are, positionally, the elements of
+/// the tuple `Prefix`.
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::StartsWith;
+///
+/// fn legacy<T: StartsWith<(u32, &'static str)>>(record: T) -> (u32, &'static str) {
+///     record.into_prefix()
+/// }
+///
+/// assert_eq!(legacy((7u32, "name", true)), (7, "name"));
+/// ```
+pub trait StartsWith<Prefix> {
+    /// Consumes the tuple, returning its first elements as `Prefix`.
+    fn into_prefix(self) -> Prefix;
+}
+
+/// Trait for tuples whose last elements are, positionally, the elements of
+/// the tuple `Suffix`.
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::EndsWith;
+///
+/// fn trailer<T: EndsWith<(bool,)>>(record: T) -> bool {
+///     record.into_suffix().0
+/// }
+///
+/// assert!(trailer((7u32, "name", true)));
+/// ```
+pub trait EndsWith<Suffix> {
+    /// Consumes the tuple, returning its last elements as `Suffix`.
+    fn into_suffix(self) -> Suffix;
+}
+
+impl<T> Prepend<T> for () {
+    type Output = (T,);
+
+    #[inline]
+    fn prepend(self, value: T) -> Self::Output {
+        (value,)
+    }
+}
+
+impl<Other> Concat<Other> for () {
+    type Output = Other;
+
+    #[inline]
+    fn concat(self, other: Other) -> Self::Output {
+        other
+    }
+}
+
+impl<S> Subset<(), ()> for S {
+    type Remainder = S;
+
+    #[inline]
+    fn split_subset(self) -> ((), Self::Remainder) {
+        ((), self)
+    }
+}
+
+// Generates one `Pluck` impl per position, walking the tuple while keeping
+// track of the elements before and after the current one
+macro_rules! impl_pluck {
+    (@ [$($before:ident),*] $idx:tt: $T:ident $(, $after_idx:tt: $after:ident)*) => {
+        #[allow(non_snake_case)]
+        impl<$($before,)* $T, $($after,)*> $crate::Pluck<$T, $crate::Position<$idx>>
+            for ($($before,)* $T, $($after,)*)
+        {
+            type Remainder = ($($before,)* $($after,)*);
+
+            #[inline]
+            fn pluck(self) -> ($T, Self::Remainder) {
+                let ($($before,)* $T, $($after,)*) = self;
+                ($T, ($($before,)* $($after,)*))
+            }
+        }
+
+        $crate::algebra::impl_pluck!(@ [$($before,)* $T] $($after_idx: $after),*);
+    };
+    (@ [$($before:ident),*]) => {};
+}
+
+// Generates one `StartsWith` impl per prefix length, given all the tuple
+// types and the elements of the prefix accumulated so far
+macro_rules! impl_starts_with {
+    (@ $all:tt [$($before_idx:tt: $before:ident),*] $idx:tt: $T:ident $(, $after_idx:tt: $after:ident)*) => {
+        $crate::algebra::impl_starts_with!(@impl $all [$($before_idx: $before,)* $idx: $T]);
+        $crate::algebra::impl_starts_with!(@ $all [$($before_idx: $before,)* $idx: $T] $($after_idx: $after),*);
+    };
+    (@ $all:tt [$($before_idx:tt: $before:ident),*]) => {};
+    (@impl [$($A:ident),+] [$($idx:tt: $T:ident),+]) => {
+        impl<$($A),+> $crate::StartsWith<($($T,)+)> for ($($A,)+) {
+            #[inline]
+            fn into_prefix(self) -> ($($T,)+) {
+                ($(self.$idx,)+)
+            }
+        }
+    };
+}
+
+// Generates one `EndsWith` impl per suffix length, dropping one leading
+// element at a time
+macro_rules! impl_ends_with {
+    ([$($A:ident),+] $idx:tt: $T:ident $(, $after_idx:tt: $after:ident)*) => {
+        impl<$($A),+> $crate::EndsWith<($T, $($after,)*)> for ($($A,)+) {
+            #[inline]
+            fn into_suffix(self) -> ($T, $($after,)*) {
+                (self.$idx, $(self.$after_idx,)*)
+            }
+        }
+
+        $crate::algebra::impl_ends_with!([$($A),+] $($after_idx: $after),*);
+    };
+    ([$($A:ident),+]) => {};
+}
+
+macro_rules! impl_tuple_algebra {
+    ($first_idx:tt: $First:ident $(, $idx:tt: $T:ident)*) => {
+        $crate::algebra::impl_pluck!(@ [] $first_idx: $First $(, $idx: $T)*);
+        $crate::algebra::impl_starts_with!(@ [$First $(, $T)*] [] $first_idx: $First $(, $idx: $T)*);
+        $crate::algebra::impl_ends_with!([$First $(, $T)*] $first_idx: $First $(, $idx: $T)*);
+
+        #[allow(non_snake_case)]
+        impl<Head, $First, $($T),*> $crate::Prepend<Head> for ($First, $($T,)*) {
+            type Output = (Head, $First, $($T,)*);
+
+            #[inline]
+            fn prepend(self, value: Head) -> Self::Output {
+                let ($First, $($T,)*) = self;
+                (value, $First, $($T,)*)
+            }
+        }
+
+        #[allow(non_snake_case)]
+        impl<$First, $($T,)* Other> $crate::Concat<Other> for ($First, $($T,)*)
+        where
+            ($($T,)*): $crate::Concat<Other>,
+            <($($T,)*) as $crate::Concat<Other>>::Output: $crate::Prepend<$First>,
+        {
+            type Output =
+                <<($($T,)*) as $crate::Concat<Other>>::Output as $crate::Prepend<$First>>::Output;
+
+            #[inline]
+            fn concat(self, other: Other) -> Self::Output {
+                let ($First, $($T,)*) = self;
+                $crate::Prepend::prepend($crate::Concat::concat(($($T,)*), other), $First)
+            }
+        }
+
+        #[allow(non_snake_case)]
+        impl<Source, $First, $($T,)* IFirst, IRest> $crate::Subset<($First, $($T,)*), (IFirst, IRest)>
+            for Source
+        where
+            Source: $crate::Pluck<$First, IFirst>,
+            <Source as $crate::Pluck<$First, IFirst>>::Remainder:
+                $crate::Subset<($($T,)*), IRest>,
+        {
+            type Remainder = <<Source as $crate::Pluck<$First, IFirst>>::Remainder as $crate::Subset<
+                ($($T,)*),
+                IRest,
+            >>::Remainder;
+
+            #[inline]
+            fn split_subset(self) -> (($First, $($T,)*), Self::Remainder) {
+                let ($First, rest) = $crate::Pluck::pluck(self);
+                let (($($T,)*), remainder) = $crate::Subset::split_subset(rest);
+                (($First, $($T,)*), remainder)
+            }
+        }
+    };
+}
+
+pub(crate) use impl_ends_with;
+pub(crate) use impl_pluck;
+pub(crate) use impl_starts_with;
+pub(crate) use impl_tuple_algebra;
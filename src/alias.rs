@@ -0,0 +1,64 @@
+//! Opt-in transparency of newtype wrappers for by-type accessors.
+
+use core::{
+    cmp::Reverse,
+    num::{Saturating, Wrapping},
+};
+
+use crate::Tagged;
+
+/// Marker trait for `#[repr(transparent)]` newtypes whose fields can be
+/// accessed as their inner value, e.g. through
+/// [`get_aliased`](crate::TupleSet::get_aliased).
+///
+/// Without specialization, the plain accessors cannot know whether a field
+/// type opts in: the aliased accessors look the wrapper up by its own type
+/// and hand out its inner value instead.
+///
+/// # Safety
+///
+/// The implementing type must be `#[repr(transparent)]` over `Inner`, so
+/// that references to it can be reinterpreted as references to `Inner`, and
+/// every value of `Inner` must be a valid value of the implementing type.
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::{TransparentAlias, TupleSet};
+///
+/// #[repr(transparent)]
+/// struct UserId(u64);
+///
+/// // SAFETY: `UserId` is a transparent wrapper over any `u64`
+/// unsafe impl TransparentAlias for UserId {
+///     type Inner = u64;
+/// }
+///
+/// let mut session = (UserId(7), "alice");
+/// assert_eq!(session.get_aliased::<UserId>(), Some(&7));
+/// assert_eq!(session.set_aliased::<UserId>(8), None);
+/// assert_eq!(session.0.0, 8);
+/// ```
+pub unsafe trait TransparentAlias: 'static {
+    /// The wrapped type.
+    type Inner: 'static;
+}
+
+// SAFETY: the wrappers below are all `#[repr(transparent)]` over any value
+// of their inner type.
+
+unsafe impl<T: 'static> TransparentAlias for Wrapping<T> {
+    type Inner = T;
+}
+
+unsafe impl<T: 'static> TransparentAlias for Saturating<T> {
+    type Inner = T;
+}
+
+unsafe impl<T: 'static> TransparentAlias for Reverse<T> {
+    type Inner = T;
+}
+
+unsafe impl<Tag: 'static, T: 'static> TransparentAlias for Tagged<Tag, T> {
+    type Inner = T;
+}
@@ -0,0 +1,52 @@
+//! Calls of functions with the elements of a tuple as arguments.
+
+/// Trait spreading a tuple as the arguments of a function, e.g. calling
+/// `f(a, b, c)` with the tuple `(a, b, c)`.
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::{Apply, TupleSet};
+///
+/// fn connect(host: &str, port: u16, secure: bool) -> String {
+///     format!("{}://{host}:{port}", if secure { "https" } else { "http" })
+/// }
+///
+/// let mut args = ("localhost", 80u16, false);
+/// args.set(8443u16);
+/// args.set(true);
+///
+/// assert_eq!(args.apply(connect), "https://localhost:8443");
+/// assert_eq!((2u32, 3u32).apply(u32::pow), 8);
+/// ```
+pub trait Apply<F> {
+    /// The value returned by the function.
+    type Output;
+
+    /// Calls `f` with the elements of the tuple, in positional order.
+    fn apply(self, f: F) -> Self::Output;
+}
+
+impl<F: FnOnce() -> R, R> Apply<F> for () {
+    type Output = R;
+
+    #[inline]
+    fn apply(self, f: F) -> R {
+        f()
+    }
+}
+
+macro_rules! impl_tuple_apply {
+    ($($idx:tt: $T:ident),+) => {
+        impl<$($T,)+ F: FnOnce($($T),+) -> R, R> $crate::Apply<F> for ($($T,)+) {
+            type Output = R;
+
+            #[inline]
+            fn apply(self, f: F) -> R {
+                f($(self.$idx),+)
+            }
+        }
+    };
+}
+
+pub(crate) use impl_tuple_apply;
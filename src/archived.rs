@@ -0,0 +1,205 @@
+//! Zero-copy archiving of tuples through rkyv.
+
+use core::any::{Any, TypeId};
+
+use rkyv::{Portable, bytecheck::CheckBytes};
+
+/// Wrapper archiving a tuple with rkyv, for every arity supported by this
+/// crate.
+///
+/// Unlike the tuple implementations of rkyv, which stop at 13 elements, it is
+/// available for every arity supported by this crate. The tuple is archived as
+/// an [`ArchivedCons`] list of its archived fields, so tuple-shaped records can
+/// be memory-mapped and read without deserializing them.
+///
+/// # Examples
+///
+/// ```
+/// use rkyv::{Archived, rancor::Error};
+/// use tuple_set::RkyvTuple;
+///
+/// type Record = RkyvTuple<(u32, bool, f64)>;
+///
+/// let bytes = rkyv::to_bytes::<Error>(&Record::new((42, true, 2.5))).unwrap();
+///
+/// let archived = rkyv::access::<Archived<Record>, Error>(&bytes).unwrap();
+/// assert_eq!(archived.get::<Archived<u32>>().map(|value| value.to_native()), Some(42));
+/// assert_eq!(archived.tail.head, true);
+///
+/// let decoded: Record = rkyv::deserialize::<_, Error>(archived).unwrap();
+/// assert_eq!(decoded.into_inner(), (42, true, 2.5));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct RkyvTuple<T> {
+    tuple: T,
+}
+
+impl<T> RkyvTuple<T> {
+    /// Wraps `tuple` to be archived.
+    pub fn new(tuple: T) -> Self {
+        Self { tuple }
+    }
+
+    /// Returns the wrapped tuple.
+    pub fn into_inner(self) -> T {
+        self.tuple
+    }
+}
+
+impl<T> core::ops::Deref for RkyvTuple<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.tuple
+    }
+}
+
+impl<T> core::ops::DerefMut for RkyvTuple<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.tuple
+    }
+}
+
+impl<T> From<T> for RkyvTuple<T> {
+    fn from(tuple: T) -> Self {
+        Self::new(tuple)
+    }
+}
+
+/// Archived form of a [`RkyvTuple`], as a list of its archived fields ending
+/// with `()`.
+///
+/// The field at position `n` is reached by following `tail` `n` times, then
+/// `head`. Fields can also be looked up by their archived type through
+/// [`get`](ArchivedCons::get).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Portable, CheckBytes)]
+#[bytecheck(crate = rkyv::bytecheck)]
+#[rkyv(crate = rkyv)]
+#[repr(C)]
+pub struct ArchivedCons<H, T> {
+    /// The first archived field.
+    pub head: H,
+    /// The remaining archived fields.
+    pub tail: T,
+}
+
+/// Trait for lists of archived fields, searched by type.
+pub trait ArchivedFields {
+    /// Returns the number of fields of type `U`.
+    fn count_of<U: 'static>(&self) -> usize;
+
+    /// Returns a reference to the first field of type `U`, if any.
+    fn first_of<U: 'static>(&self) -> Option<&U>;
+}
+
+impl ArchivedFields for () {
+    #[inline]
+    fn count_of<U: 'static>(&self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn first_of<U: 'static>(&self) -> Option<&U> {
+        None
+    }
+}
+
+impl<H: 'static, T: ArchivedFields> ArchivedFields for ArchivedCons<H, T> {
+    #[inline]
+    fn count_of<U: 'static>(&self) -> usize {
+        usize::from(TypeId::of::<H>() == TypeId::of::<U>()) + self.tail.count_of::<U>()
+    }
+
+    #[inline]
+    fn first_of<U: 'static>(&self) -> Option<&U> {
+        (&self.head as &dyn Any).downcast_ref().or_else(|| self.tail.first_of())
+    }
+}
+
+impl<H: 'static, T: ArchivedFields> ArchivedCons<H, T> {
+    /// Returns a reference to the archived field of type `U` if it appears
+    /// exactly once.
+    ///
+    /// Returns `None` if the type is not found or appears multiple times.
+    #[must_use]
+    pub fn get<U: 'static>(&self) -> Option<&U> {
+        if self.count_of::<U>() != 1 {
+            return None;
+        }
+
+        self.first_of()
+    }
+}
+
+// Expands to the `ArchivedCons` list of the archived forms of the given types
+macro_rules! archived_cons {
+    () => {
+        ()
+    };
+    ($T:ident $(, $rest:ident)*) => {
+        $crate::ArchivedCons<
+            <$T as rkyv::Archive>::Archived,
+            $crate::archived::archived_cons!($($rest),*)
+        >
+    };
+}
+
+pub(crate) use archived_cons;
+
+macro_rules! impl_tuple_rkyv {
+    ($($idx:tt: $T:ident),+) => {
+        $crate::archived::impl_tuple_rkyv!(@paths [$($T),+] [] [] $($idx)+);
+    };
+    // Accumulates the path of each field in the archived list
+    (@paths [$($T:ident),+] [$($done:tt)*] [$($path:tt)*] $idx:tt $($rest:tt)*) => {
+        $crate::archived::impl_tuple_rkyv!(
+            @paths [$($T),+] [$($done)* ($idx: $($path)* head)] [$($path)* tail .] $($rest)*
+        );
+    };
+    (@paths [$($T:ident),+] [$(($idx:tt: $($path:tt)+))+] [$($unused:tt)*]) => {
+        impl<$($T: rkyv::Archive),+> rkyv::Archive for $crate::RkyvTuple<($($T,)+)> {
+            type Archived = $crate::archived::archived_cons!($($T),+);
+            type Resolver = ($($T::Resolver,)+);
+
+            fn resolve(&self, resolver: Self::Resolver, out: rkyv::Place<Self::Archived>) {
+                // SAFETY: the pointer is only used to project to each field
+                let out_ptr = unsafe { out.ptr() };
+                $(
+                    // SAFETY: the pointer points to a field of `out`
+                    let field = unsafe {
+                        rkyv::Place::from_field_unchecked(out, &raw mut (*out_ptr).$($path)+)
+                    };
+                    self.$idx.resolve(resolver.$idx, field);
+                )+
+            }
+        }
+
+        impl<S, $($T),+> rkyv::Serialize<S> for $crate::RkyvTuple<($($T,)+)>
+        where
+            S: rkyv::rancor::Fallible + ?Sized,
+            $($T: rkyv::Serialize<S>,)+
+        {
+            fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+                Ok(($(self.$idx.serialize(serializer)?,)+))
+            }
+        }
+
+        impl<D, $($T),+> rkyv::Deserialize<$crate::RkyvTuple<($($T,)+)>, D>
+            for $crate::archived::archived_cons!($($T),+)
+        where
+            D: rkyv::rancor::Fallible + ?Sized,
+            $($T: rkyv::Archive, $T::Archived: rkyv::Deserialize<$T, D>,)+
+        {
+            fn deserialize(
+                &self,
+                deserializer: &mut D,
+            ) -> Result<$crate::RkyvTuple<($($T,)+)>, D::Error> {
+                Ok($crate::RkyvTuple::new(($(self.$($path)+.deserialize(deserializer)?,)+)))
+            }
+        }
+    };
+}
+
+pub(crate) use impl_tuple_rkyv;
@@ -0,0 +1,64 @@
+//! Element-wise arithmetic between tuples of the same type.
+
+// Defines the by-value and compound-assignment traits of an operator
+macro_rules! define_each_op {
+    ($Each:ident $each:ident $AssignEach:ident $assign_each:ident, $verb:literal, $symbol:literal, $example:literal) => {
+        #[doc = concat!("Trait for ", $verb, " two tuples of the same type element-wise.")]
+        pub trait $Each {
+            #[doc = concat!("Returns the tuple whose elements are `self.i ", $symbol, " rhs.i`.")]
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tuple_set::", stringify!($Each), ";")]
+            ///
+            #[doc = $example]
+            /// ```
+            #[must_use]
+            fn $each(self, rhs: Self) -> Self;
+        }
+
+        #[doc = concat!("Trait for ", $verb, " a tuple of the same type into a tuple element-wise, in place.")]
+        pub trait $AssignEach {
+            #[doc = concat!("Performs `self.i ", $symbol, "= rhs.i` for every element.")]
+            fn $assign_each(&mut self, rhs: Self);
+        }
+    };
+}
+
+define_each_op!(AddEach add_each AddAssignEach add_assign_each, "adding", "+", "assert_eq!((1u8, 2.5f64).add_each((2, 0.5)), (3, 3.0));");
+define_each_op!(SubEach sub_each SubAssignEach sub_assign_each, "subtracting", "-", "assert_eq!((5u8, 2.5f64).sub_each((2, 0.5)), (3, 2.0));");
+define_each_op!(MulEach mul_each MulAssignEach mul_assign_each, "multiplying", "*", "assert_eq!((5u8, 2.5f64).mul_each((2, 2.0)), (10, 5.0));");
+define_each_op!(DivEach div_each DivAssignEach div_assign_each, "dividing", "/", "assert_eq!((6u8, 2.5f64).div_each((2, 0.5)), (3, 5.0));");
+
+// Implements the by-value and compound-assignment traits of an operator for
+// a tuple
+macro_rules! impl_each_op {
+    ($Each:ident $each:ident $Op:ident $op:ident, $AssignEach:ident $assign_each:ident $AssignOp:ident $assign_op:ident; $($idx:tt: $T:ident),+) => {
+        impl<$($T: core::ops::$Op<Output = $T>),+> $crate::$Each for ($($T,)+) {
+            #[inline]
+            fn $each(self, rhs: Self) -> Self {
+                ($(core::ops::$Op::$op(self.$idx, rhs.$idx),)+)
+            }
+        }
+
+        impl<$($T: core::ops::$AssignOp),+> $crate::$AssignEach for ($($T,)+) {
+            #[inline]
+            fn $assign_each(&mut self, rhs: Self) {
+                $(core::ops::$AssignOp::$assign_op(&mut self.$idx, rhs.$idx);)+
+            }
+        }
+    };
+}
+
+macro_rules! impl_tuple_arith {
+    ($($idx:tt: $T:ident),+) => {
+        $crate::arith::impl_each_op!(AddEach add_each Add add, AddAssignEach add_assign_each AddAssign add_assign; $($idx: $T),+);
+        $crate::arith::impl_each_op!(SubEach sub_each Sub sub, SubAssignEach sub_assign_each SubAssign sub_assign; $($idx: $T),+);
+        $crate::arith::impl_each_op!(MulEach mul_each Mul mul, MulAssignEach mul_assign_each MulAssign mul_assign; $($idx: $T),+);
+        $crate::arith::impl_each_op!(DivEach div_each Div div, DivAssignEach div_assign_each DivAssign div_assign; $($idx: $T),+);
+    };
+}
+
+pub(crate) use impl_each_op;
+pub(crate) use impl_tuple_arith;
@@ -0,0 +1,33 @@
+//! Number of elements of tuple types.
+
+/// Trait exposing the number of elements of a tuple type as a constant.
+///
+/// The constant can size arrays, so code building per-field buffers or
+/// reflection tables does not need a hand-rolled table per arity. For the
+/// number of elements of a tuple value, see [`TupleSet::arity`].
+///
+/// [`TupleSet::arity`]: crate::TupleSet::arity
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::TupleArity;
+///
+/// const ARITY: usize = <(u8, &str, f64)>::ARITY;
+/// let slots = [None::<usize>; ARITY];
+/// assert_eq!(slots.len(), 3);
+/// ```
+pub trait TupleArity {
+    /// Number of elements of the tuple type.
+    const ARITY: usize;
+}
+
+macro_rules! impl_tuple_arity {
+    ($($idx:tt: $T:ident),+) => {
+        impl<$($T),+> $crate::TupleArity for ($($T,)+) {
+            const ARITY: usize = 0 $(+ $crate::one!($T))+;
+        }
+    };
+}
+
+pub(crate) use impl_tuple_arity;
@@ -0,0 +1,192 @@
+//! Conversions between homogeneous tuples and arrays, and their iteration.
+
+/// Trait for tuples whose elements all share the type
+/// [`Element`](HomogeneousTuple::Element), convertible to arrays.
+pub trait HomogeneousTuple {
+    /// The type shared by every element.
+    type Element;
+
+    /// The array with one entry per element.
+    type Array: IntoIterator<Item = Self::Element>;
+
+    /// Iterator over references to the elements.
+    type Iter<'a>: Iterator<Item = &'a Self::Element>
+    where
+        Self: 'a;
+
+    /// Iterator over mutable references to the elements.
+    type IterMut<'a>: Iterator<Item = &'a mut Self::Element>
+    where
+        Self: 'a;
+
+    /// Converts the tuple into an array, in positional order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::HomogeneousTuple;
+    ///
+    /// assert_eq!((1u8, 2u8, 3u8).into_array(), [1, 2, 3]);
+    /// ```
+    fn into_array(self) -> Self::Array;
+
+    /// Views the tuple as an array without copying.
+    ///
+    /// Tuples have no guaranteed layout, so the address of every element is
+    /// checked to follow the previous one contiguously. Returns `None` if the
+    /// elements are not laid out as an array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::HomogeneousTuple;
+    ///
+    /// let tuple = (1u8, 2u8, 3u8);
+    /// if let Some(array) = tuple.as_array_ref() {
+    ///     assert_eq!(array, &[1, 2, 3]);
+    /// }
+    /// ```
+    fn as_array_ref(&self) -> Option<&Self::Array>;
+
+    /// Views the tuple as a mutable array without copying.
+    ///
+    /// Returns `None` under the same conditions as
+    /// [`as_array_ref`](HomogeneousTuple::as_array_ref).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::HomogeneousTuple;
+    ///
+    /// let mut tuple = (3u8, 1u8, 2u8);
+    /// if let Some(array) = tuple.as_array_mut() {
+    ///     array.sort_unstable();
+    ///     assert_eq!(tuple, (1, 2, 3));
+    /// }
+    /// ```
+    fn as_array_mut(&mut self) -> Option<&mut Self::Array>;
+
+    /// Returns an iterator over references to the elements, in positional
+    /// order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::HomogeneousTuple;
+    ///
+    /// let tuple = (1u32, 2u32, 3u32);
+    /// assert_eq!(tuple.iter().max(), Some(&3));
+    /// ```
+    fn iter(&self) -> Self::Iter<'_>;
+
+    /// Returns an iterator over mutable references to the elements, in
+    /// positional order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::HomogeneousTuple;
+    ///
+    /// let mut tuple = (1u32, 2u32, 3u32);
+    /// tuple.iter_mut().for_each(|value| *value *= 2);
+    /// assert_eq!(tuple, (2, 4, 6));
+    /// ```
+    fn iter_mut(&mut self) -> Self::IterMut<'_>;
+}
+
+/// Wrapper implementing [`IntoIterator`] for a [`HomogeneousTuple`], by
+/// value and by reference.
+///
+/// The wrapper is required since `IntoIterator` cannot be implemented for
+/// tuples outside of the standard library.
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::Homogeneous;
+///
+/// let names = Homogeneous(("a", "b", "c"));
+/// assert_eq!(names.into_iter().collect::<String>(), "abc");
+///
+/// let mut totals = Homogeneous((1u8, 2u8));
+/// for total in &mut totals {
+///     *total += 1;
+/// }
+/// assert_eq!(totals.0, (2, 3));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Homogeneous<T>(pub T);
+
+impl<T: HomogeneousTuple> IntoIterator for Homogeneous<T> {
+    type Item = T::Element;
+    type IntoIter = <T::Array as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_array().into_iter()
+    }
+}
+
+impl<'a, T: HomogeneousTuple> IntoIterator for &'a Homogeneous<T> {
+    type Item = &'a T::Element;
+    type IntoIter = T::Iter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<'a, T: HomogeneousTuple> IntoIterator for &'a mut Homogeneous<T> {
+    type Item = &'a mut T::Element;
+    type IntoIter = T::IterMut<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter_mut()
+    }
+}
+
+// Expands to the given element type for each tuple type, used to spell
+// homogeneous tuples in macros
+macro_rules! element {
+    ($T:ident, $E:ident) => {
+        $E
+    };
+}
+
+macro_rules! impl_tuple_array {
+    ($($idx:tt: $T:ident),+) => {
+        impl<E: 'static> $crate::HomogeneousTuple for ($($crate::array::element!($T, E),)+) {
+            type Element = E;
+            type Array = [E; 0 $(+ $crate::one!($T))+];
+            type Iter<'a> = core::array::IntoIter<&'a E, { 0 $(+ $crate::one!($T))+ }>;
+            type IterMut<'a> = core::array::IntoIter<&'a mut E, { 0 $(+ $crate::one!($T))+ }>;
+
+            #[inline]
+            fn into_array(self) -> Self::Array {
+                [$(self.$idx),+]
+            }
+
+            #[inline]
+            fn as_array_ref(&self) -> Option<&Self::Array> {
+                $crate::TupleSet::as_slice_of::<E>(self).and_then(|slice| slice.try_into().ok())
+            }
+
+            #[inline]
+            fn as_array_mut(&mut self) -> Option<&mut Self::Array> {
+                $crate::TupleSet::as_mut_slice_of::<E>(self).and_then(|slice| slice.try_into().ok())
+            }
+
+            #[inline]
+            fn iter(&self) -> Self::Iter<'_> {
+                [$(&self.$idx),+].into_iter()
+            }
+
+            #[inline]
+            fn iter_mut(&mut self) -> Self::IterMut<'_> {
+                [$(&mut self.$idx),+].into_iter()
+            }
+        }
+    };
+}
+
+pub(crate) use element;
+pub(crate) use impl_tuple_array;
@@ -0,0 +1,161 @@
+//! Positional access to tuple elements.
+
+/// Trait for accessing the tuple element at position `N`.
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::At;
+///
+/// fn second<T: At<1>>(tuple: &T) -> &T::Element {
+///     tuple.at()
+/// }
+///
+/// let mut tuple = (42i32, "hello", 2.5f64);
+/// assert_eq!(*second(&tuple), "hello");
+///
+/// *At::<2>::at_mut(&mut tuple) *= 2.0;
+/// assert_eq!(At::<2>::into_at(tuple), 5.0);
+/// ```
+pub trait At<const N: usize> {
+    /// The type of the element at position `N`.
+    type Element;
+
+    /// Returns a reference to the element at position `N`.
+    fn at(&self) -> &Self::Element;
+
+    /// Returns a mutable reference to the element at position `N`.
+    fn at_mut(&mut self) -> &mut Self::Element;
+
+    /// Consumes the tuple, returning the element at position `N`.
+    fn into_at(self) -> Self::Element;
+}
+
+/// Trait for accessing the first element of a tuple, whatever its arity.
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::TupleFirst;
+///
+/// let mut tuple = (1u8, "hello", 2.5f64);
+/// *tuple.first_mut() += 1;
+/// assert_eq!(*tuple.first(), 2);
+/// assert_eq!(tuple.into_first(), 2);
+/// ```
+pub trait TupleFirst {
+    /// The type of the first element.
+    type First;
+
+    /// Returns a reference to the first element.
+    fn first(&self) -> &Self::First;
+
+    /// Returns a mutable reference to the first element.
+    fn first_mut(&mut self) -> &mut Self::First;
+
+    /// Consumes the tuple, returning the first element.
+    fn into_first(self) -> Self::First;
+}
+
+/// Trait for accessing the last element of a tuple, whatever its arity.
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::TupleLast;
+///
+/// let mut tuple = (1u8, "hello", 2.5f64);
+/// *tuple.last_mut() *= 2.0;
+/// assert_eq!(*tuple.last(), 5.0);
+/// assert_eq!(tuple.into_last(), 5.0);
+/// ```
+pub trait TupleLast {
+    /// The type of the last element.
+    type Last;
+
+    /// Returns a reference to the last element.
+    fn last(&self) -> &Self::Last;
+
+    /// Returns a mutable reference to the last element.
+    fn last_mut(&mut self) -> &mut Self::Last;
+
+    /// Consumes the tuple, returning the last element.
+    fn into_last(self) -> Self::Last;
+}
+
+macro_rules! impl_tuple_at {
+    // Generates the `TupleLast` impl once the last element is reached
+    (@last [$($A:ident),+] $idx:tt: $T:ident) => {
+        impl<$($A),+> $crate::TupleLast for ($($A,)+) {
+            type Last = $T;
+
+            #[inline]
+            fn last(&self) -> &$T {
+                &self.$idx
+            }
+
+            #[inline]
+            fn last_mut(&mut self) -> &mut $T {
+                &mut self.$idx
+            }
+
+            #[inline]
+            fn into_last(self) -> $T {
+                self.$idx
+            }
+        }
+    };
+    (@last $all:tt $idx:tt: $T:ident, $($rest:tt)+) => {
+        $crate::at::impl_tuple_at!(@last $all $($rest)+);
+    };
+    // Generates the impl for a single position, given all the tuple types
+    (@ [$($A:ident),+] $idx:tt: $T:ident) => {
+        impl<$($A),+> $crate::At<$idx> for ($($A,)+) {
+            type Element = $T;
+
+            #[inline]
+            fn at(&self) -> &$T {
+                &self.$idx
+            }
+
+            #[inline]
+            fn at_mut(&mut self) -> &mut $T {
+                &mut self.$idx
+            }
+
+            #[inline]
+            fn into_at(self) -> $T {
+                self.$idx
+            }
+        }
+    };
+    // Generates the impls for every position, given all the tuple types
+    (@ $all:tt $($idx:tt: $T:ident),+) => {
+        $($crate::at::impl_tuple_at!(@ $all $idx: $T);)+
+    };
+    ($first_idx:tt: $First:ident $(, $idx:tt: $T:ident)*) => {
+        $crate::at::impl_tuple_at!(@ [$First $(, $T)*] $first_idx: $First $(, $idx: $T)*);
+        $crate::at::impl_tuple_at!(@last [$First $(, $T)*] $first_idx: $First $(, $idx: $T)*);
+
+        impl<$First $(, $T)*> $crate::TupleFirst for ($First, $($T,)*) {
+            type First = $First;
+
+            #[inline]
+            fn first(&self) -> &$First {
+                &self.0
+            }
+
+            #[inline]
+            fn first_mut(&mut self) -> &mut $First {
+                &mut self.0
+            }
+
+            #[inline]
+            fn into_first(self) -> $First {
+                self.0
+            }
+        }
+    };
+}
+
+pub(crate) use impl_tuple_at;
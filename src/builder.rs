@@ -0,0 +1,143 @@
+//! Typestate builder tracking the populated fields of a tuple at compile
+//! time.
+
+use core::marker::PhantomData;
+
+/// Marker of a field of type `T` not yet provided to a [`TupleBuilder`].
+pub struct Vacant<T>(PhantomData<fn() -> T>);
+
+impl<T> Vacant<T> {
+    /// Creates the marker of a missing field.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T> Default for Vacant<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Trait for tuples buildable through a [`TupleBuilder`].
+pub trait Buildable {
+    /// The state of a builder with no field provided, holding one
+    /// [`Vacant`] marker per element.
+    type Vacancies;
+
+    /// Returns the state of a builder with no field provided.
+    fn vacancies() -> Self::Vacancies;
+}
+
+/// Trait for builder states holding the [`Vacant`] marker of a `T` field at
+/// the inferred position `I`.
+pub trait Fill<T, I> {
+    /// The state once the field is provided.
+    type Output;
+
+    /// Replaces the marker with `value`.
+    fn fill(self, value: T) -> Self::Output;
+}
+
+/// Builder of the tuple `T` whose state `S` records, in its type, which
+/// fields have been provided.
+///
+/// Each [`set`](TupleBuilder::set) replaces the [`Vacant`] marker of the
+/// field of the value's type, and [`build`](TupleBuilder::build) is only
+/// available once every field is provided. Setting a field twice, or a type
+/// not in the tuple, fails to compile. A type appearing multiple times in the
+/// tuple makes its position ambiguous, which also fails to compile.
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::TupleBuilder;
+///
+/// let config: (u16, &str, bool) =
+///     TupleBuilder::new().set("localhost").set(true).set(8080u16).build();
+/// assert_eq!(config, (8080, "localhost", true));
+/// ```
+///
+/// Building with a missing field fails to compile:
+///
+/// ```compile_fail
+/// use tuple_set::TupleBuilder;
+///
+/// let config: (u16, &str) = TupleBuilder::new().set(8080u16).build();
+/// ```
+pub struct TupleBuilder<T, S> {
+    pub(crate) state: S,
+    pub(crate) target: PhantomData<fn() -> T>,
+}
+
+impl<T: Buildable> TupleBuilder<T, T::Vacancies> {
+    /// Creates a builder with no field provided.
+    #[must_use]
+    pub fn new() -> Self {
+        Self { state: T::vacancies(), target: PhantomData }
+    }
+}
+
+impl<T: Buildable> Default for TupleBuilder<T, T::Vacancies> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, S> TupleBuilder<T, S> {
+    /// Provides the field of type `V`.
+    pub fn set<V, I>(self, value: V) -> TupleBuilder<T, S::Output>
+    where
+        S: Fill<V, I>,
+    {
+        TupleBuilder { state: self.state.fill(value), target: PhantomData }
+    }
+}
+
+impl<T> TupleBuilder<T, T> {
+    /// Returns the tuple, once every field is provided.
+    pub fn build(self) -> T {
+        self.state
+    }
+}
+
+// Generates one `Fill` impl per position, walking the tuple while keeping
+// track of the elements before and after the current one
+macro_rules! impl_fill {
+    (@ [$($before:ident),*] $idx:tt: $T:ident $(, $after_idx:tt: $after:ident)*) => {
+        #[allow(non_snake_case)]
+        impl<$($before,)* $T, $($after,)*> $crate::Fill<$T, $crate::Position<$idx>>
+            for ($($before,)* $crate::Vacant<$T>, $($after,)*)
+        {
+            type Output = ($($before,)* $T, $($after,)*);
+
+            #[inline]
+            fn fill(self, value: $T) -> Self::Output {
+                let ($($before,)* _, $($after,)*) = self;
+                ($($before,)* value, $($after,)*)
+            }
+        }
+
+        $crate::builder::impl_fill!(@ [$($before,)* $T] $($after_idx: $after),*);
+    };
+    (@ [$($before:ident),*]) => {};
+}
+
+macro_rules! impl_tuple_builder {
+    ($($idx:tt: $T:ident),+) => {
+        impl<$($T),+> $crate::Buildable for ($($T,)+) {
+            type Vacancies = ($($crate::Vacant<$T>,)+);
+
+            #[inline]
+            fn vacancies() -> Self::Vacancies {
+                ($($crate::Vacant::<$T>::new(),)+)
+            }
+        }
+
+        $crate::builder::impl_fill!(@ [] $($idx: $T),+);
+    };
+}
+
+pub(crate) use impl_fill;
+pub(crate) use impl_tuple_builder;
@@ -0,0 +1,277 @@
+//! Canonical permutations of tuples, sorting their elements by type key.
+//!
+//! Stable Rust cannot order types by their `TypeId` at compile time, so every
+//! element type carries a type-level key through [`CanonicalKey`], assigned
+//! in order by [`canonical_order!`](crate::canonical_order). The keys are
+//! compared by the trait solver, which insertion-sorts the tuple type into
+//! its [`Canonical`](Canonicalize::Canonical) shape.
+
+use core::marker::PhantomData;
+
+/// Type-level key of the first type of an order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Zero;
+
+/// Type-level key following the key `K`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Succ<K>(PhantomData<K>);
+
+/// Trait for types carrying a key in the canonical type order.
+///
+/// Keys are chains of [`Succ`] over [`Zero`], best assigned through
+/// [`canonical_order!`](crate::canonical_order). It is provided for the
+/// unit and primitive types, ordered from `()` to `&'static str`.
+pub trait CanonicalKey {
+    /// The type-level key of the type.
+    type Key;
+}
+
+/// Marker for a key ordered before another one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Less;
+
+/// Marker for a key ordered after another one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Greater;
+
+/// Trait comparing two type-level keys.
+///
+/// Equal keys are deliberately left without an implementation, so that
+/// canonicalizing a tuple holding two types with the same key, or the same
+/// type twice, is rejected at compile time.
+pub trait KeyOrder<Other> {
+    /// Either [`Less`] or [`Greater`].
+    type Ordering;
+}
+
+impl<K> KeyOrder<Succ<K>> for Zero {
+    type Ordering = Less;
+}
+
+impl<K> KeyOrder<Zero> for Succ<K> {
+    type Ordering = Greater;
+}
+
+impl<K: KeyOrder<Other>, Other> KeyOrder<Succ<Other>> for Succ<K> {
+    type Ordering = K::Ordering;
+}
+
+/// Trait for canonical tuples accepting a new element at the position given
+/// by its key.
+pub trait CanonicalInsert<T> {
+    /// The canonical tuple holding `T` as well.
+    type Output;
+
+    /// Inserts `value` keeping the tuple canonical.
+    fn canonical_insert(self, value: T) -> Self::Output;
+}
+
+/// Trait inserting `T` in a canonical tuple once its key has been compared
+/// with the key of the first element, yielding `Ordering`.
+pub trait CanonicalInsertOrdered<T, Ordering> {
+    /// The canonical tuple holding `T` as well.
+    type Output;
+
+    /// Inserts `value` keeping the tuple canonical.
+    fn canonical_insert_ordered(self, value: T) -> Self::Output;
+}
+
+/// Trait converting a tuple into its canonical permutation, with elements
+/// sorted by their [`CanonicalKey`].
+///
+/// Tuples holding the same types in any order share the same canonical
+/// type, so contexts assembling the same types in different orders can be
+/// normalized to one shape. This only holds for types keyed in a single
+/// order, as described in [`canonical_order!`](crate::canonical_order).
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::{Canonicalize, canonical_order};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Host(&'static str);
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Port(u16);
+///
+/// canonical_order!(after &'static str => Host, Port);
+///
+/// let left = (Port(80), true, Host("localhost")).canonicalize();
+/// let right = (Host("localhost"), Port(80), true).canonicalize();
+/// assert_eq!(left, right);
+/// assert_eq!(left, (true, Host("localhost"), Port(80)));
+/// ```
+pub trait Canonicalize {
+    /// The canonical permutation of the tuple.
+    type Canonical;
+
+    /// Reorders the elements of the tuple into its canonical permutation.
+    fn canonicalize(self) -> Self::Canonical;
+}
+
+/// Implements [`CanonicalKey`] for the given types, assigning increasing keys
+/// in the order they are listed.
+///
+/// Keys start from [`Zero`], or follow the key of the type given after
+/// `after`. The unit and primitive types are keyed from `()` to
+/// `&'static str`, so types listed after `&'static str` never collide with
+/// them. Types sharing a key cannot be canonicalized together.
+///
+/// Keys are not derived from the types, so they must be coordinated: two
+/// crates both keying their types after `&'static str` assign them the same
+/// keys. Types meant to be canonicalized together should be keyed from a
+/// single crate, such as the one defining the shared context types, while
+/// downstream crates continue after the last type it keys.
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::{Canonicalize, canonical_order};
+///
+/// struct Host;
+/// struct Port;
+/// struct Timeout;
+///
+/// canonical_order!(after &'static str => Host, Port);
+/// canonical_order!(after Port => Timeout);
+///
+/// let (_, _, _): (Host, Port, Timeout) = (Timeout, Port, Host).canonicalize();
+/// ```
+#[macro_export]
+macro_rules! canonical_order {
+    (@after $previous:ty; $T:ty $(, $rest:ty)*) => {
+        impl $crate::CanonicalKey for $T {
+            type Key = $crate::Succ<<$previous as $crate::CanonicalKey>::Key>;
+        }
+
+        $crate::canonical_order!(@after $T; $($rest),*);
+    };
+    (@after $previous:ty;) => {};
+    (after $previous:ty => $($T:ty),+ $(,)?) => {
+        $crate::canonical_order!(@after $previous; $($T),+);
+    };
+    ($first:ty $(, $rest:ty)* $(,)?) => {
+        impl $crate::CanonicalKey for $first {
+            type Key = $crate::Zero;
+        }
+
+        $crate::canonical_order!(@after $first; $($rest),*);
+    };
+}
+
+canonical_order!(
+    (),
+    bool,
+    char,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    f32,
+    f64,
+    &'static str
+);
+
+impl<T> CanonicalInsert<T> for () {
+    type Output = (T,);
+
+    #[inline]
+    fn canonical_insert(self, value: T) -> Self::Output {
+        (value,)
+    }
+}
+
+impl Canonicalize for () {
+    type Canonical = ();
+
+    #[inline]
+    fn canonicalize(self) -> Self::Canonical {}
+}
+
+macro_rules! impl_tuple_canonical {
+    ($first_idx:tt: $First:ident $(, $idx:tt: $T:ident)*) => {
+        #[allow(non_snake_case)]
+        impl<Inserted, $First, $($T),*> $crate::CanonicalInsert<Inserted> for ($First, $($T,)*)
+        where
+            Inserted: $crate::CanonicalKey,
+            $First: $crate::CanonicalKey,
+            Inserted::Key: $crate::KeyOrder<$First::Key>,
+            Self: $crate::CanonicalInsertOrdered<
+                Inserted,
+                <Inserted::Key as $crate::KeyOrder<$First::Key>>::Ordering,
+            >,
+        {
+            type Output = <Self as $crate::CanonicalInsertOrdered<
+                Inserted,
+                <Inserted::Key as $crate::KeyOrder<$First::Key>>::Ordering,
+            >>::Output;
+
+            #[inline]
+            fn canonical_insert(self, value: Inserted) -> Self::Output {
+                $crate::CanonicalInsertOrdered::canonical_insert_ordered(self, value)
+            }
+        }
+
+        #[allow(non_snake_case)]
+        impl<Inserted, $First, $($T),*> $crate::CanonicalInsertOrdered<Inserted, $crate::Less>
+            for ($First, $($T,)*)
+        {
+            type Output = (Inserted, $First, $($T,)*);
+
+            #[inline]
+            fn canonical_insert_ordered(self, value: Inserted) -> Self::Output {
+                let ($First, $($T,)*) = self;
+                (value, $First, $($T,)*)
+            }
+        }
+
+        #[allow(non_snake_case)]
+        impl<Inserted, $First, $($T),*> $crate::CanonicalInsertOrdered<Inserted, $crate::Greater>
+            for ($First, $($T,)*)
+        where
+            ($($T,)*): $crate::CanonicalInsert<Inserted>,
+            <($($T,)*) as $crate::CanonicalInsert<Inserted>>::Output: $crate::Prepend<$First>,
+        {
+            type Output = <<($($T,)*) as $crate::CanonicalInsert<Inserted>>::Output as $crate::Prepend<
+                $First,
+            >>::Output;
+
+            #[inline]
+            fn canonical_insert_ordered(self, value: Inserted) -> Self::Output {
+                let ($First, $($T,)*) = self;
+                $crate::Prepend::prepend(
+                    $crate::CanonicalInsert::canonical_insert(($($T,)*), value),
+                    $First,
+                )
+            }
+        }
+
+        #[allow(non_snake_case)]
+        impl<$First, $($T),*> $crate::Canonicalize for ($First, $($T,)*)
+        where
+            ($($T,)*): $crate::Canonicalize,
+            <($($T,)*) as $crate::Canonicalize>::Canonical: $crate::CanonicalInsert<$First>,
+        {
+            type Canonical = <<($($T,)*) as $crate::Canonicalize>::Canonical as $crate::CanonicalInsert<
+                $First,
+            >>::Output;
+
+            #[inline]
+            fn canonicalize(self) -> Self::Canonical {
+                let ($First, $($T,)*) = self;
+                $crate::CanonicalInsert::canonical_insert(($($T,)*).canonicalize(), $First)
+            }
+        }
+    };
+}
+
+pub(crate) use impl_tuple_canonical;
@@ -0,0 +1,200 @@
+//! Little-endian binary codec for tuples of fixed-size primitives.
+
+use core::fmt;
+
+/// Error returned when encoding or decoding a tuple fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CodecError {
+    /// The provided buffer is shorter than the encoded tuple.
+    BufferTooShort {
+        /// Number of bytes required by the tuple.
+        required: usize,
+        /// Number of bytes available in the buffer.
+        available: usize,
+    },
+    /// The bytes of the element at `position` do not encode a valid value.
+    InvalidValue {
+        /// Position of the element in the tuple.
+        position: usize,
+    },
+}
+
+impl fmt::Display for CodecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BufferTooShort { required, available } => {
+                write!(f, "buffer too short: {required} bytes required, {available} available")
+            }
+            Self::InvalidValue { position } => {
+                write!(f, "invalid value encoded for the element at position {position}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for CodecError {}
+
+/// Trait for fixed-size values with a little-endian byte representation.
+pub trait LePrimitive: Sized {
+    /// Number of bytes of the encoded value.
+    const SIZE: usize;
+
+    /// Writes the little-endian representation into `bytes`, which is exactly
+    /// `SIZE` bytes long.
+    fn write_le(&self, bytes: &mut [u8]);
+
+    /// Reads a value from `bytes`, which is exactly `SIZE` bytes long.
+    ///
+    /// Returns `None` if the bytes do not encode a valid value.
+    fn read_le(bytes: &[u8]) -> Option<Self>;
+}
+
+macro_rules! impl_le_primitive {
+    ($($P:ty),+) => {
+        $(
+            impl LePrimitive for $P {
+                const SIZE: usize = core::mem::size_of::<$P>();
+
+                #[inline]
+                fn write_le(&self, bytes: &mut [u8]) {
+                    bytes.copy_from_slice(&self.to_le_bytes());
+                }
+
+                #[inline]
+                fn read_le(bytes: &[u8]) -> Option<Self> {
+                    bytes.try_into().ok().map(<$P>::from_le_bytes)
+                }
+            }
+        )+
+    };
+}
+
+impl_le_primitive!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+impl LePrimitive for bool {
+    const SIZE: usize = 1;
+
+    #[inline]
+    fn write_le(&self, bytes: &mut [u8]) {
+        bytes[0] = u8::from(*self);
+    }
+
+    #[inline]
+    fn read_le(bytes: &[u8]) -> Option<Self> {
+        match bytes {
+            [0] => Some(false),
+            [1] => Some(true),
+            _ => None,
+        }
+    }
+}
+
+impl LePrimitive for char {
+    const SIZE: usize = 4;
+
+    #[inline]
+    fn write_le(&self, bytes: &mut [u8]) {
+        u32::from(*self).write_le(bytes);
+    }
+
+    #[inline]
+    fn read_le(bytes: &[u8]) -> Option<Self> {
+        u32::read_le(bytes).and_then(char::from_u32)
+    }
+}
+
+/// Trait for encoding tuples of [`LePrimitive`] values as little-endian bytes,
+/// without allocating.
+pub trait TupleCodec: Sized {
+    /// Total number of bytes of the encoded tuple.
+    const ENCODED_SIZE: usize;
+
+    /// Writes the tuple into the start of `buffer`, returning the number of
+    /// bytes written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleCodec;
+    ///
+    /// let mut buffer = [0u8; <(u16, bool)>::ENCODED_SIZE];
+    /// assert_eq!((0x0102u16, true).to_le_bytes(&mut buffer), Ok(3));
+    /// assert_eq!(buffer, [0x02, 0x01, 1]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CodecError::BufferTooShort`] if `buffer` is shorter than
+    /// [`ENCODED_SIZE`](TupleCodec::ENCODED_SIZE).
+    fn to_le_bytes(&self, buffer: &mut [u8]) -> Result<usize, CodecError>;
+
+    /// Reads a tuple from the start of `bytes`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleCodec;
+    ///
+    /// let tuple = <(u16, bool)>::from_le_bytes(&[0x02, 0x01, 1]);
+    /// assert_eq!(tuple, Ok((0x0102u16, true)));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CodecError::BufferTooShort`] if `bytes` is shorter than
+    /// [`ENCODED_SIZE`](TupleCodec::ENCODED_SIZE), or
+    /// [`CodecError::InvalidValue`] if an element cannot be decoded.
+    fn from_le_bytes(bytes: &[u8]) -> Result<Self, CodecError>;
+}
+
+#[inline]
+pub(crate) fn check_len(required: usize, available: usize) -> Result<(), CodecError> {
+    if available < required {
+        return Err(CodecError::BufferTooShort { required, available });
+    }
+    Ok(())
+}
+
+// Splits the first `len` bytes off the cursor, advancing it
+#[inline]
+pub(crate) fn split_off<'a>(cursor: &mut &'a [u8], len: usize) -> &'a [u8] {
+    let (head, tail) = cursor.split_at(len);
+    *cursor = tail;
+    head
+}
+
+// Splits the first `len` mutable bytes off the cursor, advancing it
+#[inline]
+pub(crate) fn split_off_mut<'a>(cursor: &mut &'a mut [u8], len: usize) -> &'a mut [u8] {
+    let (head, tail) = core::mem::take(cursor).split_at_mut(len);
+    *cursor = tail;
+    head
+}
+
+macro_rules! impl_tuple_codec {
+    ($($idx:tt: $T:ident),+) => {
+        impl<$($T: $crate::LePrimitive),+> $crate::TupleCodec for ($($T,)+) {
+            const ENCODED_SIZE: usize = 0 $(+ $T::SIZE)+;
+
+            fn to_le_bytes(&self, buffer: &mut [u8]) -> Result<usize, $crate::CodecError> {
+                $crate::codec::check_len(Self::ENCODED_SIZE, buffer.len())?;
+                let mut cursor = buffer;
+                $(
+                    self.$idx.write_le($crate::codec::split_off_mut(&mut cursor, $T::SIZE));
+                )+
+                Ok(Self::ENCODED_SIZE)
+            }
+
+            fn from_le_bytes(bytes: &[u8]) -> Result<Self, $crate::CodecError> {
+                $crate::codec::check_len(Self::ENCODED_SIZE, bytes.len())?;
+                let mut cursor = bytes;
+                Ok(($(
+                    $T::read_le($crate::codec::split_off(&mut cursor, $T::SIZE))
+                        .ok_or($crate::CodecError::InvalidValue { position: $idx })?,
+                )+))
+            }
+        }
+    };
+}
+
+pub(crate) use impl_tuple_codec;
@@ -0,0 +1,101 @@
+//! Type-based tuple accessors usable in const contexts, relying on unstable
+//! const trait implementations.
+
+use core::any::TypeId;
+
+/// Returns whether `A` and `B` are the same type, in const contexts.
+#[inline]
+pub(crate) const fn same_type<A: 'static, B: 'static>() -> bool {
+    TypeId::of::<A>() == TypeId::of::<B>()
+}
+
+/// Counterpart of the read-only [`TupleSet`](crate::TupleSet) accessors
+/// callable in `const fn`, so tuple-backed configuration can be resolved
+/// entirely at compile time.
+///
+/// Methods are prefixed with `const_` so they do not collide with those of
+/// [`TupleSet`](crate::TupleSet) when both traits are in scope.
+///
+/// Requires the `unstable-const` feature and a nightly compiler.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(const_trait_impl)]
+///
+/// use tuple_set::ConstTupleSet;
+///
+/// const CONFIG: (u16, &str, bool) = (8080, "localhost", true);
+/// const PORT: u16 = *CONFIG.const_get::<u16>().unwrap();
+/// const POSITION: Option<usize> = CONFIG.const_position::<bool>();
+///
+/// assert_eq!(PORT, 8080);
+/// assert_eq!(POSITION, Some(2));
+/// ```
+pub const trait ConstTupleSet {
+    /// Returns the number of elements of type `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(const_trait_impl)]
+    ///
+    /// use tuple_set::ConstTupleSet;
+    ///
+    /// const fn has_port<C: [const] ConstTupleSet>(config: &C) -> bool {
+    ///     config.const_count::<u16>() == 1
+    /// }
+    ///
+    /// const _: () = assert!(has_port(&(8080u16, "localhost")));
+    /// const _: () = assert!(!has_port(&(true,)));
+    /// ```
+    fn const_count<T: 'static>(&self) -> usize;
+
+    /// Returns the position of the element of type `T` if it appears exactly
+    /// once, or `None` otherwise.
+    fn const_position<T: 'static>(&self) -> Option<usize>;
+
+    /// Get a reference to the value of type `T` if it appears exactly once,
+    /// or `None` otherwise.
+    fn const_get<T: 'static>(&self) -> Option<&T>;
+}
+
+macro_rules! impl_tuple_const {
+    ($($idx:tt: $T:ident),+) => {
+        impl<$($T: 'static),+> const $crate::ConstTupleSet for ($($T,)+) {
+            #[inline]
+            fn const_count<Target: 'static>(&self) -> usize {
+                0 $(+ $crate::const_access::same_type::<Target, $T>() as usize)+
+            }
+
+            #[inline]
+            fn const_position<Target: 'static>(&self) -> Option<usize> {
+                if self.const_count::<Target>() != 1 {
+                    return None;
+                }
+                $(
+                    if $crate::const_access::same_type::<Target, $T>() {
+                        return Some($idx);
+                    }
+                )+
+                None
+            }
+
+            #[inline]
+            fn const_get<Target: 'static>(&self) -> Option<&Target> {
+                if self.const_count::<Target>() != 1 {
+                    return None;
+                }
+                $(
+                    if $crate::const_access::same_type::<Target, $T>() {
+                        // SAFETY: We've verified Target == $T via TypeId
+                        return Some(unsafe { &*(&raw const self.$idx).cast::<Target>() });
+                    }
+                )+
+                None
+            }
+        }
+    };
+}
+
+pub(crate) use impl_tuple_const;
@@ -0,0 +1,72 @@
+//! Occurrence counts of tuple element types as associated constants, relying
+//! on unstable const `TypeId` comparisons.
+
+/// Trait exposing the number of elements of type `T` of a tuple type as a
+/// constant.
+///
+/// Unlike [`TupleSet::count`](crate::TupleSet::count), the count is known at
+/// compile time, so a duplicated or missing type can be rejected by a const
+/// assertion instead of surfacing as a `None` at runtime.
+///
+/// Requires the `unstable-const-type-id` feature and a nightly compiler.
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::{CountOf, TupleSet};
+///
+/// type Config = (u16, &'static str, bool, u8, u8);
+///
+/// const { assert!(<Config as CountOf<u16>>::COUNT == 1) };
+/// const { assert!(<Config as CountOf<u8>>::COUNT == 2) };
+///
+/// let config: Config = (8080, "localhost", true, 1, 2);
+/// assert_eq!(config.get::<u16>(), Some(&8080));
+/// ```
+pub trait CountOf<T> {
+    /// Number of elements of type `T`.
+    const COUNT: usize;
+}
+
+/// Trait exposing, as a constant, whether a tuple type shares no element type
+/// with the tuple type `Other`.
+///
+/// Unlike [`TupleTypes::is_disjoint_from`](crate::TupleTypes::is_disjoint_from),
+/// the answer is known at compile time, so code concatenating two tuples can
+/// reject an overlap, which would make a type ambiguous, by a const assertion.
+///
+/// Requires the `unstable-const-type-id` feature and a nightly compiler.
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::DisjointFrom;
+///
+/// type Physics = (f32, [f32; 3]);
+/// type Audio = (u8, bool);
+///
+/// const { assert!(<Physics as DisjointFrom<Audio>>::DISJOINT) };
+/// const { assert!(!<Physics as DisjointFrom<(u8, f32)>>::DISJOINT) };
+/// ```
+pub trait DisjointFrom<Other> {
+    /// Whether no element type appears in both tuple types.
+    const DISJOINT: bool;
+}
+
+macro_rules! impl_tuple_count_of {
+    ($($idx:tt: $T:ident),+) => {
+        impl<Target: 'static, $($T: 'static),+> $crate::CountOf<Target> for ($($T,)+) {
+            const COUNT: usize = 0 $(
+                + (core::any::TypeId::of::<Target>() == core::any::TypeId::of::<$T>()) as usize
+            )+;
+        }
+
+        impl<Other: $($crate::CountOf<$T> +)+, $($T: 'static),+> $crate::DisjointFrom<Other>
+            for ($($T,)+)
+        {
+            const DISJOINT: bool = $(<Other as $crate::CountOf<$T>>::COUNT == 0)&&+;
+        }
+    };
+}
+
+pub(crate) use impl_tuple_count_of;
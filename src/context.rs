@@ -0,0 +1,118 @@
+//! Thread-local stack of context tuples, retrievable by type.
+
+use core::any::TypeId;
+use std::{cell::RefCell, thread_local, vec::Vec};
+
+/// Trait for tuples whose fields can be looked up by a `TypeId` known only
+/// at runtime, so that they can be pushed on the context stack of
+/// [`with_context`].
+///
+/// # Safety
+///
+/// [`field_ptr`](ContextTuple::field_ptr) must return either `None` or a
+/// pointer to a field of the tuple whose type has the given `TypeId`, as
+/// [`with_current`] dereferences it for as long as the tuple is borrowed.
+pub unsafe trait ContextTuple {
+    /// Returns a pointer to the field whose type has the given `TypeId`, if
+    /// that type appears exactly once in the tuple.
+    fn field_ptr(&self, type_id: TypeId) -> Option<*const ()>;
+}
+
+thread_local! {
+    static CONTEXTS: RefCell<Vec<*const dyn ContextTuple>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Pops the context pushed by [`with_context`], even if its closure panics.
+struct PopOnDrop;
+
+impl Drop for PopOnDrop {
+    fn drop(&mut self) {
+        CONTEXTS.with_borrow_mut(Vec::pop);
+    }
+}
+
+/// Runs `f` with `context` pushed on the thread-local context stack, so that
+/// its fields can be retrieved by type through [`current`] or
+/// [`with_current`] anywhere within `f`.
+///
+/// Contexts can be nested: lookups search from the innermost context
+/// outwards, and use the first one in which the type appears exactly once.
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::{current, with_context};
+///
+/// fn log_prefix() -> String {
+///     format!("[{}]", current::<&str>().unwrap_or("anonymous"))
+/// }
+///
+/// with_context(&("worker", 3u32), || {
+///     assert_eq!(log_prefix(), "[worker]");
+///
+///     // The inner context shadows the outer one for `&str` only
+///     with_context(&("nested",), || {
+///         assert_eq!(log_prefix(), "[nested]");
+///         assert_eq!(current::<u32>(), Some(3));
+///     });
+/// });
+/// assert_eq!(log_prefix(), "[anonymous]");
+/// ```
+pub fn with_context<T: ContextTuple + 'static, R>(context: &T, f: impl FnOnce() -> R) -> R {
+    CONTEXTS.with_borrow_mut(|contexts| contexts.push(context as &dyn ContextTuple));
+    let _guard = PopOnDrop;
+    f()
+}
+
+/// Calls `f` with a reference to the field of type `U` of the innermost
+/// context in which it appears exactly once.
+///
+/// Returns `None` if no context pushed by [`with_context`] provides `U`.
+pub fn with_current<U: 'static, R>(f: impl FnOnce(&U) -> R) -> Option<R> {
+    // The stack is released before calling `f`, which may push further
+    // contexts
+    let field = CONTEXTS.with_borrow(|contexts| {
+        contexts.iter().rev().find_map(|context| {
+            // SAFETY: contexts are only on the stack while the call to
+            // `with_context` borrowing them is running
+            unsafe { &**context }.field_ptr(TypeId::of::<U>())
+        })
+    })?;
+
+    // SAFETY: `ContextTuple` guarantees that the pointer refers to a field
+    // of type `U` of the context, which outlives this call as it was pushed
+    // by an enclosing `with_context`
+    Some(f(unsafe { &*field.cast::<U>() }))
+}
+
+/// Returns a clone of the field of type `U` of the innermost context in
+/// which it appears exactly once.
+///
+/// Returns `None` if no context pushed by [`with_context`] provides `U`.
+#[must_use]
+pub fn current<U: 'static + Clone>() -> Option<U> {
+    with_current(U::clone)
+}
+
+macro_rules! impl_tuple_context {
+    ($($idx:tt: $T:ident),+) => {
+        // SAFETY: the pointer is only returned for the position of the field
+        // whose type has the given `TypeId`
+        unsafe impl<$($T: 'static),+> $crate::ContextTuple for ($($T,)+) {
+            fn field_ptr(&self, type_id: core::any::TypeId) -> Option<*const ()> {
+                let type_ids = [$(core::any::TypeId::of::<$T>()),+];
+                let mut positions = (0..type_ids.len()).filter(|&position| type_ids[position] == type_id);
+                let position = positions.next()?;
+                if positions.next().is_some() {
+                    return None;
+                }
+                match position {
+                    $($idx => Some((&raw const self.$idx).cast::<()>()),)+
+                    _ => unreachable!(),
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use impl_tuple_context;
@@ -0,0 +1,348 @@
+//! Whole-tuple element-wise conversions and combinations.
+
+/// Trait converting a tuple into another tuple of the same arity, where each
+/// target element implements `From` the corresponding source element.
+pub trait MapTypes<Target> {
+    /// Converts each element of the tuple via `From`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::MapTypes;
+    ///
+    /// let upgraded: (u64, f64, i32) = (7u32, 1.5f32, 3i8).map_types();
+    /// assert_eq!(upgraded, (7, 1.5, 3));
+    /// ```
+    fn map_types(self) -> Target;
+}
+
+/// Trait fallibly converting a tuple into another tuple of the same arity,
+/// where each source element implements `TryInto` the corresponding target
+/// element and every conversion error converts into `E`.
+pub trait TryMapTypes<Target, E> {
+    /// Converts each element of the tuple via `TryInto`, stopping at the first
+    /// failure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::num::TryFromIntError;
+    ///
+    /// use tuple_set::TryMapTypes;
+    ///
+    /// let narrowed: Result<(u8, i16), TryFromIntError> = (200u32, -5i64).try_map_types();
+    /// assert_eq!(narrowed, Ok((200, -5)));
+    ///
+    /// let overflow: Result<(u8, i16), TryFromIntError> = (300u32, -5i64).try_map_types();
+    /// assert!(overflow.is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the first element whose conversion fails.
+    fn try_map_types(self) -> Result<Target, E>;
+}
+
+/// Function callable on pairs of values of different types, used to combine
+/// tuples element-wise through [`ZipWith`].
+///
+/// Closures cannot be generic over their argument types, so heterogeneous
+/// combinations implement this trait once per pair of element types.
+pub trait ZipFn<A, B> {
+    /// The combined value.
+    type Output;
+
+    /// Combines `a` and `b`.
+    fn call(&mut self, a: A, b: B) -> Self::Output;
+}
+
+/// Trait combining the corresponding elements of two tuples of the same
+/// arity into a third tuple.
+pub trait ZipWith<Other, F> {
+    /// The tuple of combined values.
+    type Output;
+
+    /// Combines each element of `self` with the corresponding element of
+    /// `other`, in positional order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::time::Duration;
+    ///
+    /// use tuple_set::{ZipFn, ZipWith};
+    ///
+    /// struct Delta;
+    ///
+    /// impl ZipFn<u64, u64> for Delta {
+    ///     type Output = i64;
+    ///
+    ///     fn call(&mut self, current: u64, previous: u64) -> i64 {
+    ///         current as i64 - previous as i64
+    ///     }
+    /// }
+    ///
+    /// impl ZipFn<Duration, Duration> for Delta {
+    ///     type Output = Duration;
+    ///
+    ///     fn call(&mut self, current: Duration, previous: Duration) -> Duration {
+    ///         current.saturating_sub(previous)
+    ///     }
+    /// }
+    ///
+    /// let current = (10u64, Duration::from_secs(5));
+    /// let previous = (12u64, Duration::from_secs(2));
+    /// assert_eq!(current.zip_with(previous, Delta), (-2, Duration::from_secs(3)));
+    /// ```
+    fn zip_with(self, other: Other, f: F) -> Self::Output;
+}
+
+/// [`ZipFn`] pairing its arguments, used by [`Zip`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Pair;
+
+impl<A, B> ZipFn<A, B> for Pair {
+    type Output = (A, B);
+
+    #[inline]
+    fn call(&mut self, a: A, b: B) -> (A, B) {
+        (a, b)
+    }
+}
+
+/// [`ZipFn`] appending its second argument to the tuple given as its first,
+/// used to zip any number of tuples.
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::{Push, Zip, ZipWith};
+///
+/// let zipped = (1u8, 'a')
+///     .zip((2u16, 'b'))
+///     .zip_with((3u32, 'c'), Push)
+///     .zip_with((4u64, 'd'), Push);
+/// assert_eq!(zipped, ((1, 2, 3, 4), ('a', 'b', 'c', 'd')));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Push;
+
+/// Trait zipping two tuples of the same arity into a tuple of pairs.
+pub trait Zip<Other> {
+    /// The tuple of pairs.
+    type Output;
+
+    /// Pairs each element of `self` with the corresponding element of
+    /// `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::Zip;
+    ///
+    /// assert_eq!((1u8, "a").zip(('x', true)), ((1, 'x'), ("a", true)));
+    /// ```
+    fn zip(self, other: Other) -> Self::Output;
+}
+
+impl<S: ZipWith<Other, Pair>, Other> Zip<Other> for S {
+    type Output = S::Output;
+
+    #[inline]
+    fn zip(self, other: Other) -> Self::Output {
+        self.zip_with(other, Pair)
+    }
+}
+
+/// Trait zipping three tuples of the same arity into a tuple of triples.
+///
+/// Longer zips chain [`ZipWith::zip_with`] with [`Push`].
+pub trait Zip3<B, C> {
+    /// The tuple of triples.
+    type Output;
+
+    /// Groups each element of `self` with the corresponding elements of `b`
+    /// and `c`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::Zip3;
+    ///
+    /// let morning = (12.5f64, 3u32);
+    /// let noon = (18.0f64, 5u32);
+    /// let evening = (15.5f64, 4u32);
+    ///
+    /// let aligned = morning.zip3(noon, evening);
+    /// assert_eq!(aligned, ((12.5, 18.0, 15.5), (3, 5, 4)));
+    /// ```
+    fn zip3(self, b: B, c: C) -> Self::Output;
+}
+
+impl<S, B, C> Zip3<B, C> for S
+where
+    S: ZipWith<B, Pair>,
+    S::Output: ZipWith<C, Push>,
+{
+    type Output = <S::Output as ZipWith<C, Push>>::Output;
+
+    #[inline]
+    fn zip3(self, b: B, c: C) -> Self::Output {
+        self.zip_with(b, Pair).zip_with(c, Push)
+    }
+}
+
+/// Trait converting a reference to a tuple into a tuple of references to its
+/// elements, e.g. `&(A, B)` into `(&A, &B)`.
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::AsRefs;
+///
+/// fn describe((name, retries): (&String, &u32)) -> String {
+///     format!("{name} ({retries} retries)")
+/// }
+///
+/// let mut job = (String::from("backup"), 3u32);
+/// assert_eq!(describe(job.as_refs()), "backup (3 retries)");
+///
+/// let (name, retries) = job.as_muts();
+/// name.push_str("-nightly");
+/// *retries += 1;
+/// assert_eq!(job, (String::from("backup-nightly"), 4));
+/// ```
+pub trait AsRefs {
+    /// The tuple of shared references to the elements.
+    type Refs<'a>
+    where
+        Self: 'a;
+
+    /// The tuple of mutable references to the elements.
+    type Muts<'a>
+    where
+        Self: 'a;
+
+    /// Returns a tuple of shared references to the elements.
+    fn as_refs(&self) -> Self::Refs<'_>;
+
+    /// Returns a tuple of mutable references to the elements.
+    fn as_muts(&mut self) -> Self::Muts<'_>;
+}
+
+/// Trait cloning the referents of a tuple of shared references, e.g.
+/// `(&A, &B)` into `(A, B)`, mirroring [`Iterator::cloned`].
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::{AsRefs, Cloned};
+///
+/// let job = (String::from("backup"), vec![3u32, 5]);
+/// let copy: (String, Vec<u32>) = job.as_refs().cloned();
+/// assert_eq!(copy, job);
+/// ```
+pub trait Cloned {
+    /// The tuple of cloned values.
+    type Output;
+
+    /// Clones each referenced element.
+    fn cloned(self) -> Self::Output;
+}
+
+/// Trait copying the referents of a tuple of shared references, e.g.
+/// `(&A, &B)` into `(A, B)`, mirroring [`Iterator::copied`].
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::{AsRefs, Copied};
+///
+/// let sample = (3u32, 0.5f64, true);
+/// let (count, ratio, valid) = sample.as_refs().copied();
+/// assert_eq!((count, ratio, valid), sample);
+/// ```
+pub trait Copied {
+    /// The tuple of copied values.
+    type Output;
+
+    /// Copies each referenced element.
+    fn copied(self) -> Self::Output;
+}
+
+macro_rules! impl_tuple_convert {
+    ($($idx:tt: $T:ident $U:ident),+) => {
+        impl<$($T, $U,)+ F> $crate::ZipWith<($($U,)+), F> for ($($T,)+)
+        where
+            $(F: $crate::ZipFn<$T, $U>,)+
+        {
+            type Output = ($(<F as $crate::ZipFn<$T, $U>>::Output,)+);
+
+            #[inline]
+            fn zip_with(self, other: ($($U,)+), mut f: F) -> Self::Output {
+                ($(<F as $crate::ZipFn<$T, $U>>::call(&mut f, self.$idx, other.$idx),)+)
+            }
+        }
+
+        impl<$($T),+> $crate::AsRefs for ($($T,)+) {
+            type Refs<'a> = ($(&'a $T,)+) where Self: 'a;
+            type Muts<'a> = ($(&'a mut $T,)+) where Self: 'a;
+
+            #[inline]
+            fn as_refs(&self) -> Self::Refs<'_> {
+                ($(&self.$idx,)+)
+            }
+
+            #[inline]
+            fn as_muts(&mut self) -> Self::Muts<'_> {
+                ($(&mut self.$idx,)+)
+            }
+        }
+
+        impl<$($T,)+ Last> $crate::ZipFn<($($T,)+), Last> for $crate::Push {
+            type Output = ($($T,)+ Last);
+
+            #[inline]
+            fn call(&mut self, init: ($($T,)+), last: Last) -> Self::Output {
+                ($(init.$idx,)+ last)
+            }
+        }
+
+        impl<$($T: Clone),+> $crate::Cloned for ($(&$T,)+) {
+            type Output = ($($T,)+);
+
+            #[inline]
+            fn cloned(self) -> Self::Output {
+                ($(self.$idx.clone(),)+)
+            }
+        }
+
+        impl<$($T: Copy),+> $crate::Copied for ($(&$T,)+) {
+            type Output = ($($T,)+);
+
+            #[inline]
+            fn copied(self) -> Self::Output {
+                ($(*self.$idx,)+)
+            }
+        }
+
+        impl<$($T, $U: From<$T>),+> $crate::MapTypes<($($U,)+)> for ($($T,)+) {
+            #[inline]
+            fn map_types(self) -> ($($U,)+) {
+                ($($U::from(self.$idx),)+)
+            }
+        }
+
+        impl<$($T: TryInto<$U>, $U,)+ E> $crate::TryMapTypes<($($U,)+), E> for ($($T,)+)
+        where
+            $(<$T as TryInto<$U>>::Error: Into<E>,)+
+        {
+            #[inline]
+            fn try_map_types(self) -> Result<($($U,)+), E> {
+                Ok(($(self.$idx.try_into().map_err(Into::into)?,)+))
+            }
+        }
+    };
+}
+
+pub(crate) use impl_tuple_convert;
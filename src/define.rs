@@ -0,0 +1,252 @@
+//! Named wrappers over tuples, with typed accessors per field.
+
+/// Defines a newtype over a tuple, with named accessors for each field and
+/// the by-type traits of the crate implemented by delegation.
+///
+/// Each field is declared as `field: Type [field_mut, set_field]`: the
+/// wrapper provides a `field()` getter, and a mutable getter and a setter
+/// with the given names, as `macro_rules!` cannot derive them from the field
+/// name. The wrapper dereferences to the tuple, and implements
+/// [`TupleSet`](crate::TupleSet), [`TupleTypes`](crate::TupleTypes),
+/// [`TupleArity`](crate::TupleArity) and [`DynFields`](crate::DynFields), so
+/// fields remain accessible by type.
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::{TupleSet, define_tuple};
+///
+/// define_tuple!(
+///     /// Connection settings.
+///     #[derive(Debug, Clone, PartialEq)]
+///     pub Config =>
+///         retries: u32 [retries_mut, set_retries],
+///         timeout: u64 [timeout_mut, set_timeout],
+///         debug: bool [debug_mut, set_debug],
+/// );
+///
+/// let mut config = Config::new(3, 30, false);
+/// config.set_debug(true);
+/// config.set_retries(5);
+/// *config.timeout_mut() *= 2;
+///
+/// assert_eq!(*config.retries(), 5);
+/// assert_eq!(config.get::<u64>(), Some(&60));
+/// assert_eq!(config.into_inner(), (5, 60, true));
+/// ```
+#[macro_export]
+macro_rules! define_tuple {
+    (
+        $(#[$meta:meta])* $vis:vis $Name:ident =>
+        $($field:ident: $T:ty [$field_mut:ident, $set_field:ident]),+ $(,)?
+    ) => {
+        $(#[$meta])*
+        $vis struct $Name(pub ($($T,)+));
+
+        impl $Name {
+            /// Creates the wrapper from the value of each field.
+            #[allow(clippy::too_many_arguments)]
+            $vis fn new($($field: $T),+) -> Self {
+                Self(($($field,)+))
+            }
+
+            $crate::define_tuple!(
+                @accessors [$vis] [$($field),+] $($field: $T [$field_mut, $set_field]),+
+            );
+
+            /// Consumes the wrapper, returning the underlying tuple.
+            $vis fn into_inner(self) -> ($($T,)+) {
+                self.0
+            }
+        }
+
+        impl core::ops::Deref for $Name {
+            type Target = ($($T,)+);
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl core::ops::DerefMut for $Name {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.0
+            }
+        }
+
+        impl From<($($T,)+)> for $Name {
+            fn from(tuple: ($($T,)+)) -> Self {
+                Self(tuple)
+            }
+        }
+
+        impl From<$Name> for ($($T,)+) {
+            fn from(wrapper: $Name) -> Self {
+                wrapper.0
+            }
+        }
+
+        impl $crate::TupleSet for $Name {
+            $crate::__delegate_tuple_set!(0);
+        }
+
+        // SAFETY: the tuple is stored inline in the wrapper, and the pointers
+        // are delegated to it
+        unsafe impl $crate::InlineFields for $Name {}
+
+        impl $crate::TupleArity for $Name {
+            const ARITY: usize = <($($T,)+) as $crate::TupleArity>::ARITY;
+        }
+
+        impl $crate::DynFields for $Name {
+            fn dyn_fields(
+                &self,
+            ) -> impl ExactSizeIterator<Item = (&'static str, &dyn core::any::Any)> {
+                $crate::DynFields::dyn_fields(&self.0)
+            }
+
+            fn dyn_fields_mut(
+                &mut self,
+            ) -> impl ExactSizeIterator<Item = (&'static str, &mut dyn core::any::Any)> {
+                $crate::DynFields::dyn_fields_mut(&mut self.0)
+            }
+        }
+
+        impl $crate::TupleTypes for $Name {
+            type TypeIds = <($($T,)+) as $crate::TupleTypes>::TypeIds;
+            type TypeNames = <($($T,)+) as $crate::TupleTypes>::TypeNames;
+
+            fn type_ids() -> Self::TypeIds {
+                <($($T,)+) as $crate::TupleTypes>::type_ids()
+            }
+
+            fn type_names() -> Self::TypeNames {
+                <($($T,)+) as $crate::TupleTypes>::type_names()
+            }
+        }
+    };
+    // Generates the accessors of every field, given all the field names
+    (@accessors [$vis:vis] $all:tt $($field:ident: $T:ty [$field_mut:ident, $set_field:ident]),+) => {
+        $(
+            #[doc = concat!("Returns a reference to the `", stringify!($field), "` field.")]
+            $vis fn $field(&self) -> &$T {
+                $crate::define_tuple!(@field &self.0, $all $field)
+            }
+
+            #[doc = concat!("Returns a mutable reference to the `", stringify!($field), "` field.")]
+            $vis fn $field_mut(&mut self) -> &mut $T {
+                $crate::define_tuple!(@field &mut self.0, $all $field)
+            }
+
+            #[doc = concat!("Sets the `", stringify!($field), "` field.")]
+            $vis fn $set_field(&mut self, value: $T) {
+                *self.$field_mut() = value;
+            }
+        )+
+    };
+    // Binds every field of the tuple by name, returning the requested one
+    (@field $tuple:expr, [$($all:ident),+] $field:ident) => {{
+        #[allow(unused_variables)]
+        let ($($all,)+) = $tuple;
+        $field
+    }};
+}
+
+/// Implements the required methods of [`TupleSet`](crate::TupleSet), and
+/// the single-pass lookups overridden by the tuples, by delegating to the
+/// tuple stored in the given field.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __delegate_tuple_set {
+    ($field:tt) => {
+        fn count<T: 'static>(&self) -> usize {
+            $crate::TupleSet::count::<T>(&self.$field)
+        }
+
+        #[inline]
+        fn get<T: 'static>(&self) -> Option<&T> {
+            $crate::TupleSet::get::<T>(&self.$field)
+        }
+
+        #[inline]
+        fn get_mut<T: 'static>(&mut self) -> Option<&mut T> {
+            $crate::TupleSet::get_mut::<T>(&mut self.$field)
+        }
+
+        #[inline]
+        unsafe fn get_unchecked<T: 'static>(&self) -> &T {
+            unsafe { $crate::TupleSet::get_unchecked::<T>(&self.$field) }
+        }
+
+        #[inline]
+        unsafe fn map_unchecked<T: 'static, F, R>(&mut self, f: F) -> R
+        where
+            F: FnOnce(&mut T) -> R,
+        {
+            unsafe { $crate::TupleSet::map_unchecked(&mut self.$field, f) }
+        }
+
+        #[inline]
+        fn as_ptr_of<T: 'static>(&self) -> Option<*const T> {
+            $crate::TupleSet::as_ptr_of::<T>(&self.$field)
+        }
+
+        #[inline]
+        fn as_mut_ptr_of<T: 'static>(&mut self) -> Option<*mut T> {
+            $crate::TupleSet::as_mut_ptr_of::<T>(&mut self.$field)
+        }
+
+        #[inline]
+        unsafe fn as_ptr_of_unchecked<T: 'static>(&self) -> *const T {
+            unsafe { $crate::TupleSet::as_ptr_of_unchecked::<T>(&self.$field) }
+        }
+
+        #[inline]
+        unsafe fn as_mut_ptr_of_unchecked<T: 'static>(&mut self) -> *mut T {
+            unsafe { $crate::TupleSet::as_mut_ptr_of_unchecked::<T>(&mut self.$field) }
+        }
+
+        #[inline]
+        fn as_slice_of<T: 'static>(&self) -> Option<&[T]> {
+            $crate::TupleSet::as_slice_of::<T>(&self.$field)
+        }
+
+        #[inline]
+        fn as_mut_slice_of<T: 'static>(&mut self) -> Option<&mut [T]> {
+            $crate::TupleSet::as_mut_slice_of::<T>(&mut self.$field)
+        }
+
+        #[inline]
+        fn for_each_of<'a, T: 'static, F: FnMut(&'a T)>(&'a self, f: F) {
+            $crate::TupleSet::for_each_of(&self.$field, f);
+        }
+
+        #[inline]
+        fn for_each_of_mut<'a, T: 'static, F: FnMut(&'a mut T)>(&'a mut self, f: F) {
+            $crate::TupleSet::for_each_of_mut(&mut self.$field, f);
+        }
+
+        #[inline]
+        fn iter_of_mut<T: 'static>(&mut self) -> impl Iterator<Item = &mut T> {
+            $crate::TupleSet::iter_of_mut(&mut self.$field)
+        }
+
+        #[inline]
+        fn fill_from_iter<T: 'static, I: IntoIterator<Item = T>>(&mut self, iter: I) -> usize {
+            $crate::TupleSet::fill_from_iter(&mut self.$field, iter)
+        }
+
+        #[inline]
+        fn scatter_to_slice<T: 'static + Clone>(
+            &self,
+            slice: &mut [T],
+        ) -> Result<usize, $crate::SliceTooShort> {
+            $crate::TupleSet::scatter_to_slice(&self.$field, slice)
+        }
+
+        #[inline]
+        fn migrate<Target: $crate::TupleSet + Default>(self) -> Target {
+            $crate::TupleSet::migrate(self.$field)
+        }
+    };
+}
@@ -0,0 +1,112 @@
+//! Reporting of failed checked lookups through the optional `log` and `defmt`
+//! backends, and of failed unchecked lookups through panics.
+
+use core::fmt;
+
+use crate::{TupleArity, TupleSet, types};
+
+/// Returns whether `T` appears exactly once in `tuple`.
+///
+/// When the `log` or `defmt` feature is enabled, a failed lookup is reported
+/// as a warning including the requested type and the number of occurrences
+/// found. The arity is only known for the built-in implementations, which
+/// report through [`lookup_miss`].
+#[inline]
+pub(crate) fn lookup<T: 'static, S: TupleSet + ?Sized>(tuple: &S) -> bool {
+    let count = tuple.count::<T>();
+
+    #[cfg(any(feature = "log", feature = "defmt"))]
+    if count != 1 {
+        report::<T, S>(None, count);
+    }
+
+    count == 1
+}
+
+/// Reports a failed checked lookup of `T` in `tuple`, located in a single
+/// pass over its fields.
+///
+/// When the `log` or `defmt` feature is enabled, the lookup is reported as a
+/// warning as by [`lookup`], including the tuple arity; the occurrences are
+/// only counted on this path.
+#[doc(hidden)]
+#[cold]
+#[inline]
+pub fn lookup_miss<T: 'static, S: TupleSet + TupleArity + ?Sized>(tuple: &S) {
+    #[cfg(any(feature = "log", feature = "defmt"))]
+    report::<T, S>(Some(S::ARITY), tuple.count::<T>());
+
+    #[cfg(not(any(feature = "log", feature = "defmt")))]
+    let _ = tuple;
+}
+
+#[cfg(any(feature = "log", feature = "defmt"))]
+#[cold]
+#[inline(never)]
+fn report<T: 'static, S: ?Sized>(arity: Option<usize>, count: usize) {
+    #[cfg(feature = "log")]
+    log::warn!(
+        "lookup of `{}` failed in `{}`{}: found {} occurrences, expected exactly one",
+        core::any::type_name::<T>(),
+        core::any::type_name::<S>(),
+        Arity(arity),
+        count,
+    );
+
+    #[cfg(feature = "defmt")]
+    match arity {
+        Some(arity) => defmt::warn!(
+            "lookup of `{=str}` failed in `{=str}` (arity {=usize}): found {=usize} occurrences, expected exactly one",
+            core::any::type_name::<T>(),
+            core::any::type_name::<S>(),
+            arity,
+            count,
+        ),
+        None => defmt::warn!(
+            "lookup of `{=str}` failed in `{=str}`: found {=usize} occurrences, expected exactly one",
+            core::any::type_name::<T>(),
+            core::any::type_name::<S>(),
+            count,
+        ),
+    }
+}
+
+/// Formats the arity of a tuple in parentheses, if known.
+#[cfg(feature = "log")]
+struct Arity(Option<usize>);
+
+#[cfg(feature = "log")]
+impl fmt::Display for Arity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(arity) => write!(f, " (arity {arity})"),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Formats a list of type names as a tuple type, as
+/// [`write_signature`](crate::TupleTypes::write_signature) does.
+struct TypeList<'a>(&'a [&'static str]);
+
+impl fmt::Display for TypeList<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        types::write_signature(f, self.0)
+    }
+}
+
+/// Panics on an unchecked lookup of `T` in a tuple whose elements have the
+/// given type names, with `count` occurrences of `T`.
+#[doc(hidden)]
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub fn unchecked_miss<T: ?Sized>(type_names: &[&'static str], count: usize) -> ! {
+    panic!(
+        "Type '{}' not found in tuple {} (arity {}, {} occurrences). This is undefined behavior.",
+        core::any::type_name::<T>(),
+        TypeList(type_names),
+        type_names.len(),
+        count,
+    );
+}
@@ -0,0 +1,72 @@
+//! Type-erased iteration over tuple fields, for inspectors and debug consoles.
+
+use core::any::Any;
+
+/// Trait for iterating over the fields of a tuple as `(type name, &dyn Any)`
+/// pairs, in positional order.
+///
+/// This is meant for tools rendering arbitrary state, such as inspector GUIs
+/// or debug consoles: each field is labeled by the name of its type, as
+/// rendered by [`core::any::type_name`], and can be downcast to the types the
+/// tool knows how to display or edit.
+///
+/// # Examples
+///
+/// ```
+/// use core::any::Any;
+///
+/// use tuple_set::DynFields;
+///
+/// fn render(value: &dyn Any) -> String {
+///     if let Some(value) = value.downcast_ref::<u32>() {
+///         value.to_string()
+///     } else if let Some(value) = value.downcast_ref::<&str>() {
+///         format!("{value:?}")
+///     } else {
+///         "<opaque>".to_owned()
+///     }
+/// }
+///
+/// let mut state = (3u32, "idle", [0u8; 4]);
+/// let lines: Vec<_> =
+///     state.dyn_fields().map(|(name, value)| format!("{name} = {}", render(value))).collect();
+/// assert_eq!(lines, ["u32 = 3", "&str = \"idle\"", "[u8; 4] = <opaque>"]);
+///
+/// // Editing a field from the console
+/// for (_, value) in state.dyn_fields_mut() {
+///     if let Some(value) = value.downcast_mut::<u32>() {
+///         *value += 1;
+///     }
+/// }
+/// assert_eq!(state.0, 4);
+/// ```
+pub trait DynFields {
+    /// Returns an iterator over the type name of each field and a reference
+    /// to its value.
+    fn dyn_fields(&self) -> impl ExactSizeIterator<Item = (&'static str, &dyn Any)>;
+
+    /// Returns an iterator over the type name of each field and a mutable
+    /// reference to its value.
+    fn dyn_fields_mut(&mut self) -> impl ExactSizeIterator<Item = (&'static str, &mut dyn Any)>;
+}
+
+macro_rules! impl_tuple_dyn_fields {
+    ($($idx:tt: $T:ident),+) => {
+        impl<$($T: 'static),+> $crate::DynFields for ($($T,)+) {
+            #[inline]
+            fn dyn_fields(&self) -> impl ExactSizeIterator<Item = (&'static str, &dyn core::any::Any)> {
+                [$((core::any::type_name::<$T>(), &self.$idx as &dyn core::any::Any)),+].into_iter()
+            }
+
+            #[inline]
+            fn dyn_fields_mut(
+                &mut self,
+            ) -> impl ExactSizeIterator<Item = (&'static str, &mut dyn core::any::Any)> {
+                [$((core::any::type_name::<$T>(), &mut self.$idx as &mut dyn core::any::Any)),+]
+                    .into_iter()
+            }
+        }
+    };
+}
+
+pub(crate) use impl_tuple_dyn_fields;
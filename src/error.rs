@@ -0,0 +1,230 @@
+//! Errors returned by the methods of [`TupleSet`](crate::TupleSet).
+
+use core::fmt;
+
+/// Error returned when a slice is too short to hold every occurrence of a
+/// type in a tuple.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SliceTooShort {
+    /// Number of occurrences of the type in the tuple.
+    pub required: usize,
+    /// Length of the provided slice.
+    pub available: usize,
+}
+
+impl fmt::Display for SliceTooShort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "slice too short: {} elements required, {} available",
+            self.required, self.available
+        )
+    }
+}
+
+impl core::error::Error for SliceTooShort {}
+
+/// Error returned when a bounded collection cannot hold every occurrence of
+/// a type in a tuple.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CapacityExceeded {
+    /// Number of occurrences of the type in the tuple.
+    pub required: usize,
+    /// Capacity of the collection.
+    pub capacity: usize,
+}
+
+impl fmt::Display for CapacityExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "capacity exceeded: {} elements required, capacity is {}",
+            self.required, self.capacity
+        )
+    }
+}
+
+impl core::error::Error for CapacityExceeded {}
+
+/// Largest arity for which [`TupleSet`](crate::TupleSet) is implemented.
+#[cfg(feature = "diagnostics")]
+const MAX_ARITY: usize = 64;
+
+/// Error returned when a type does not appear exactly once in a tuple.
+///
+/// With the `diagnostics` feature, the error also records the positions at
+/// which the type occurs, pointing at the offending fields when the type is
+/// duplicated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LookupError {
+    type_name: &'static str,
+    count: usize,
+    #[cfg(feature = "diagnostics")]
+    positions: [u8; MAX_ARITY],
+}
+
+impl LookupError {
+    /// Creates the error for a lookup of `T` in a tuple with the given
+    /// element types.
+    pub(crate) fn new<T: 'static>(type_ids: &[core::any::TypeId]) -> Self {
+        let target = core::any::TypeId::of::<T>();
+        let occurrences = type_ids.iter().enumerate().filter(|(_, type_id)| **type_id == target);
+
+        #[cfg(feature = "diagnostics")]
+        let mut positions = [0; MAX_ARITY];
+        #[cfg(feature = "diagnostics")]
+        for (slot, (position, _)) in positions.iter_mut().zip(occurrences.clone()) {
+            // Positions within the built-in arities fit, larger ones saturate
+            *slot = u8::try_from(position).unwrap_or(u8::MAX);
+        }
+
+        Self {
+            type_name: core::any::type_name::<T>(),
+            count: occurrences.count(),
+            #[cfg(feature = "diagnostics")]
+            positions,
+        }
+    }
+
+    /// Returns the name of the type that was looked up.
+    #[must_use]
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Returns the number of occurrences of the type in the tuple, either
+    /// zero or more than one.
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns whether the type does not appear in the tuple.
+    #[must_use]
+    pub fn is_not_found(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Returns whether the type appears multiple times in the tuple.
+    #[must_use]
+    pub fn is_duplicate(&self) -> bool {
+        self.count > 1
+    }
+
+    /// Returns the positions at which the type occurs in the tuple, in
+    /// increasing order.
+    ///
+    /// Only the first 64 positions are recorded, and positions past
+    /// `u8::MAX` are reported as `u8::MAX`. Both limits can only be reached
+    /// by [`TupleTypes`](crate::TupleTypes) implementations with more elements
+    /// than the built-in tuples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// let tuple = (1u8, "hello", 2u8);
+    /// let error = tuple.try_get::<u8>().unwrap_err();
+    /// assert!(error.positions().eq([0, 2]));
+    /// ```
+    #[cfg(feature = "diagnostics")]
+    pub fn positions(&self) -> impl ExactSizeIterator<Item = usize> + '_ {
+        self.positions[..self.count.min(MAX_ARITY)].iter().map(|&position| usize::from(position))
+    }
+}
+
+impl fmt::Display for LookupError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.count == 0 {
+            return write!(f, "type `{}` not found in tuple", self.type_name);
+        }
+        write!(f, "type `{}` appears {} times in tuple", self.type_name, self.count)?;
+        #[cfg(feature = "diagnostics")]
+        for (index, position) in self.positions().enumerate() {
+            let separator = if index == 0 { " at positions " } else { ", " };
+            write!(f, "{separator}{position}")?;
+        }
+        Ok(())
+    }
+}
+
+impl core::error::Error for LookupError {}
+
+/// Reason why a value could not be stored in a tuple.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SetErrorKind {
+    /// The type does not appear in the tuple.
+    NotFound,
+    /// The type appears multiple times in the tuple.
+    Duplicate {
+        /// Number of occurrences of the type in the tuple.
+        count: usize,
+    },
+}
+
+/// Error returned when a value cannot be stored in a tuple, handing the value
+/// back together with the reason of the failure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SetError<T> {
+    value: T,
+    error: LookupError,
+}
+
+impl<T> SetError<T> {
+    /// Creates the error for storing `value` in a tuple with the given
+    /// element types.
+    pub(crate) fn new(value: T, type_ids: &[core::any::TypeId]) -> Self
+    where
+        T: 'static,
+    {
+        Self { value, error: LookupError::new::<T>(type_ids) }
+    }
+
+    /// Returns why the value could not be stored.
+    #[must_use]
+    pub fn kind(&self) -> SetErrorKind {
+        if self.error.is_not_found() {
+            SetErrorKind::NotFound
+        } else {
+            SetErrorKind::Duplicate { count: self.error.count() }
+        }
+    }
+
+    /// Returns a reference to the value that could not be stored.
+    #[must_use]
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns the value that could not be stored.
+    #[must_use]
+    pub fn into_value(self) -> T {
+        self.value
+    }
+
+    /// Returns the underlying lookup error.
+    #[must_use]
+    pub fn lookup_error(&self) -> &LookupError {
+        &self.error
+    }
+
+    /// Splits the error into the value that could not be stored and the
+    /// underlying lookup error.
+    #[must_use]
+    pub fn into_parts(self) -> (T, LookupError) {
+        (self.value, self.error)
+    }
+}
+
+impl<T> fmt::Display for SetError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cannot set value: {}", self.error)
+    }
+}
+
+impl<T: fmt::Debug> core::error::Error for SetError<T> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
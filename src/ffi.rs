@@ -0,0 +1,94 @@
+//! Layout-stable mirrors of tuples, for crossing C FFI boundaries.
+
+/// Defines a `#[repr(C)]` struct mirroring a tuple, with one named field per
+/// element, conversions from and into the tuple, and the by-type traits of
+/// the crate.
+///
+/// Tuples have no stable layout, so they cannot be passed across a C FFI
+/// boundary: the mirror lays its fields out in declaration order, following
+/// the C rules, while [`TupleSet`](crate::TupleSet) and
+/// [`TupleTypes`](crate::TupleTypes) keep fields accessible by type. The
+/// field types must themselves be FFI-safe for the mirror to be.
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::{TupleSet, define_ffi_mirror};
+///
+/// define_ffi_mirror!(
+///     /// Point shared with C code.
+///     #[derive(Debug, Clone, Copy, PartialEq)]
+///     pub Point => x: i32, y: i32, visible: bool
+/// );
+///
+/// extern "C" fn hide(mut point: Point) -> Point {
+///     point.set(false);
+///     point
+/// }
+///
+/// let point = hide(Point::from((3, 4, true)));
+/// assert_eq!(point.get::<bool>(), Some(&false));
+/// assert_eq!(point.count::<i32>(), 2);
+///
+/// let tuple: (i32, i32, bool) = point.into();
+/// assert_eq!(tuple, (3, 4, false));
+/// ```
+#[macro_export]
+macro_rules! define_ffi_mirror {
+    ($(#[$meta:meta])* $vis:vis $Name:ident => $($field:ident: $T:ty),+ $(,)?) => {
+        $(#[$meta])*
+        #[repr(C)]
+        $vis struct $Name {
+            $(
+                #[doc = concat!("The `", stringify!($field), "` field.")]
+                $vis $field: $T,
+            )+
+        }
+
+        impl $Name {
+            /// Creates the mirror from the value of each field.
+            #[allow(clippy::too_many_arguments)]
+            $vis fn new($($field: $T),+) -> Self {
+                Self { $($field),+ }
+            }
+        }
+
+        impl From<($($T,)+)> for $Name {
+            fn from(tuple: ($($T,)+)) -> Self {
+                let ($($field,)+) = tuple;
+                Self { $($field),+ }
+            }
+        }
+
+        impl From<$Name> for ($($T,)+) {
+            fn from(mirror: $Name) -> Self {
+                ($(mirror.$field,)+)
+            }
+        }
+
+        impl $crate::TupleSet for $Name {
+            $crate::__impl_tuple_set_fields!($($field: $T),+);
+        }
+
+        // SAFETY: the fields are stored inline in the mirror, and the built-in
+        // `as_ptr_of` and `as_mut_ptr_of` return pointers to them
+        unsafe impl $crate::InlineFields for $Name {}
+
+        impl $crate::TupleArity for $Name {
+            const ARITY: usize = <($($T,)+) as $crate::TupleArity>::ARITY;
+        }
+
+        impl $crate::TupleTypes for $Name {
+            type TypeIds = <($($T,)+) as $crate::TupleTypes>::TypeIds;
+            type TypeNames = <($($T,)+) as $crate::TupleTypes>::TypeNames;
+
+            fn type_ids() -> Self::TypeIds {
+                <($($T,)+) as $crate::TupleTypes>::type_ids()
+            }
+
+            fn type_names() -> Self::TypeNames {
+                <($($T,)+) as $crate::TupleTypes>::type_names()
+            }
+        }
+    };
+}
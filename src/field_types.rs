@@ -0,0 +1,115 @@
+//! Compile-time descriptors of the element types of a tuple.
+
+/// Descriptors of the element types of a tuple, as `(index, type)` pairs,
+/// built at compile time by the [`field_types!`](crate::field_types!) macro.
+///
+/// Type names are the source spelling of each type, as written in the macro
+/// invocation.
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::{FieldTypes, field_types};
+///
+/// const SHAPE: FieldTypes<3> = field_types!((u32, Vec<u8>, bool));
+///
+/// assert_eq!(SHAPE.fields(), &[(0, "u32"), (1, "Vec<u8>"), (2, "bool")]);
+/// assert_eq!(SHAPE.type_name(1), Some("Vec<u8>"));
+/// assert_eq!(SHAPE.position("bool"), Some(2));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FieldTypes<const N: usize> {
+    fields: [(usize, &'static str); N],
+}
+
+impl<const N: usize> FieldTypes<N> {
+    /// Creates the descriptors from `(index, type)` pairs.
+    #[must_use]
+    pub const fn new(fields: [(usize, &'static str); N]) -> Self {
+        Self { fields }
+    }
+
+    /// Returns the number of elements of the tuple.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    /// Returns whether the tuple has no elements.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Returns the `(index, type)` pairs, in positional order.
+    #[must_use]
+    pub const fn fields(&self) -> &[(usize, &'static str); N] {
+        &self.fields
+    }
+
+    /// Returns the name of the type at position `index`, or `None` if out of
+    /// bounds.
+    #[must_use]
+    pub const fn type_name(&self, index: usize) -> Option<&'static str> {
+        if index < N { Some(self.fields[index].1) } else { None }
+    }
+
+    /// Returns the position of the first element whose type is spelled
+    /// `name`, or `None` if no such element exists.
+    #[must_use]
+    pub const fn position(&self, name: &str) -> Option<usize> {
+        let mut index = 0;
+        while index < N {
+            if str_eq(self.fields[index].1, name) {
+                return Some(index);
+            }
+            index += 1;
+        }
+        None
+    }
+}
+
+// String equality usable in const contexts
+const fn str_eq(left: &str, right: &str) -> bool {
+    let (left, right) = (left.as_bytes(), right.as_bytes());
+    if left.len() != right.len() {
+        return false;
+    }
+    let mut index = 0;
+    while index < left.len() {
+        if left[index] != right[index] {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
+
+/// Expands to the [`FieldTypes`] descriptors of a tuple type, evaluable in
+/// const contexts.
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::field_types;
+///
+/// const ARITY: usize = field_types!((u8, &'static str)).len();
+/// assert_eq!(ARITY, 2);
+///
+/// for (index, name) in field_types!((u8, &'static str)).fields() {
+///     println!("{index}: {name}");
+/// }
+/// ```
+#[macro_export]
+macro_rules! field_types {
+    (($($T:ty),+ $(,)?)) => {
+        $crate::FieldTypes::new($crate::field_types!(@ [] 0usize; $($T),+))
+    };
+    // Accumulates the `(index, type)` pairs, one element at a time
+    (@ [$($fields:tt)*] $idx:expr; $T:ty $(, $rest:ty)*) => {
+        $crate::field_types!(@ [$($fields)* ($idx, stringify!($T)),] $idx + 1; $($rest),*)
+    };
+    (@ [$($fields:tt)*] $idx:expr;) => {
+        [$($fields)*]
+    };
+}
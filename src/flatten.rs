@@ -0,0 +1,164 @@
+//! Deep flattening of nested tuples into a single flat tuple.
+
+use crate::Tagged;
+
+/// Trait for values flattened into a flat tuple by
+/// [`flatten_all`](FlattenAll::flatten_all).
+///
+/// Tuples are flattened recursively, at any depth, while every other value
+/// is a leaf, flattened into a single-element tuple. Stable Rust cannot tell
+/// tuples and leaves apart on its own, so leaf types must implement the
+/// trait: it is provided for primitives, references, `Option`s, arrays and
+/// [`Tagged`] fields, and can be provided for other types through
+/// [`flatten_leaf!`](crate::flatten_leaf).
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::{FlattenAll, TupleSet};
+///
+/// let network = (8080u16, "localhost");
+/// let limits = ((3u32, 30u64), true);
+///
+/// let context = (network, (limits, 'x')).flatten_all();
+/// assert_eq!(context, (8080, "localhost", 3, 30, true, 'x'));
+/// assert_eq!(context.get::<u64>(), Some(&30));
+/// ```
+pub trait FlattenAll {
+    /// The flat tuple holding every leaf, in depth-first order.
+    type Output;
+
+    /// Flattens the value into a flat tuple of its leaves.
+    fn flatten_all(self) -> Self::Output;
+}
+
+impl FlattenAll for () {
+    type Output = ();
+
+    #[inline]
+    fn flatten_all(self) -> Self::Output {}
+}
+
+/// Implements [`FlattenAll`] for the given types as leaves, flattened into a
+/// single-element tuple.
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::{FlattenAll, flatten_leaf};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Port(u16);
+///
+/// flatten_leaf!(Port);
+///
+/// assert_eq!((Port(80), (1u8, (Port(443),))).flatten_all(), (Port(80), 1, Port(443)));
+/// ```
+#[macro_export]
+macro_rules! flatten_leaf {
+    ($($T:ty),+ $(,)?) => {
+        $(
+            impl $crate::FlattenAll for $T {
+                type Output = ($T,);
+
+                #[inline]
+                fn flatten_all(self) -> Self::Output {
+                    (self,)
+                }
+            }
+        )+
+    };
+}
+
+flatten_leaf!(bool, char, f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl<T: ?Sized> FlattenAll for &T {
+    type Output = (Self,);
+
+    #[inline]
+    fn flatten_all(self) -> Self::Output {
+        (self,)
+    }
+}
+
+impl<T: ?Sized> FlattenAll for &mut T {
+    type Output = (Self,);
+
+    #[inline]
+    fn flatten_all(self) -> Self::Output {
+        (self,)
+    }
+}
+
+impl<T> FlattenAll for Option<T> {
+    type Output = (Self,);
+
+    #[inline]
+    fn flatten_all(self) -> Self::Output {
+        (self,)
+    }
+}
+
+impl<T, const N: usize> FlattenAll for [T; N] {
+    type Output = (Self,);
+
+    #[inline]
+    fn flatten_all(self) -> Self::Output {
+        (self,)
+    }
+}
+
+impl<Tag, T> FlattenAll for Tagged<Tag, T> {
+    type Output = (Self,);
+
+    #[inline]
+    fn flatten_all(self) -> Self::Output {
+        (self,)
+    }
+}
+
+#[cfg(feature = "alloc")]
+flatten_leaf!(alloc::string::String);
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized> FlattenAll for alloc::boxed::Box<T> {
+    type Output = (Self,);
+
+    #[inline]
+    fn flatten_all(self) -> Self::Output {
+        (self,)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> FlattenAll for alloc::vec::Vec<T> {
+    type Output = (Self,);
+
+    #[inline]
+    fn flatten_all(self) -> Self::Output {
+        (self,)
+    }
+}
+
+macro_rules! impl_tuple_flatten {
+    ($first_idx:tt: $First:ident $(, $idx:tt: $T:ident)*) => {
+        #[allow(non_snake_case)]
+        impl<$First, $($T),*> $crate::FlattenAll for ($First, $($T,)*)
+        where
+            $First: $crate::FlattenAll,
+            ($($T,)*): $crate::FlattenAll,
+            $First::Output: $crate::Concat<<($($T,)*) as $crate::FlattenAll>::Output>,
+        {
+            type Output =
+                <$First::Output as $crate::Concat<<($($T,)*) as $crate::FlattenAll>::Output>>::Output;
+
+            #[inline]
+            fn flatten_all(self) -> Self::Output {
+                let ($First, $($T,)*) = self;
+                $crate::Concat::concat($First.flatten_all(), ($($T,)*).flatten_all())
+            }
+        }
+    };
+}
+
+pub(crate) use impl_tuple_flatten;
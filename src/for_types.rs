@@ -0,0 +1,65 @@
+//! Bulk operations over a chosen list of field types.
+
+/// Applies the same closure to the fields of each of the listed types, through
+/// one [`map`](crate::TupleSet::map) call per type.
+///
+/// The closure is expanded once per type, so its argument takes a different
+/// type in each call: its body only needs to compile for each of them. The
+/// tuple expression is evaluated once, and the macro evaluates to the number
+/// of listed types found exactly once in the tuple, whose fields were
+/// mapped.
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::for_types;
+///
+/// let mut stats = (12u32, 0.5f64, true, "label");
+///
+/// let reset = for_types!((u32, f64, bool) in stats => |x| *x = Default::default());
+/// assert_eq!(reset, 3);
+/// assert_eq!(stats, (0, 0.0, false, "label"));
+///
+/// // Types missing from the tuple are skipped
+/// let doubled = for_types!((u32, f64, u8) in stats => |x| *x += *x);
+/// assert_eq!(doubled, 2);
+/// ```
+#[macro_export]
+macro_rules! for_types {
+    (($($T:ty),+ $(,)?) in $tuple:expr => $f:expr) => {{
+        use $crate::TupleSet as _;
+        let tuple = &mut $tuple;
+        let mut mapped = 0usize;
+        $(mapped += usize::from(tuple.map::<$T, _, _>($f).is_some());)+
+        mapped
+    }};
+}
+
+/// Fetches the fields of each of the listed types at once, through one
+/// [`get`](crate::TupleSet::get) call per type.
+///
+/// The tuple expression is evaluated once, and the macro evaluates to a tuple
+/// holding one `Option` per listed type, in the listed order, which is `None`
+/// when the type is not found or appears multiple times.
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::get_all;
+///
+/// let stats = (12u32, 0.5f64, true, "label");
+///
+/// let (count, ratio, enabled) = get_all!(stats, u32, f64, bool);
+/// assert_eq!((count, ratio, enabled), (Some(&12), Some(&0.5), Some(&true)));
+///
+/// // Types missing from the tuple are `None`
+/// assert_eq!(get_all!(stats, &str, u8), (Some(&"label"), None));
+/// ```
+#[macro_export]
+macro_rules! get_all {
+    ($tuple:expr, $($T:ty),+ $(,)?) => {{
+        use $crate::TupleSet as _;
+        let tuple = &$tuple;
+        ($(tuple.get::<$T>(),)+)
+    }};
+}
@@ -0,0 +1,56 @@
+//! Read-only views of tuples.
+
+/// Read-only view over an owned or borrowed tuple.
+///
+/// The view dereferences to the tuple, exposing only its shared-reference
+/// methods such as [`get`](crate::TupleSet::get) and
+/// [`count`](crate::TupleSet::count), so a context can be handed out without
+/// granting mutation.
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::{Frozen, TupleSet};
+///
+/// let mut context = (3u32, "localhost");
+/// let frozen = Frozen::new(&context);
+/// assert_eq!(frozen.get::<u32>(), Some(&3));
+/// assert_eq!(frozen.count::<&str>(), 1);
+///
+/// // The view does not allow mutation, while the owner still can
+/// context.set(4u32);
+/// assert_eq!(Frozen::new(context).get::<u32>(), Some(&4));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Frozen<T>(pub(crate) T);
+
+impl<T> Frozen<T> {
+    /// Creates a read-only view over `tuple`.
+    pub fn new(tuple: T) -> Self {
+        Self(tuple)
+    }
+}
+
+macro_rules! impl_tuple_frozen {
+    ($($idx:tt: $T:ident),+) => {
+        impl<$($T),+> core::ops::Deref for $crate::Frozen<($($T,)+)> {
+            type Target = ($($T,)+);
+
+            #[inline]
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl<$($T),+> core::ops::Deref for $crate::Frozen<&($($T,)+)> {
+            type Target = ($($T,)+);
+
+            #[inline]
+            fn deref(&self) -> &Self::Target {
+                self.0
+            }
+        }
+    };
+}
+
+pub(crate) use impl_tuple_frozen;
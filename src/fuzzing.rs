@@ -0,0 +1,102 @@
+//! `Arbitrary` implementations of the wrapper types, for fuzzing.
+//!
+//! Patches need no dedicated implementation, as they are plain tuples of
+//! `Option`s already supported by the `arbitrary` crate.
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::{Frozen, Homogeneous, Tagged};
+#[cfg(feature = "alloc")]
+use crate::{TupleSet, Validatable, ValidatedTuple};
+
+// The impls below are written by hand so that they do not require the marker
+// types to implement `Arbitrary` as well.
+
+impl<'a, Tag, T: Arbitrary<'a>> Arbitrary<'a> for Tagged<Tag, T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        T::arbitrary(u).map(Self::new)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        T::size_hint(depth)
+    }
+}
+
+impl<'a, T: Arbitrary<'a>> Arbitrary<'a> for Homogeneous<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        T::arbitrary(u).map(Self)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        T::size_hint(depth)
+    }
+}
+
+impl<'a, T: Arbitrary<'a>> Arbitrary<'a> for Frozen<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        T::arbitrary(u).map(Self::new)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        T::size_hint(depth)
+    }
+}
+
+/// The generated wrapper holds no validator, as validators are closures which
+/// cannot be generated.
+#[cfg(feature = "alloc")]
+impl<'a, T> Arbitrary<'a> for ValidatedTuple<T>
+where
+    T: TupleSet + Validatable + Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        T::arbitrary(u).map(Self::new)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        T::size_hint(depth)
+    }
+}
+
+#[cfg(feature = "algebra")]
+mod builder {
+    use core::marker::PhantomData;
+
+    use arbitrary::{Arbitrary, Result, Unstructured};
+
+    use crate::{TupleBuilder, Vacant};
+
+    impl<'a, T> Arbitrary<'a> for Vacant<T> {
+        fn arbitrary(_u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(Self::new())
+        }
+
+        fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+            (0, Some(0))
+        }
+    }
+
+    /// Generates a builder in the state `S`, so that a partially populated
+    /// builder can be generated by choosing a state mixing values and
+    /// [`Vacant`] markers.
+    impl<'a, T, S: Arbitrary<'a>> Arbitrary<'a> for TupleBuilder<T, S> {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            S::arbitrary(u).map(|state| Self { state, target: PhantomData })
+        }
+
+        fn size_hint(depth: usize) -> (usize, Option<usize>) {
+            S::size_hint(depth)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'a, T: Arbitrary<'a>, R> Arbitrary<'a> for crate::SerdeTuple<T, R> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        T::arbitrary(u).map(Self::new)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        T::size_hint(depth)
+    }
+}
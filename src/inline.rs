@@ -0,0 +1,49 @@
+//! Trusted layout of the fields of by-type accessible types.
+
+use crate::TupleSet;
+
+/// Marker trait for [`TupleSet`] types whose fields are stored inline, at a
+/// byte offset that only depends on the type.
+///
+/// [`TupleSet`] is a safe trait, so nothing prevents an implementation from
+/// returning arbitrary pointers from [`as_ptr_of`](TupleSet::as_ptr_of).
+/// Types resolving the offset of a field once and reusing it, possibly on
+/// other values of the same type, such as [`Accessor`](crate::Accessor),
+/// require this trait instead.
+///
+/// It is implemented for every tuple, and for the types generated by
+/// [`define_tuple!`](crate::define_tuple) and
+/// [`define_ffi_mirror!`](crate::define_ffi_mirror).
+///
+/// # Safety
+///
+/// For every value of the implementing type and every type `T`,
+/// [`as_ptr_of`](TupleSet::as_ptr_of) and
+/// [`as_mut_ptr_of`](TupleSet::as_mut_ptr_of) must return either `None` or a
+/// pointer to the only field of type `T` stored within the value, at the same
+/// byte offset for every value of the type.
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::{InlineFields, define_tuple};
+///
+/// define_tuple!(
+///     Endpoint => host: &'static str [host_mut, set_host], port: u16 [port_mut, set_port]
+/// );
+///
+/// fn assert_inline<S: InlineFields>() {}
+/// assert_inline::<(u32, bool)>();
+/// assert_inline::<Endpoint>();
+/// ```
+pub unsafe trait InlineFields: TupleSet {}
+
+macro_rules! impl_tuple_inline_fields {
+    ($($idx:tt: $T:ident),+) => {
+        // SAFETY: the fields of a tuple are stored inline, and the built-in
+        // `as_ptr_of` and `as_mut_ptr_of` return pointers to them
+        unsafe impl<$($T: 'static),+> $crate::InlineFields for ($($T,)+) {}
+    };
+}
+
+pub(crate) use impl_tuple_inline_fields;
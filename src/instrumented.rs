@@ -0,0 +1,141 @@
+//! Tuple wrapper counting accesses per field.
+
+use alloc::vec::Vec;
+use core::{cell::Cell, fmt};
+
+use crate::{TupleSet, TupleTypes, types};
+
+/// Access counters of a field of an [`Instrumented`] tuple.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FieldAccess {
+    /// Position of the field in the tuple.
+    pub position: usize,
+    /// Name of the type of the field.
+    pub type_name: &'static str,
+    /// Number of successful reads of the field.
+    pub reads: usize,
+    /// Number of successful writes of the field.
+    pub writes: usize,
+}
+
+/// Tuple wrapper counting the reads and writes of each field, to find out
+/// which fields of a large context tuple are actually hot.
+///
+/// Reads are counted by [`get`](Instrumented::get), writes by
+/// [`set`](Instrumented::set) and [`map`](Instrumented::map). Failed lookups
+/// are not counted. The summary is available through
+/// [`summary`](Instrumented::summary) or the `Display` implementation.
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::Instrumented;
+///
+/// let mut context = Instrumented::new((3u32, "localhost"));
+/// context.get::<u32>();
+/// context.get::<u32>();
+/// context.set("example.com");
+///
+/// let summary = context.summary();
+/// assert_eq!((summary[0].reads, summary[0].writes), (2, 0));
+/// assert_eq!((summary[1].reads, summary[1].writes), (0, 1));
+/// ```
+pub struct Instrumented<T> {
+    tuple: T,
+    reads: Vec<Cell<usize>>,
+    writes: Vec<usize>,
+}
+
+impl<T: TupleSet + TupleTypes> Instrumented<T> {
+    /// Creates a new wrapper around `tuple`, with all counters set to zero.
+    pub fn new(tuple: T) -> Self {
+        let arity = T::type_ids().as_ref().len();
+        Self {
+            tuple,
+            reads: (0..arity).map(|_| Cell::new(0)).collect(),
+            writes: alloc::vec![0; arity],
+        }
+    }
+
+    /// Get a reference to the value for type `U` if it appears exactly once,
+    /// counting a read of its field.
+    pub fn get<U: 'static>(&self) -> Option<&U> {
+        let position = types::unique_position::<T, U>()?;
+        let value = self.tuple.get()?;
+        let reads = &self.reads[position];
+        reads.set(reads.get() + 1);
+        Some(value)
+    }
+
+    /// Sets the value for type `U` if it appears exactly once, counting a
+    /// write of its field.
+    ///
+    /// Returns `None` on success, `Some(value)` if the type is not found or
+    /// appears multiple times.
+    pub fn set<U: 'static>(&mut self, value: U) -> Option<U> {
+        let Some(position) = types::unique_position::<T, U>() else {
+            return Some(value);
+        };
+        let rejected = self.tuple.set(value);
+        if rejected.is_none() {
+            self.writes[position] += 1;
+        }
+        rejected
+    }
+
+    /// Applies a mapping function to the value of type `U` if it appears
+    /// exactly once, counting a write of its field.
+    ///
+    /// Returns `Some(result)` with the function's return value on success,
+    /// or `None` if the type is not found or appears multiple times.
+    pub fn map<U: 'static, G, R>(&mut self, f: G) -> Option<R>
+    where
+        G: FnOnce(&mut U) -> R,
+    {
+        let position = types::unique_position::<T, U>()?;
+        let result = self.tuple.map(f)?;
+        self.writes[position] += 1;
+        Some(result)
+    }
+
+    /// Returns the access counters of every field, in positional order.
+    #[must_use]
+    pub fn summary(&self) -> Vec<FieldAccess> {
+        T::type_names()
+            .as_ref()
+            .iter()
+            .zip(self.reads.iter().zip(&self.writes))
+            .enumerate()
+            .map(|(position, (type_name, (reads, writes)))| FieldAccess {
+                position,
+                type_name,
+                reads: reads.get(),
+                writes: *writes,
+            })
+            .collect()
+    }
+
+    /// Resets all counters to zero.
+    pub fn reset(&mut self) {
+        self.reads.iter().for_each(|reads| reads.set(0));
+        self.writes.iter_mut().for_each(|writes| *writes = 0);
+    }
+
+    /// Consumes the wrapper, returning the underlying tuple.
+    pub fn into_inner(self) -> T {
+        self.tuple
+    }
+}
+
+impl<T: TupleSet + TupleTypes> fmt::Display for Instrumented<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for access in self.summary() {
+            writeln!(
+                f,
+                "{}: {} (reads: {}, writes: {})",
+                access.position, access.type_name, access.reads, access.writes
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+//! Layout introspection of tuple elements.
+
+/// Trait exposing the size and alignment of each tuple element.
+///
+/// The returned arrays have one entry per tuple element, in positional order,
+/// so layers built on top of tuples (e.g. binary serializers) can compute
+/// buffer sizes without hand-rolled per-arity tables.
+pub trait TupleLayout {
+    /// Array type with one `usize` entry per tuple element.
+    type Layout: AsRef<[usize]> + AsMut<[usize]> + Copy + core::fmt::Debug + Eq;
+
+    /// Returns the `size_of` of each tuple element, in positional order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleLayout;
+    ///
+    /// assert_eq!(<(u8, u32, u16)>::field_sizes(), [1, 4, 2]);
+    /// ```
+    fn field_sizes() -> Self::Layout;
+
+    /// Returns the `align_of` of each tuple element, in positional order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleLayout;
+    ///
+    /// assert_eq!(<(u8, u32, u16)>::field_align(), [1, 4, 2]);
+    /// ```
+    fn field_align() -> Self::Layout;
+}
+
+macro_rules! impl_tuple_layout {
+    ($($idx:tt: $T:ident),+) => {
+        impl<$($T),+> $crate::TupleLayout for ($($T,)+) {
+            type Layout = [usize; 0 $(+ $crate::one!($T))+];
+
+            #[inline]
+            fn field_sizes() -> Self::Layout {
+                [$(core::mem::size_of::<$T>()),+]
+            }
+
+            #[inline]
+            fn field_align() -> Self::Layout {
+                [$(core::mem::align_of::<$T>()),+]
+            }
+        }
+    };
+}
+
+pub(crate) use impl_tuple_layout;
@@ -1,23 +1,295 @@
 #![doc = include_str!("../README.md")]
 #![no_std]
+#![recursion_limit = "256"]
+#![cfg_attr(feature = "unstable-named", feature(adt_const_params, unsized_const_params))]
+#![cfg_attr(feature = "unstable-named", allow(incomplete_features))]
+#![cfg_attr(
+    any(feature = "unstable-const", feature = "unstable-const-type-id"),
+    feature(const_trait_impl, const_cmp)
+)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 use core::any::TypeId;
 
+mod accessor;
+#[cfg(feature = "algebra")]
+mod algebra;
+mod alias;
+mod apply;
+#[cfg(feature = "rkyv")]
+mod archived;
+mod arith;
+mod arity;
+mod array;
+mod at;
+#[cfg(feature = "algebra")]
+mod builder;
+#[cfg(feature = "algebra")]
+mod canonical;
+mod codec;
+#[cfg(feature = "unstable-const")]
+mod const_access;
+#[cfg(feature = "unstable-const-type-id")]
+mod const_count;
+#[cfg(feature = "std")]
+mod context;
+mod convert;
+mod define;
+mod diagnostics;
+mod dyn_fields;
+mod error;
+mod ffi;
+mod field_types;
+#[cfg(feature = "algebra")]
+mod flatten;
+mod for_types;
+mod frozen;
+#[cfg(feature = "arbitrary")]
+mod fuzzing;
+mod inline;
+#[cfg(feature = "alloc")]
+mod instrumented;
+mod layout;
+#[cfg(feature = "unstable-named")]
+mod named;
+mod observed;
+mod partial;
+mod patch;
+mod permutation;
+#[cfg(feature = "pyo3")]
+mod python;
+#[cfg(feature = "alloc")]
+mod recorded;
+#[cfg(feature = "alloc")]
+mod registry;
+mod scoped;
+mod selection;
+#[cfg(feature = "serde")]
+mod serialization;
+#[cfg(feature = "strict")]
+mod strict;
+#[cfg(feature = "std")]
+mod sync;
+mod tagged;
+#[cfg(feature = "algebra")]
+mod type_eq;
+mod type_map;
+mod types;
+#[cfg(feature = "alloc")]
+mod validated;
+#[cfg(kani)]
+mod verification;
+mod view;
+#[cfg(feature = "wasm")]
+mod wasm;
+
+pub use accessor::Accessor;
+#[cfg(feature = "algebra")]
+pub use algebra::{
+    Concat, Contains, ContainsAll, Difference, EndsWith, Intersection, Pluck, Position, Prepend,
+    StartsWith, Subset, Union,
+};
+pub use alias::TransparentAlias;
+pub use apply::Apply;
+#[cfg(feature = "rkyv")]
+pub use archived::{ArchivedCons, ArchivedFields, RkyvTuple};
+pub use arith::{
+    AddAssignEach, AddEach, DivAssignEach, DivEach, MulAssignEach, MulEach, SubAssignEach, SubEach,
+};
+pub use arity::TupleArity;
+pub use array::{Homogeneous, HomogeneousTuple};
+pub use at::{At, TupleFirst, TupleLast};
+#[cfg(feature = "algebra")]
+pub use builder::{Buildable, Fill, TupleBuilder, Vacant};
+#[cfg(feature = "algebra")]
+pub use canonical::{
+    CanonicalInsert, CanonicalInsertOrdered, CanonicalKey, Canonicalize, Greater, KeyOrder, Less,
+    Succ, Zero,
+};
+pub use codec::{CodecError, LePrimitive, TupleCodec};
+#[cfg(feature = "unstable-const")]
+pub use const_access::ConstTupleSet;
+#[cfg(feature = "unstable-const-type-id")]
+pub use const_count::{CountOf, DisjointFrom};
+#[cfg(feature = "std")]
+pub use context::{ContextTuple, current, with_context, with_current};
+pub use convert::{
+    AsRefs, Cloned, Copied, MapTypes, Pair, Push, TryMapTypes, Zip, Zip3, ZipFn, ZipWith,
+};
+pub use dyn_fields::DynFields;
+pub use error::{CapacityExceeded, LookupError, SetError, SetErrorKind, SliceTooShort};
+pub use field_types::FieldTypes;
+#[cfg(feature = "algebra")]
+pub use flatten::FlattenAll;
+pub use frozen::Frozen;
+pub use inline::InlineFields;
+#[cfg(feature = "alloc")]
+pub use instrumented::{FieldAccess, Instrumented};
+pub use layout::TupleLayout;
+#[cfg(feature = "unstable-named")]
+pub use named::Named;
+pub use observed::{Mutation, ObservedTuple};
+pub use partial::{Partial, PartialArgs};
+pub use patch::{Diffable, Patchable};
+pub use permutation::UnorderedEq;
+#[cfg(feature = "pyo3")]
+pub use python::{FromPyKeyed, IntoPyKeyed, PyKeyed, TupleView};
+#[cfg(feature = "alloc")]
+pub use recorded::{Recorded, Revision};
+#[cfg(feature = "alloc")]
+pub use registry::{Constructible, MissingConstructor, Registry};
+pub use scoped::ScopedSet;
+pub use selection::Selection;
+#[cfg(feature = "serde")]
+pub use serialization::{
+    Auto, DeserializeKeyed, DeserializePositional, Keyed, Positional, SerdeTuple, SerializeKeyed,
+    SerializePositional,
+};
+#[cfg(feature = "strict")]
+pub use strict::{ContainsUnique, StrictTupleSet};
+#[cfg(feature = "std")]
+pub use sync::{Lockable, SyncTuple};
+pub use tagged::Tagged;
+#[cfg(feature = "algebra")]
+pub use type_eq::{Field, TypeEq};
+pub use type_map::TypeMapView;
+pub use types::{OverlapEntry, TupleTypes, TypeOverlap};
+#[cfg(feature = "alloc")]
+pub use validated::{Validatable, ValidatedTuple, ValidationError, Validator};
+pub use view::{CachedView, SplitTargets, ViewTargets};
+#[cfg(feature = "wasm")]
+pub use wasm::{BridgeError, JsBridge, JsField, JsFields};
+
+// Items used by the exported macros, not part of the public API
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::diagnostics::{lookup_miss, unchecked_miss};
+}
+
 /// Trait for accessing and manipulating tuple elements by type.
 pub trait TupleSet {
+    /// Returns the number of fields of the tuple.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// assert_eq!((42i32, "hello", 3.14f64).arity(), 3);
+    /// ```
+    #[inline]
+    fn arity(&self) -> usize
+    where
+        Self: TupleArity,
+    {
+        Self::ARITY
+    }
+
     /// Returns the number of times type `T` appears in the tuple.
     fn count<T: 'static>(&self) -> usize;
 
+    /// Returns true if the tuple contains at least one instance of type `T`.
+    #[inline]
+    fn contains<T: 'static>(&self) -> bool {
+        self.count::<T>() > 0
+    }
+
     /// Returns true if the tuple contains exactly one instance of type `T`.
     #[inline]
     fn contains_unique<T: 'static>(&self) -> bool {
         self.count::<T>() == 1
     }
 
+    /// Returns true if the tuple contains more than one instance of type `T`.
+    #[inline]
+    fn contains_duplicate<T: 'static>(&self) -> bool {
+        self.count::<T>() > 1
+    }
+
+    /// Returns the `TypeId`, name and number of occurrences of every type
+    /// appearing more than once in the tuple, in positional order of first
+    /// occurrence.
+    ///
+    /// Fields of these types cannot be accessed by type, so this audits why
+    /// [`get`](TupleSet::get) or [`set`](TupleSet::set) calls fail.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::any::TypeId;
+    ///
+    /// use tuple_set::TupleSet;
+    ///
+    /// let tuple = (1u8, "host", 2u8, 3u8, "port", true);
+    /// let duplicates: Vec<_> = tuple.duplicates().collect();
+    /// assert_eq!(duplicates, [(TypeId::of::<u8>(), "u8", 3), (TypeId::of::<&str>(), "&str", 2)]);
+    /// ```
+    fn duplicates(&self) -> impl Iterator<Item = (TypeId, &'static str, usize)>
+    where
+        Self: TupleTypes,
+    {
+        let (ids, names) = (Self::type_ids(), Self::type_names());
+        (0..ids.as_ref().len()).filter_map(move |position| {
+            let ids = ids.as_ref();
+            let id = ids[position];
+            let count = ids.iter().filter(|other| **other == id).count();
+            (count > 1 && !ids[..position].contains(&id))
+                .then(|| (id, names.as_ref()[position], count))
+        })
+    }
+
+    /// Returns the positions of the fields of type `T`, in increasing order.
+    ///
+    /// Unlike most methods, `T` may appear any number of times, so this
+    /// locates the fields that make [`get`](TupleSet::get) or
+    /// [`set`](TupleSet::set) fail.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// let tuple = (1u8, "host", 2u8, 3u8, "port", true);
+    /// assert_eq!(tuple.indices_of::<u8>().collect::<Vec<_>>(), [0, 2, 3]);
+    /// assert_eq!(tuple.indices_of::<char>().count(), 0);
+    /// ```
+    fn indices_of<T: 'static>(&self) -> impl Iterator<Item = usize>
+    where
+        Self: TupleTypes,
+    {
+        let ids = Self::type_ids();
+        (0..ids.as_ref().len()).filter(move |&position| ids.as_ref()[position] == TypeId::of::<T>())
+    }
+
+    /// Returns the position of the first field of type `T`, or `None` if the
+    /// type is not found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// let config = ("localhost", 8080u16, true, 30u16);
+    /// assert_eq!(config.index_of::<u16>(), Some(1));
+    /// assert_eq!(config.index_of::<bool>(), Some(2));
+    /// assert_eq!(config.index_of::<char>(), None);
+    /// ```
+    fn index_of<T: 'static>(&self) -> Option<usize>
+    where
+        Self: TupleTypes,
+    {
+        Self::type_ids().as_ref().iter().position(|id| *id == TypeId::of::<T>())
+    }
+
     /// Sets the value for type `T` if it appears exactly once in the tuple.
     ///
-    /// Returns `None` on success, `Some(value)` if the type is not found or
-    /// appears multiple times.
+    /// The previous value is dropped: use [`replace`](TupleSet::replace) to
+    /// retrieve it instead. Returns `None` on success, `Some(value)` if the
+    /// type is not found or appears multiple times.
     ///
     /// # Examples
     ///
@@ -33,17 +305,158 @@ pub trait TupleSet {
     /// assert_eq!(result, Some(true));
     /// ```
     fn set<T: 'static>(&mut self, value: T) -> Option<T> {
-        if !self.contains_unique::<T>() {
+        let Some(field) = self.get_mut::<T>() else {
             return Some(value);
-        }
+        };
 
-        unsafe {
-            self.set_unchecked(value);
+        *field = value;
+
+        None
+    }
+
+    /// Replaces the value for type `T` if it appears exactly once in the
+    /// tuple, returning the previous value.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(value)` if the type is not found or appears multiple
+    /// times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// let mut tuple = (String::from("draft"), 3u32);
+    /// let previous = tuple.replace(String::from("final")).unwrap();
+    /// assert_eq!(previous, "draft");
+    /// assert_eq!(tuple.0, "final");
+    ///
+    /// // Type not found
+    /// assert_eq!(tuple.replace(true), Err(true));
+    /// ```
+    fn replace<T: 'static>(&mut self, value: T) -> Result<T, T> {
+        match self.get_mut::<T>() {
+            Some(field) => Ok(core::mem::replace(field, value)),
+            None => Err(value),
         }
+    }
+
+    /// Sets the value for type `T` if it appears exactly once in the tuple,
+    /// reporting why the value could not be stored otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SetError`] handing the value back if the type is not found
+    /// or appears multiple times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::{SetErrorKind, TupleSet};
+    ///
+    /// let mut tuple = (42i32, "hello", 7i32);
+    /// assert!(tuple.try_set("world").is_ok());
+    ///
+    /// let error = tuple.try_set(100i32).unwrap_err();
+    /// assert_eq!(error.kind(), SetErrorKind::Duplicate { count: 2 });
+    /// assert_eq!(error.into_value(), 100);
+    ///
+    /// let error = tuple.try_set(true).unwrap_err();
+    /// assert_eq!(error.kind(), SetErrorKind::NotFound);
+    /// assert_eq!(error.to_string(), "cannot set value: type `bool` not found in tuple");
+    /// ```
+    fn try_set<T: 'static>(&mut self, value: T) -> Result<(), SetError<T>>
+    where
+        Self: TupleTypes,
+    {
+        let Some(field) = self.get_mut::<T>() else {
+            return Err(SetError::new(value, Self::type_ids().as_ref()));
+        };
+
+        *field = value;
+
+        Ok(())
+    }
+
+    /// Sets the value for type `T` to the result of `f` if `T` appears
+    /// exactly once in the tuple.
+    ///
+    /// Unlike [`set`](TupleSet::set), `f` is only invoked when the value can
+    /// be stored, so expensive values are never built just to be handed back.
+    /// Returns `true` if the value was set, `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// let mut tuple = (42i32, "hello");
+    /// assert!(tuple.set_with(|| 100i32));
+    /// assert_eq!(tuple.0, 100);
+    ///
+    /// // Type not found, the closure is never called
+    /// assert!(!tuple.set_with::<u64, _>(|| unreachable!()));
+    /// ```
+    fn set_with<T: 'static, F: FnOnce() -> T>(&mut self, f: F) -> bool {
+        self.map(|x: &mut T| *x = f()).is_some()
+    }
+
+    /// Sets the value for type `T` to `value.into()` if `T` appears exactly
+    /// once in the tuple.
+    ///
+    /// The conversion is only performed when the value can be stored.
+    /// Returns `None` on success, `Some(value)` with the unconverted value if
+    /// the type is not found or appears multiple times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// let mut tuple = (String::from("localhost"), 8080u32);
+    /// assert!(tuple.set_from_into::<String, _>("example.com").is_none());
+    /// assert!(tuple.set_from_into::<u32, _>(443u16).is_none());
+    /// assert_eq!(tuple, (String::from("example.com"), 443));
+    ///
+    /// // Type not found
+    /// assert_eq!(tuple.set_from_into::<u64, _>(1u8), Some(1));
+    /// ```
+    fn set_from_into<T: 'static, U: Into<T>>(&mut self, value: U) -> Option<U> {
+        let Some(field) = self.get_mut::<T>() else {
+            return Some(value);
+        };
+
+        *field = value.into();
 
         None
     }
 
+    /// Temporarily sets the value for type `T`, returning a guard restoring the
+    /// previous value when dropped.
+    ///
+    /// Returns `Err(value)` if the type is not found or appears multiple
+    /// times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// let mut config = (3u32, "production");
+    /// {
+    ///     let guard = config.scoped_set("test").unwrap();
+    ///     assert_eq!(guard.1, "test");
+    ///     assert_eq!(*guard.previous(), "production");
+    /// }
+    /// assert_eq!(config.1, "production");
+    /// ```
+    fn scoped_set<T: 'static>(&mut self, value: T) -> Result<ScopedSet<'_, Self, T>, T> {
+        let previous = self.replace(value)?;
+
+        Ok(ScopedSet::new(self, previous))
+    }
+
     /// Get a reference to the value for type `T` in the tuple if it appears
     /// exactly once.
     ///
@@ -61,13 +474,66 @@ pub trait TupleSet {
     /// assert_eq!(value, Some(&42i32));
     /// ```
     fn get<T: 'static>(&self) -> Option<&T> {
-        if !self.contains_unique::<T>() {
+        if !diagnostics::lookup::<T, Self>(self) {
             return None;
         }
 
         unsafe { Some(self.get_unchecked()) }
     }
 
+    /// Get a mutable reference to the value for type `T` in the tuple if it
+    /// appears exactly once.
+    ///
+    /// Returns `Some(&mut T)` on success, or `None` if the type is not found
+    /// or appears multiple times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// let mut tuple = (42i32, "hello", 3.14f64);
+    /// if let Some(value) = tuple.get_mut::<i32>() {
+    ///     *value += 1;
+    /// }
+    /// assert_eq!(tuple.0, 43);
+    /// assert!(tuple.get_mut::<bool>().is_none());
+    /// ```
+    fn get_mut<T: 'static>(&mut self) -> Option<&mut T> {
+        if !diagnostics::lookup::<T, Self>(self) {
+            return None;
+        }
+
+        unsafe { Some(self.get_mut_unchecked()) }
+    }
+
+    /// Get a reference to the value for type `T` in the tuple if it appears
+    /// exactly once, reporting why the lookup failed otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`LookupError`] if the type is not found or appears multiple
+    /// times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// let tuple = (42i32, "hello");
+    /// assert_eq!(tuple.try_get::<i32>(), Ok(&42));
+    ///
+    /// let error = tuple.try_get::<f64>().unwrap_err();
+    /// assert!(error.is_not_found());
+    /// assert_eq!(error.to_string(), "type `f64` not found in tuple");
+    /// ```
+    fn try_get<T: 'static>(&self) -> Result<&T, LookupError>
+    where
+        Self: TupleTypes,
+    {
+        self.get::<T>().ok_or_else(|| LookupError::new::<T>(Self::type_ids().as_ref()))
+    }
+
     /// Sets the value for type `T` in the tuple without checking.
     ///
     /// # Safety
@@ -75,7 +541,7 @@ pub trait TupleSet {
     /// The caller must ensure that `T` appears exactly once in the tuple.
     /// Calling this with a type that doesn't exist or appears multiple times
     /// may lead to respectively to a panic or changing solely the first
-    /// occurrence.
+    /// occurrence. The previous value is dropped.
     ///
     /// # Examples
     ///
@@ -106,6 +572,134 @@ pub trait TupleSet {
     /// the first occurrence.
     unsafe fn get_unchecked<T: 'static>(&self) -> &T;
 
+    /// Get a mutable reference to the value for type `T` in the tuple without
+    /// checking.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `T` appears exactly once in the tuple.
+    /// Calling this with a type that doesn't exist will panic, while calling
+    /// this with a type that appears multiple times will return a reference to
+    /// the first occurrence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// let mut tuple = (42i32, "hello", 3.14f64);
+    /// unsafe {
+    ///     *tuple.get_mut_unchecked::<&str>() = "world";
+    /// }
+    /// assert_eq!(tuple.1, "world");
+    /// ```
+    unsafe fn get_mut_unchecked<T: 'static>(&mut self) -> &mut T {
+        // SAFETY: the pointer comes from a unique borrow of the tuple
+        unsafe { &mut *self.as_mut_ptr_of_unchecked::<T>() }
+    }
+
+    /// Returns the value of the unique `OnceCell<T>` field, initializing it
+    /// with `init` if it is empty.
+    ///
+    /// Returns `None` if no `OnceCell<T>` field is found or it appears multiple
+    /// times, in which case `init` is not called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::cell::OnceCell;
+    ///
+    /// use tuple_set::TupleSet;
+    ///
+    /// let context = (OnceCell::<u32>::new(), "hello");
+    /// assert_eq!(context.get_or_init_field(|| 42u32), Some(&42));
+    /// assert_eq!(context.get_or_init_field(|| 7u32), Some(&42));
+    /// ```
+    fn get_or_init_field<T: 'static, F: FnOnce() -> T>(&self, init: F) -> Option<&T> {
+        self.get::<core::cell::OnceCell<T>>().map(|cell| cell.get_or_init(init))
+    }
+
+    /// Returns the value of the unique `OnceCell<T>` field if it has been
+    /// initialized.
+    ///
+    /// Returns `None` if the cell is empty, or if no `OnceCell<T>` field is
+    /// found or it appears multiple times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::cell::OnceCell;
+    ///
+    /// use tuple_set::TupleSet;
+    ///
+    /// let context = (OnceCell::<u32>::new(), "hello");
+    /// assert_eq!(context.get_initialized::<u32>(), None);
+    /// context.get_or_init_field(|| 42u32);
+    /// assert_eq!(context.get_initialized::<u32>(), Some(&42));
+    /// ```
+    fn get_initialized<T: 'static>(&self) -> Option<&T> {
+        self.get::<core::cell::OnceCell<T>>().and_then(core::cell::OnceCell::get)
+    }
+
+    /// Returns a reference to the value held by the first `Box<dyn Any>`
+    /// field, in positional order, which can be downcast to `T`.
+    ///
+    /// Unlike most methods, any number of `Box<dyn Any>` fields may be
+    /// searched, so statically and dynamically typed values can be mixed in
+    /// one tuple. Returns `None` if no such field holds a `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::any::Any;
+    ///
+    /// use tuple_set::TupleSet;
+    ///
+    /// let plugins: (u32, Box<dyn Any>, Box<dyn Any>) = (1, Box::new("audio"), Box::new(2.5f64));
+    /// assert_eq!(plugins.get_downcast::<f64>(), Some(&2.5));
+    /// assert_eq!(plugins.get_downcast::<&str>(), Some(&"audio"));
+    /// assert_eq!(plugins.get_downcast::<u32>(), None);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn get_downcast<T: 'static>(&self) -> Option<&T> {
+        let mut found = None;
+        self.for_each_of(|slot: &alloc::boxed::Box<dyn core::any::Any>| {
+            if found.is_none() {
+                found = slot.downcast_ref::<T>();
+            }
+        });
+        found
+    }
+
+    /// Returns a mutable reference to the value held by the first
+    /// `Box<dyn Any>` field, in positional order, which can be downcast to
+    /// `T`.
+    ///
+    /// Returns `None` under the same conditions as
+    /// [`get_downcast`](TupleSet::get_downcast).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::any::Any;
+    ///
+    /// use tuple_set::TupleSet;
+    ///
+    /// let mut plugins: (u32, Box<dyn Any>) = (1, Box::new(2.5f64));
+    /// *plugins.get_downcast_mut::<f64>().unwrap() *= 2.0;
+    /// assert_eq!(plugins.get_downcast::<f64>(), Some(&5.0));
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn get_downcast_mut<T: 'static>(&mut self) -> Option<&mut T> {
+        let mut found = None;
+        self.for_each_of_mut(|slot: &mut alloc::boxed::Box<dyn core::any::Any>| {
+            if found.is_none() {
+                found = slot.downcast_mut::<T>();
+            }
+        });
+        found
+    }
+
     /// Applies a mapping function to the value of type `T` in the tuple.
     ///
     /// Returns `Some(result)` with the function's return value on success,
@@ -131,125 +725,1533 @@ pub trait TupleSet {
     where
         F: FnOnce(&mut T) -> R,
     {
-        if !self.contains_unique::<T>() {
-            return None;
-        }
-
-        unsafe { Some(self.map_unchecked(f)) }
+        self.get_mut().map(f)
     }
 
-    /// Applies a mapping function to the value of type `T` in the tuple without
-    /// checking.
-    ///
-    /// # Safety
+    /// Get a reference to the first value of type `T` in the tuple.
     ///
-    /// The caller must ensure that `T` appears exactly once in the tuple.
-    /// Calling this with a type that doesn't exist will panic, while calling
-    /// this with a type that appears multiple times may lead to solely changing
-    /// the first occurrence.
+    /// Unlike [`get`](TupleSet::get), `T` may appear any number of times.
+    /// Returns `None` if the type is not found.
     ///
     /// # Examples
     ///
     /// ```
     /// use tuple_set::TupleSet;
     ///
-    /// let mut tuple = (42i32, "hello", 3.14f64);
-    /// let old = unsafe {
-    ///     tuple.map_unchecked(|x: &mut i32| {
-    ///         let old = *x;
-    ///         *x *= 2;
-    ///         old
-    ///     })
-    /// };
-    /// assert_eq!(old, 42);
-    /// assert_eq!(tuple.0, 84);
+    /// let tuple = (1u8, "sensor", 2u8);
+    /// assert_eq!(tuple.get::<u8>(), None);
+    /// assert_eq!(tuple.get_first::<u8>(), Some(&1));
+    /// assert_eq!(tuple.get_first::<bool>(), None);
     /// ```
-    unsafe fn map_unchecked<T: 'static, F, R>(&mut self, f: F) -> R
-    where
-        F: FnOnce(&mut T) -> R;
+    fn get_first<T: 'static>(&self) -> Option<&T> {
+        self.get_nth(0)
+    }
 
-    /// Takes the value out of a `T` field, leaving `T::default()` in its place.
+    /// Sets the first value of type `T` in the tuple.
+    ///
+    /// Unlike [`set`](TupleSet::set), `T` may appear any number of times.
+    /// Returns `None` on success, `Some(value)` if the type is not found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// let mut tuple = (1u8, "sensor", 2u8);
+    /// assert!(tuple.set_first(10u8).is_none());
+    /// assert_eq!(tuple, (10, "sensor", 2));
+    /// assert_eq!(tuple.set_first(true), Some(true));
+    /// ```
+    fn set_first<T: 'static>(&mut self, value: T) -> Option<T> {
+        let Some(field) = self.iter_of_mut::<T>().next() else {
+            return Some(value);
+        };
+
+        *field = value;
+
+        None
+    }
+
+    /// Applies a mapping function to the first value of type `T` in the
+    /// tuple.
+    ///
+    /// Unlike [`map`](TupleSet::map), `T` may appear any number of times.
+    /// Returns `Some(result)` with the function's return value on success, or
+    /// `None` if the type is not found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// let mut tuple = (1u8, "sensor", 2u8);
+    /// assert_eq!(tuple.map_first(|value: &mut u8| *value += 5), Some(()));
+    /// assert_eq!(tuple, (6, "sensor", 2));
+    /// ```
+    fn map_first<T: 'static, F, R>(&mut self, f: F) -> Option<R>
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        self.iter_of_mut().next().map(f)
+    }
+
+    /// Get a reference to the last value of type `T` in the tuple.
+    ///
+    /// Unlike [`get`](TupleSet::get), `T` may appear any number of times, so
+    /// tuples used as layered contexts can let the latest field win. Returns
+    /// `None` if the type is not found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// // The port of the latest layer overrides the default one
+    /// let layered = ("localhost", 8080u16, 9090u16);
+    /// assert_eq!(layered.get_last::<u16>(), Some(&9090));
+    /// assert_eq!(layered.get_last::<bool>(), None);
+    /// ```
+    fn get_last<T: 'static>(&self) -> Option<&T> {
+        let mut last = None;
+        self.for_each_of(|value: &T| last = Some(value));
+        last
+    }
+
+    /// Sets the last value of type `T` in the tuple.
+    ///
+    /// Unlike [`set`](TupleSet::set), `T` may appear any number of times.
+    /// Returns `None` on success, `Some(value)` if the type is not found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// let mut tuple = (1u8, "sensor", 2u8);
+    /// assert!(tuple.set_last(20u8).is_none());
+    /// assert_eq!(tuple, (1, "sensor", 20));
+    /// assert_eq!(tuple.set_last(true), Some(true));
+    /// ```
+    fn set_last<T: 'static>(&mut self, value: T) -> Option<T> {
+        if !self.contains::<T>() {
+            return Some(value);
+        }
+
+        self.map_last(|x: &mut T| *x = value);
+
+        None
+    }
+
+    /// Applies a mapping function to the last value of type `T` in the tuple.
+    ///
+    /// Unlike [`map`](TupleSet::map), `T` may appear any number of times.
+    /// Returns `Some(result)` with the function's return value on success, or
+    /// `None` if the type is not found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// let mut tuple = (1u8, "sensor", 2u8);
+    /// assert_eq!(tuple.map_last(|value: &mut u8| *value += 5), Some(()));
+    /// assert_eq!(tuple, (1, "sensor", 7));
+    /// ```
+    fn map_last<T: 'static, F, R>(&mut self, f: F) -> Option<R>
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        let mut last = None;
+        self.for_each_of_mut(|value: &mut T| last = Some(value));
+        last.map(f)
+    }
+
+    /// Get a reference to the occurrence of type `T` at index `n`, counting
+    /// only the fields of type `T` in positional order.
+    ///
+    /// Unlike [`get`](TupleSet::get), `T` may appear any number of times.
+    /// Returns `None` if `T` appears at most `n` times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// let tuple = (1u8, "sensor", 2u8, 3u8);
+    /// assert_eq!(tuple.get_nth::<u8>(1), Some(&2));
+    /// assert_eq!(tuple.get_nth::<&str>(0), Some(&"sensor"));
+    /// assert_eq!(tuple.get_nth::<u8>(3), None);
+    /// ```
+    fn get_nth<T: 'static>(&self, n: usize) -> Option<&T> {
+        let mut index = 0;
+        let mut nth = None;
+        self.for_each_of(|value: &T| {
+            if index == n {
+                nth = Some(value);
+            }
+            index += 1;
+        });
+        nth
+    }
+
+    /// Sets the occurrence of type `T` at index `n`, counting only the fields
+    /// of type `T` in positional order.
+    ///
+    /// Returns `None` on success, `Some(value)` if `T` appears at most `n`
+    /// times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// let mut tuple = (1u8, "sensor", 2u8, 3u8);
+    /// assert!(tuple.set_nth(1, 20u8).is_none());
+    /// assert_eq!(tuple, (1, "sensor", 20, 3));
+    /// assert_eq!(tuple.set_nth(3, 4u8), Some(4));
+    /// ```
+    fn set_nth<T: 'static>(&mut self, n: usize, value: T) -> Option<T> {
+        if self.count::<T>() <= n {
+            return Some(value);
+        }
+
+        self.map_nth(n, |x: &mut T| *x = value);
+
+        None
+    }
+
+    /// Applies a mapping function to the occurrence of type `T` at index `n`,
+    /// counting only the fields of type `T` in positional order.
+    ///
+    /// Returns `Some(result)` with the function's return value on success, or
+    /// `None` if `T` appears at most `n` times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// let mut tuple = (1u8, "sensor", 2u8, 3u8);
+    /// assert_eq!(tuple.map_nth(2, |value: &mut u8| *value *= 10), Some(()));
+    /// assert_eq!(tuple, (1, "sensor", 2, 30));
+    /// ```
+    fn map_nth<T: 'static, F, R>(&mut self, n: usize, f: F) -> Option<R>
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        let mut index = 0;
+        let mut nth = None;
+        self.for_each_of_mut(|value: &mut T| {
+            if index == n {
+                nth = Some(value);
+            }
+            index += 1;
+        });
+        nth.map(f)
+    }
+
+    /// Sets every field of type `T` to a clone of `value`, returning the
+    /// number of fields written.
+    ///
+    /// Unlike [`set`](TupleSet::set), `T` may appear any number of times. The
+    /// previous values are dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// let mut slots = (Some(3u16), "pool", None::<u16>, Some(7u16));
+    /// assert_eq!(slots.set_all(None::<u16>), 3);
+    /// assert_eq!(slots, (None, "pool", None, None));
+    /// assert_eq!(slots.set_all(true), 0);
+    /// ```
+    fn set_all<T: 'static + Clone>(&mut self, value: T) -> usize {
+        self.map_all(|x: &mut T| x.clone_from(&value))
+    }
+
+    /// Applies `f` to every field of type `T`, in positional order, returning
+    /// the number of applications.
+    ///
+    /// Unlike [`map`](TupleSet::map), `T` may appear any number of times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// let mut tuple = (1u8, "sensor", 2u8, 3u8);
+    /// assert_eq!(tuple.map_all(|value: &mut u8| *value *= 10), 3);
+    /// assert_eq!(tuple, (10, "sensor", 20, 30));
+    /// ```
+    fn map_all<T: 'static, F: FnMut(&mut T)>(&mut self, mut f: F) -> usize {
+        let mut applied = 0;
+        self.for_each_of_mut(|value: &mut T| {
+            f(value);
+            applied += 1;
+        });
+        applied
+    }
+
+    /// Applies an asynchronous mapping function to the value of type `T` in
+    /// the tuple, so that the mutation can await.
+    ///
+    /// The returned future resolves to `Some(result)` with the function's
+    /// output on success, or `None` if the type is not found or appears
+    /// multiple times. No executor is required beyond the caller's own.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::{
+    ///     pin::pin,
+    ///     task::{Context, Poll, Waker},
+    /// };
+    ///
+    /// use tuple_set::TupleSet;
+    ///
+    /// async fn fetch_retries() -> u32 {
+    ///     5
+    /// }
+    ///
+    /// let mut tuple = (3u32, "localhost");
+    /// let poll = {
+    ///     let future = pin!(tuple.map_async(async |retries: &mut u32| {
+    ///         *retries = fetch_retries().await;
+    ///         *retries
+    ///     }));
+    ///     future.poll(&mut Context::from_waker(Waker::noop()))
+    /// };
+    /// assert_eq!(poll, Poll::Ready(Some(5)));
+    /// assert_eq!(tuple, (5, "localhost"));
+    /// ```
+    fn map_async<T: 'static, F, R>(&mut self, f: F) -> impl Future<Output = Option<R>>
+    where
+        F: AsyncFnOnce(&mut T) -> R,
+    {
+        async move {
+            let value = self.get_mut::<T>()?;
+            Some(f(value).await)
+        }
+    }
+
+    /// Applies a mapping function to the value of type `T` in the tuple without
+    /// checking.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `T` appears exactly once in the tuple.
+    /// Calling this with a type that doesn't exist will panic, while calling
+    /// this with a type that appears multiple times may lead to solely changing
+    /// the first occurrence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// let mut tuple = (42i32, "hello", 3.14f64);
+    /// let old = unsafe {
+    ///     tuple.map_unchecked(|x: &mut i32| {
+    ///         let old = *x;
+    ///         *x *= 2;
+    ///         old
+    ///     })
+    /// };
+    /// assert_eq!(old, 42);
+    /// assert_eq!(tuple.0, 84);
+    /// ```
+    unsafe fn map_unchecked<T: 'static, F, R>(&mut self, f: F) -> R
+    where
+        F: FnOnce(&mut T) -> R;
+
+    /// Returns a raw pointer to the value of type `T` if it appears exactly
+    /// once in the tuple.
+    ///
+    /// No intermediate reference to the field is created, so the pointer can
+    /// be handed to FFI or DMA APIs without asserting aliasing guarantees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// let tuple = (42i32, "hello", 3.14f64);
+    /// let ptr = tuple.as_ptr_of::<i32>().unwrap();
+    /// assert_eq!(unsafe { *ptr }, 42);
+    /// assert!(tuple.as_ptr_of::<bool>().is_none());
+    /// ```
+    fn as_ptr_of<T: 'static>(&self) -> Option<*const T> {
+        if !diagnostics::lookup::<T, Self>(self) {
+            return None;
+        }
+
+        unsafe { Some(self.as_ptr_of_unchecked()) }
+    }
+
+    /// Returns a mutable raw pointer to the value of type `T` if it appears
+    /// exactly once in the tuple.
+    ///
+    /// No intermediate reference to the field is created, so the pointer can
+    /// be handed to FFI or DMA APIs without asserting aliasing guarantees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// let mut tuple = (42i32, "hello", 3.14f64);
+    /// let ptr = tuple.as_mut_ptr_of::<i32>().unwrap();
+    /// unsafe { ptr.write(7) };
+    /// assert_eq!(tuple.0, 7);
+    /// ```
+    fn as_mut_ptr_of<T: 'static>(&mut self) -> Option<*mut T> {
+        if !diagnostics::lookup::<T, Self>(self) {
+            return None;
+        }
+
+        unsafe { Some(self.as_mut_ptr_of_unchecked()) }
+    }
+
+    /// Performs a volatile read of the value of type `T` if it appears exactly
+    /// once in the tuple.
+    ///
+    /// Intended for register blocks modeled as tuples, where every access
+    /// must reach memory and must not be elided or reordered by the compiler.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// let registers = (0x10u32, 0x2u8);
+    /// assert_eq!(registers.read_volatile_of::<u32>(), Some(0x10));
+    /// assert_eq!(registers.read_volatile_of::<u16>(), None);
+    /// ```
+    fn read_volatile_of<T: 'static + Copy>(&self) -> Option<T> {
+        self.as_ptr_of::<T>().map(|ptr| unsafe { ptr.read_volatile() })
+    }
+
+    /// Performs a volatile write of `value` into the field of type `T` if it
+    /// appears exactly once in the tuple.
+    ///
+    /// Returns `None` on success, `Some(value)` if the type is not found or
+    /// appears multiple times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// let mut registers = (0x10u32, 0x2u8);
+    /// assert!(registers.write_volatile_of(0x3u8).is_none());
+    /// assert_eq!(registers.1, 0x3);
+    /// assert_eq!(registers.write_volatile_of(1u16), Some(1));
+    /// ```
+    fn write_volatile_of<T: 'static + Copy>(&mut self, value: T) -> Option<T> {
+        let Some(ptr) = self.as_mut_ptr_of::<T>() else {
+            return Some(value);
+        };
+
+        unsafe { ptr.write_volatile(value) };
+
+        None
+    }
+
+    /// Returns a raw pointer to the value of type `T` without checking.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `T` appears exactly once in the tuple.
+    /// Calling this with a type that doesn't exist will panic, while calling
+    /// this with a type that appears multiple times will return a pointer to
+    /// the first occurrence.
+    unsafe fn as_ptr_of_unchecked<T: 'static>(&self) -> *const T;
+
+    /// Returns a mutable raw pointer to the value of type `T` without
+    /// checking.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `T` appears exactly once in the tuple.
+    /// Calling this with a type that doesn't exist will panic, while calling
+    /// this with a type that appears multiple times will return a pointer to
+    /// the first occurrence.
+    unsafe fn as_mut_ptr_of_unchecked<T: 'static>(&mut self) -> *mut T;
+
+    /// Views a tuple whose elements are all of type `T` as a slice.
+    ///
+    /// Tuples have no guaranteed layout, so the address of every element is
+    /// checked to follow the previous one contiguously. Returns `None` if any
+    /// element is not a `T` or the elements are not laid out as an array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// let samples = (1.0f32, 2.0f32, 4.0f32);
+    /// if let Some(slice) = samples.as_slice_of::<f32>() {
+    ///     assert_eq!(slice.iter().sum::<f32>(), 7.0);
+    /// }
+    ///
+    /// assert_eq!((1.0f32, 2u8).as_slice_of::<f32>(), None);
+    /// ```
+    fn as_slice_of<T: 'static>(&self) -> Option<&[T]>;
+
+    /// Views a tuple whose elements are all of type `T` as a mutable slice.
+    ///
+    /// Returns `None` under the same conditions as
+    /// [`as_slice_of`](TupleSet::as_slice_of).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// let mut samples = (3u32, 1u32, 2u32);
+    /// if let Some(slice) = samples.as_mut_slice_of::<u32>() {
+    ///     slice.sort_unstable();
+    ///     assert_eq!(samples, (1, 2, 3));
+    /// }
+    /// ```
+    fn as_mut_slice_of<T: 'static>(&mut self) -> Option<&mut [T]>;
+
+    /// Writes `Dst::from(src)` into the `Dst` field, where `src` is a clone of
+    /// the `Src` field, if both types appear exactly once in the tuple.
+    ///
+    /// Returns `true` if the conversion was performed, `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// let mut tuple = (42u8, "hello", 0u32);
+    /// assert!(tuple.convert_field::<u8, u32>());
+    /// assert_eq!(tuple, (42, "hello", 42));
+    ///
+    /// // Destination type not found
+    /// assert!(!tuple.convert_field::<u8, u64>());
+    /// ```
+    fn convert_field<Src: 'static + Clone, Dst: 'static + From<Src>>(&mut self) -> bool {
+        if !diagnostics::lookup::<Dst, Self>(self) {
+            return false;
+        }
+
+        let Some(src) = self.get::<Src>().cloned() else {
+            return false;
+        };
+
+        unsafe {
+            self.set_unchecked(Dst::from(src));
+        }
+
+        true
+    }
+
+    /// Get a reference to the value wrapped in the [`Tagged<Tag, T>`] field if
+    /// it appears exactly once.
+    ///
+    /// Returns `None` if the tagged type is not found or appears multiple
+    /// times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::{Tagged, TupleSet};
+    ///
+    /// struct Width;
+    /// struct Height;
+    ///
+    /// let size = (Tagged::<Width, u32>::new(640), Tagged::<Height, u32>::new(480));
+    /// assert_eq!(size.get_tagged::<Height, u32>(), Some(&480));
+    /// assert_eq!(size.get_tagged::<Width, u64>(), None);
+    /// ```
+    fn get_tagged<Tag: 'static, T: 'static>(&self) -> Option<&T> {
+        self.get::<Tagged<Tag, T>>().map(|tagged| &**tagged)
+    }
+
+    /// Sets the value wrapped in the [`Tagged<Tag, T>`] field if it appears
+    /// exactly once.
+    ///
+    /// Returns `None` on success, or `Some(value)` if the tagged type is not
+    /// found or appears multiple times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::{Tagged, TupleSet};
+    ///
+    /// struct Width;
+    /// struct Height;
+    ///
+    /// let mut size = (Tagged::<Width, u32>::new(640), Tagged::<Height, u32>::new(480));
+    /// assert_eq!(size.set_tagged::<Width, u32>(800), None);
+    /// assert_eq!(*size.0, 800);
+    /// ```
+    fn set_tagged<Tag: 'static, T: 'static>(&mut self, value: T) -> Option<T> {
+        self.set(Tagged::<Tag, T>::new(value)).map(Tagged::into_inner)
+    }
+
+    /// Applies a mapping function to the value wrapped in the
+    /// [`Tagged<Tag, T>`] field.
+    ///
+    /// Returns `Some(result)` on success, or `None` if the tagged type is not
+    /// found or appears multiple times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::{Tagged, TupleSet};
+    ///
+    /// struct Width;
+    /// struct Height;
+    ///
+    /// let mut size = (Tagged::<Width, u32>::new(640), Tagged::<Height, u32>::new(480));
+    /// size.map_tagged::<Height, u32, _, _>(|height| *height *= 2);
+    /// assert_eq!(*size.1, 960);
+    /// ```
+    fn map_tagged<Tag: 'static, T: 'static, F, R>(&mut self, f: F) -> Option<R>
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        self.map(|tagged: &mut Tagged<Tag, T>| f(tagged))
+    }
+
+    /// Get a reference to the value wrapped in the field of the
+    /// [`TransparentAlias`] type `W` if it appears exactly once.
+    ///
+    /// Returns `None` if the wrapper type is not found or appears multiple
+    /// times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::num::Wrapping;
+    ///
+    /// use tuple_set::TupleSet;
+    ///
+    /// let counters = (Wrapping(255u8), "requests");
+    /// assert_eq!(counters.get_aliased::<Wrapping<u8>>(), Some(&255));
+    /// assert_eq!(counters.get_aliased::<Wrapping<u16>>(), None);
+    /// ```
+    fn get_aliased<W: TransparentAlias>(&self) -> Option<&W::Inner> {
+        // SAFETY: `W` is a transparent wrapper over `W::Inner`
+        self.get::<W>().map(|wrapper| unsafe { &*(&raw const *wrapper).cast::<W::Inner>() })
+    }
+
+    /// Sets the value wrapped in the field of the [`TransparentAlias`] type
+    /// `W` if it appears exactly once.
+    ///
+    /// Returns `None` on success, or `Some(value)` if the wrapper type is not
+    /// found or appears multiple times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::num::Wrapping;
+    ///
+    /// use tuple_set::TupleSet;
+    ///
+    /// let mut counters = (Wrapping(255u8), "requests");
+    /// assert_eq!(counters.set_aliased::<Wrapping<u8>>(0), None);
+    /// assert_eq!(counters.0, Wrapping(0));
+    /// ```
+    fn set_aliased<W: TransparentAlias>(&mut self, value: W::Inner) -> Option<W::Inner> {
+        let Some(field) = self.get_mut::<W>() else {
+            return Some(value);
+        };
+
+        // SAFETY: `W` is a transparent wrapper over `W::Inner`
+        unsafe {
+            *core::ptr::from_mut(field).cast::<W::Inner>() = value;
+        }
+
+        None
+    }
+
+    /// Applies a mapping function to the value wrapped in the field of the
+    /// [`TransparentAlias`] type `W`.
+    ///
+    /// Returns `Some(result)` on success, or `None` if the wrapper type is not
+    /// found or appears multiple times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::num::Saturating;
+    ///
+    /// use tuple_set::TupleSet;
+    ///
+    /// let mut budget = (Saturating(250u8), true);
+    /// budget.map_aliased::<Saturating<u8>, _, _>(|remaining| *remaining -= 50);
+    /// assert_eq!(budget.0, Saturating(200));
+    /// ```
+    fn map_aliased<W: TransparentAlias, F, R>(&mut self, f: F) -> Option<R>
+    where
+        F: FnOnce(&mut W::Inner) -> R,
+    {
+        // SAFETY: `W` is a transparent wrapper over `W::Inner`
+        self.map(|wrapper: &mut W| f(unsafe { &mut *(&raw mut *wrapper).cast::<W::Inner>() }))
+    }
+
+    /// Get a reference to the value wrapped in the [`Named<NAME, T>`] field if
+    /// it appears exactly once.
+    ///
+    /// Returns `None` if the named type is not found or appears multiple
+    /// times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::{Named, TupleSet};
+    ///
+    /// let timeouts = (Named::<"connect", u64>::new(5), Named::<"read", u64>::new(30));
+    /// assert_eq!(timeouts.get_named::<"connect", u64>(), Some(&5));
+    /// assert_eq!(timeouts.get_named::<"write", u64>(), None);
+    /// ```
+    #[cfg(feature = "unstable-named")]
+    fn get_named<const NAME: &'static str, T: 'static>(&self) -> Option<&T> {
+        self.get::<Named<NAME, T>>().map(|named| &**named)
+    }
+
+    /// Sets the value wrapped in the [`Named<NAME, T>`] field if it appears
+    /// exactly once.
+    ///
+    /// Returns `None` on success, or `Some(value)` if the named type is not
+    /// found or appears multiple times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::{Named, TupleSet};
+    ///
+    /// let mut timeouts = (Named::<"connect", u64>::new(5), Named::<"read", u64>::new(30));
+    /// assert_eq!(timeouts.set_named::<"read", u64>(60), None);
+    /// assert_eq!(*timeouts.1, 60);
+    /// ```
+    #[cfg(feature = "unstable-named")]
+    fn set_named<const NAME: &'static str, T: 'static>(&mut self, value: T) -> Option<T> {
+        self.set(Named::<NAME, T>::new(value)).map(Named::into_inner)
+    }
+
+    /// Applies a mapping function to the value wrapped in the
+    /// [`Named<NAME, T>`] field.
+    ///
+    /// Returns `Some(result)` on success, or `None` if the named type is not
+    /// found or appears multiple times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::{Named, TupleSet};
+    ///
+    /// let mut timeouts = (Named::<"connect", u64>::new(5), Named::<"read", u64>::new(30));
+    /// timeouts.map_named::<"connect", u64, _, _>(|timeout| *timeout *= 2);
+    /// assert_eq!(*timeouts.0, 10);
+    /// ```
+    #[cfg(feature = "unstable-named")]
+    fn map_named<const NAME: &'static str, T: 'static, F, R>(&mut self, f: F) -> Option<R>
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        self.map(|named: &mut Named<NAME, T>| f(named))
+    }
+
+    /// Calls `f` on a reference to every field of type `T`, in positional
+    /// order.
+    ///
+    /// Unlike most methods, `T` may appear any number of times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// let tuple = (1u8, "sensor", 2u8, 3u8);
+    /// let mut sum = 0;
+    /// tuple.for_each_of(|value: &u8| sum += value);
+    /// assert_eq!(sum, 6);
+    /// ```
+    fn for_each_of<'a, T: 'static, F: FnMut(&'a T)>(&'a self, f: F);
+
+    /// Calls `f` on a mutable reference to every field of type `T`, in
+    /// positional order.
+    ///
+    /// Unlike most methods, `T` may appear any number of times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// let mut tuple = (1u8, "sensor", 2u8, 3u8);
+    /// tuple.for_each_of_mut(|value: &mut u8| *value *= 10);
+    /// assert_eq!(tuple, (10, "sensor", 20, 30));
+    /// ```
+    fn for_each_of_mut<'a, T: 'static, F: FnMut(&'a mut T)>(&'a mut self, f: F);
+
+    /// Returns an iterator over mutable references to every field of type
+    /// `T`, in positional order.
+    ///
+    /// Unlike most methods, `T` may appear any number of times. Distinct
+    /// fields never alias, so the references can be held simultaneously.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// let mut stats = (3u32, "requests", 5u32, 8u32);
+    /// for counter in stats.iter_of_mut::<u32>() {
+    ///     *counter += 1;
+    /// }
+    /// assert_eq!(stats, (4, "requests", 6, 9));
+    /// ```
+    fn iter_of_mut<T: 'static>(&mut self) -> impl Iterator<Item = &mut T>;
+
+    /// Folds every field of type `T`, in positional order, into an
+    /// accumulator starting from `init`.
+    ///
+    /// Unlike most methods, `T` may appear any number of times. Returns
+    /// `init` if `T` is not found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// let telemetry = (120u64, "eu-west", 35u64, 7u64);
+    /// assert_eq!(telemetry.fold_of(0, |total, sent: &u64| total + sent), 162);
+    /// assert_eq!(telemetry.fold_of(0, |total, _: &bool| total + 1), 0);
+    /// ```
+    fn fold_of<T: 'static, A, F: FnMut(A, &T) -> A>(&self, init: A, mut f: F) -> A {
+        let mut acc = Some(init);
+        self.for_each_of(|value: &T| acc = acc.take().map(|acc| f(acc, value)));
+        // `acc` is only taken to be immediately replaced
+        acc.unwrap_or_else(|| unreachable!())
+    }
+
+    /// Collects references to every field of type `T`, in positional order,
+    /// into a `Vec`.
+    ///
+    /// Unlike most methods, `T` may appear any number of times. Without
+    /// allocating, see [`collect_refs_of`](TupleSet::collect_refs_of) with the
+    /// `heapless` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// let tuple = (1u8, "sensor", 2u8, 3u8);
+    /// assert_eq!(tuple.get_all::<u8>(), [&1, &2, &3]);
+    /// assert!(tuple.get_all::<bool>().is_empty());
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn get_all<T: 'static>(&self) -> alloc::vec::Vec<&T> {
+        let mut refs = alloc::vec::Vec::with_capacity(self.count::<T>());
+        self.for_each_of(|value| refs.push(value));
+        refs
+    }
+
+    /// Collects mutable references to every field of type `T`, in positional
+    /// order, into a `Vec`.
+    ///
+    /// The references borrow distinct fields, so they can be held at the same
+    /// time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// let mut tuple = (1u8, "sensor", 2u8);
+    /// if let [first, second] = tuple.get_all_mut::<u8>().as_mut_slice() {
+    ///     core::mem::swap(*first, *second);
+    /// }
+    /// assert_eq!(tuple, (2, "sensor", 1));
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn get_all_mut<T: 'static>(&mut self) -> alloc::vec::Vec<&mut T> {
+        self.iter_of_mut().collect()
+    }
+
+    /// Collects references to every field of type `T`, in positional order,
+    /// into a bounded [`heapless::Vec`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// let tuple = (1u8, "sensor", 2u8);
+    /// let refs = tuple.collect_refs_of::<u8, 4>().unwrap();
+    /// assert_eq!(refs, [&1, &2]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CapacityExceeded`] if `T` appears more than `N` times.
+    #[cfg(feature = "heapless")]
+    fn collect_refs_of<T: 'static, const N: usize>(
+        &self,
+    ) -> Result<heapless::Vec<&T, N>, CapacityExceeded> {
+        let required = self.count::<T>();
+        if required > N {
+            return Err(CapacityExceeded { required, capacity: N });
+        }
+        let mut refs = heapless::Vec::new();
+        self.for_each_of(|value| {
+            let _ = refs.push(value);
+        });
+        Ok(refs)
+    }
+
+    /// Collects mutable references to every field of type `T`, in
+    /// positional order, into a bounded [`heapless::Vec`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// let mut tuple = (1u8, "sensor", 2u8);
+    /// for value in tuple.collect_mut_refs_of::<u8, 4>().unwrap() {
+    ///     *value += 1;
+    /// }
+    /// assert_eq!(tuple, (2, "sensor", 3));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CapacityExceeded`] if `T` appears more than `N` times.
+    #[cfg(feature = "heapless")]
+    fn collect_mut_refs_of<T: 'static, const N: usize>(
+        &mut self,
+    ) -> Result<heapless::Vec<&mut T, N>, CapacityExceeded> {
+        let required = self.count::<T>();
+        if required > N {
+            return Err(CapacityExceeded { required, capacity: N });
+        }
+        let mut refs = heapless::Vec::new();
+        self.for_each_of_mut(|value| {
+            let _ = refs.push(value);
+        });
+        Ok(refs)
+    }
+
+    /// Collects clones of every field of type `T`, in positional order, into
+    /// a bounded [`heapless::Vec`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// let tuple = (1u8, "sensor", 2u8);
+    /// let values = tuple.collect_cloned_of::<u8, 4>().unwrap();
+    /// assert_eq!(values, [1, 2]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CapacityExceeded`] if `T` appears more than `N` times.
+    #[cfg(feature = "heapless")]
+    fn collect_cloned_of<T: 'static + Clone, const N: usize>(
+        &self,
+    ) -> Result<heapless::Vec<T, N>, CapacityExceeded> {
+        let required = self.count::<T>();
+        if required > N {
+            return Err(CapacityExceeded { required, capacity: N });
+        }
+        let mut values = heapless::Vec::new();
+        self.for_each_of(|value: &T| {
+            let _ = values.push(value.clone());
+        });
+        Ok(values)
+    }
+
+    /// Collects references to every field of type `T`, in positional order,
+    /// into a [`GenericArray`](generic_array::GenericArray) whose length is the
+    /// `typenum` type `N`.
+    ///
+    /// Unlike arrays sized by a const generic, the length can take part in
+    /// type-level arithmetic on stable Rust. Returns `None` if `T` does not
+    /// appear exactly `N` times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use generic_array::typenum::{U2, U3};
+    /// use tuple_set::TupleSet;
+    ///
+    /// let tuple = (1u8, "sensor", 2u8);
+    /// let refs = tuple.occurrences::<u8, U2>().unwrap();
+    /// assert_eq!(refs.as_slice(), [&1, &2]);
+    /// assert!(tuple.occurrences::<u8, U3>().is_none());
+    /// ```
+    #[cfg(feature = "generic-array")]
+    fn occurrences<T: 'static, N: generic_array::ArrayLength>(
+        &self,
+    ) -> Option<generic_array::GenericArray<&T, N>> {
+        if self.count::<T>() != N::USIZE {
+            return None;
+        }
+        let mut refs = generic_array::GenericArray::<Option<&T>, N>::default();
+        let mut slots = refs.iter_mut();
+        self.for_each_of(|value| {
+            if let Some(slot) = slots.next() {
+                *slot = Some(value);
+            }
+        });
+        generic_array::GenericArray::try_from_iter(refs.into_iter().flatten()).ok()
+    }
+
+    /// Takes every field of type `T`, in positional order, into a
+    /// stack-allocated [`arrayvec::ArrayVec`], leaving `T::default()` in their
+    /// place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// let mut tuple = (String::from("a"), 1u8, String::from("b"));
+    /// let drained = tuple.drain_all_of::<String, 4>().unwrap();
+    /// assert_eq!(drained.as_slice(), ["a", "b"]);
+    /// assert_eq!(tuple, (String::new(), 1, String::new()));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CapacityExceeded`] if `T` appears more than `N` times, in
+    /// which case the tuple is left untouched.
+    #[cfg(feature = "arrayvec")]
+    fn drain_all_of<T: 'static + Default, const N: usize>(
+        &mut self,
+    ) -> Result<arrayvec::ArrayVec<T, N>, CapacityExceeded> {
+        let required = self.count::<T>();
+        if required > N {
+            return Err(CapacityExceeded { required, capacity: N });
+        }
+        let mut drained = arrayvec::ArrayVec::new();
+        self.for_each_of_mut(|value: &mut T| drained.push(core::mem::take(value)));
+        Ok(drained)
+    }
+
+    /// Writes successive items from `iter` into every field of type `T`, in
+    /// positional order.
+    ///
+    /// Unlike most methods, `T` may appear any number of times. Filling stops
+    /// early when the iterator is exhausted, leaving the remaining fields
+    /// untouched. Returns the number of fields filled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// let mut tuple = (0u8, "sensor", 0u8, 0u8);
+    /// assert_eq!(tuple.fill_from_iter([10u8, 20]), 2);
+    /// assert_eq!(tuple, (10, "sensor", 20, 0));
+    /// ```
+    fn fill_from_iter<T: 'static, I: IntoIterator<Item = T>>(&mut self, iter: I) -> usize;
+
+    /// Clones every field of type `T` into the start of `slice`, in
+    /// positional order.
+    ///
+    /// Unlike most methods, `T` may appear any number of times. Returns the
+    /// number of fields written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// let tuple = (1u8, "sensor", 2u8, 3u8);
+    /// let mut readings = [0u8; 4];
+    /// assert_eq!(tuple.scatter_to_slice(&mut readings), Ok(3));
+    /// assert_eq!(readings, [1, 2, 3, 0]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SliceTooShort`] if `slice` cannot hold every occurrence of
+    /// `T`, in which case the slice is left untouched.
+    fn scatter_to_slice<T: 'static + Clone>(&self, slice: &mut [T])
+    -> Result<usize, SliceTooShort>;
+
+    /// Takes the value out of a `T` field, leaving `T::default()` in its place.
     #[inline]
     fn take<T: 'static + Default>(&mut self) -> Option<T> {
         // Get the current value and replace with default
         self.map(core::mem::take)
     }
+
+    /// Replaces the unique `T` field with `T::default()`, intentionally leaking
+    /// the previous value without running its destructor.
+    ///
+    /// This is an escape hatch for values whose `Drop` must not run, e.g.
+    /// handles whose ownership was transferred to foreign code. Returns `true`
+    /// if the field was found exactly once and forgotten, `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// let mut tuple = (vec![1, 2, 3], "hello");
+    /// assert!(tuple.forget_field::<Vec<i32>>());
+    /// assert!(tuple.0.is_empty());
+    /// ```
+    #[inline]
+    fn forget_field<T: 'static + Default>(&mut self) -> bool {
+        self.take::<T>().map(core::mem::forget).is_some()
+    }
+
+    /// Returns clones of the fields of the types in `S`, so that they can
+    /// later be rolled back with [`restore`](TupleSet::restore).
+    ///
+    /// Returns `None` if any type of `S` is not found or appears multiple
+    /// times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// let mut state = (1u32, "idle", 2.5f64);
+    /// let saved = state.snapshot::<(u32, &str)>().unwrap();
+    /// state.set(7u32);
+    /// state.set("busy");
+    ///
+    /// assert!(state.restore(saved).is_none());
+    /// assert_eq!(state, (1, "idle", 2.5));
+    /// ```
+    fn snapshot<S: Selection>(&self) -> Option<S> {
+        S::select_from(self)
+    }
+
+    /// Writes back the fields of the types in `S`, typically obtained from
+    /// [`snapshot`](TupleSet::snapshot).
+    ///
+    /// Returns `None` on success, `Some(values)` if any type of `S` is not
+    /// found or appears multiple times, in which case no field is written.
+    fn restore<S: Selection>(&mut self, values: S) -> Option<S> {
+        values.write_into(self)
+    }
+
+    /// Splits the tuple into mutable references to the fields of the types in
+    /// `A` and to those of the types in `B`, so that two subsystems sharing
+    /// the tuple can mutate their own fields at the same time.
+    ///
+    /// Returns `None` if any type of `A` or `B` is not found or appears
+    /// multiple times in the tuple, or appears more than once across `A` and
+    /// `B`. As the references are built from the field pointers, the tuple
+    /// must implement [`InlineFields`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// let mut context = (0u32, 1.5f64, "idle", false);
+    /// let ((ticks, ready), (speed,)) = context.split_by_types::<(u32, bool), (f64,)>().unwrap();
+    /// *ticks += 1;
+    /// *speed *= 2.0;
+    /// *ready = true;
+    /// assert_eq!(context, (1, 3.0, "idle", true));
+    ///
+    /// assert!(context.split_by_types::<(u32,), (u32,)>().is_none());
+    /// assert!(context.split_by_types::<(u32,), (char,)>().is_none());
+    /// ```
+    fn split_by_types<A: SplitTargets, B: SplitTargets>(
+        &mut self,
+    ) -> Option<(A::Refs<'_>, B::Refs<'_>)>
+    where
+        Self: Sized + InlineFields,
+    {
+        if types::first_duplicate(A::type_ids().as_ref()).is_some()
+            || types::first_duplicate(B::type_ids().as_ref()).is_some()
+            || !A::is_disjoint_from::<B>()
+        {
+            return None;
+        }
+
+        let (left, right) = (A::resolve(self)?, B::resolve(self)?);
+        let base = (&raw mut *self).cast::<u8>();
+        // SAFETY: `InlineFields` guarantees that the offsets resolved on this
+        // tuple point within it, to the only field of each type. The types
+        // are pairwise distinct, so that no two references alias, and the
+        // tuple stays mutably borrowed for as long as the references live.
+        unsafe { Some((A::refs_at(base, left), B::refs_at(base, right))) }
+    }
+
+    /// Moves the tuple into a new tuple shape, e.g. a newer version of a
+    /// persisted context with more or reordered fields.
+    ///
+    /// Every field whose type appears exactly once in both tuples is moved into
+    /// the target, while the remaining target fields are left to their
+    /// `Default` value. Fields that cannot be moved are dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleSet;
+    ///
+    /// let v1 = (3u32, "localhost");
+    /// let v2: (&str, bool, u32) = v1.migrate();
+    /// assert_eq!(v2, ("localhost", false, 3));
+    /// ```
+    fn migrate<Target: TupleSet + Default>(self) -> Target
+    where
+        Self: Sized;
 }
 
-// Macro to generate implementations
-macro_rules! impl_tuple_traits {
-    ($($idx:tt: $T:ident),+) => {
-        impl<$($T: 'static),+> TupleSet for ($($T,)+) {
-			fn count<Target: 'static>(&self) -> usize {
-                0 $(+ (TypeId::of::<Target>() == TypeId::of::<$T>()) as usize)+
+/// Implements the required methods of [`TupleSet`] over the given fields of
+/// `self`, each with its type, listed in declaration order, and overrides the
+/// checked lookups with versions locating the field in a single pass.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_tuple_set_fields {
+    ($($field:tt: $T:ty),+) => {
+        fn count<Target: 'static>(&self) -> usize {
+            0 $(+ (core::any::TypeId::of::<Target>() == core::any::TypeId::of::<$T>()) as usize)+
+        }
+
+        unsafe fn get_unchecked<Target: 'static>(&self) -> &Target {
+            $(
+                if core::any::TypeId::of::<Target>() == core::any::TypeId::of::<$T>() {
+                    // SAFETY: We've verified Target == $T via TypeId
+                    unsafe {
+                        let ptr = &self.$field as *const $T as *const Target;
+                        return &*ptr;
+                    }
+                }
+            )+
+
+            $crate::__private::unchecked_miss::<Target>(
+                &[$(core::any::type_name::<$T>()),+],
+                $crate::TupleSet::count::<Target>(self),
+            );
+        }
+
+        unsafe fn map_unchecked<Target: 'static, F, R>(&mut self, f: F) -> R
+        where
+            F: FnOnce(&mut Target) -> R,
+        {
+            $(
+                if core::any::TypeId::of::<Target>() == core::any::TypeId::of::<$T>() {
+                    // SAFETY: We've verified Target == $T via TypeId
+                    unsafe {
+                        let ptr = &mut self.$field as *mut $T as *mut Target;
+                        return f(&mut *ptr);
+                    }
+                }
+            )+
+
+            $crate::__private::unchecked_miss::<Target>(
+                &[$(core::any::type_name::<$T>()),+],
+                $crate::TupleSet::count::<Target>(self),
+            );
+        }
+
+        fn migrate<Target: $crate::TupleSet + Default>(self) -> Target {
+            let mut unique = [$($crate::TupleSet::contains_unique::<$T>(&self)),+].into_iter();
+            let mut target = Target::default();
+            $(
+                if unique.next() == Some(true) && $crate::TupleSet::contains_unique::<$T>(&target) {
+                    // SAFETY: We've verified $T appears exactly once in Target
+                    unsafe { $crate::TupleSet::set_unchecked(&mut target, self.$field) };
+                }
+            )+
+            target
+        }
+
+        fn for_each_of<'a, Target: 'static, F: FnMut(&'a Target)>(&'a self, mut f: F) {
+            $(
+                if core::any::TypeId::of::<Target>() == core::any::TypeId::of::<$T>() {
+                    // SAFETY: We've verified Target == $T via TypeId
+                    f(unsafe { &*(&raw const self.$field).cast::<Target>() });
+                }
+            )+
+        }
+
+        fn for_each_of_mut<'a, Target: 'static, F: FnMut(&'a mut Target)>(&'a mut self, mut f: F) {
+            $(
+                if core::any::TypeId::of::<Target>() == core::any::TypeId::of::<$T>() {
+                    // SAFETY: We've verified Target == $T via TypeId, and
+                    // each field is borrowed at most once
+                    f(unsafe { &mut *(&raw mut self.$field).cast::<Target>() });
+                }
+            )+
+        }
+
+        fn iter_of_mut<Target: 'static>(&mut self) -> impl Iterator<Item = &mut Target> {
+            [$(
+                if core::any::TypeId::of::<Target>() == core::any::TypeId::of::<$T>() {
+                    // SAFETY: We've verified Target == $T via TypeId, and
+                    // each field is borrowed at most once
+                    Some(unsafe { &mut *(&raw mut self.$field).cast::<Target>() })
+                } else {
+                    None
+                }
+            ),+]
+            .into_iter()
+            .flatten()
+        }
+
+        fn fill_from_iter<Target: 'static, I>(&mut self, iter: I) -> usize
+        where
+            I: IntoIterator<Item = Target>,
+        {
+            let mut iter = iter.into_iter();
+            let mut filled = 0;
+            $(
+                if core::any::TypeId::of::<Target>() == core::any::TypeId::of::<$T>() {
+                    let Some(value) = iter.next() else {
+                        return filled;
+                    };
+                    // SAFETY: We've verified Target == $T via TypeId
+                    unsafe {
+                        *(&raw mut self.$field).cast::<Target>() = value;
+                    }
+                    filled += 1;
+                }
+            )+
+            filled
+        }
+
+        fn scatter_to_slice<Target: 'static + Clone>(
+            &self,
+            slice: &mut [Target],
+        ) -> Result<usize, $crate::SliceTooShort> {
+            let required = $crate::TupleSet::count::<Target>(self);
+            if slice.len() < required {
+                return Err($crate::SliceTooShort { required, available: slice.len() });
             }
+            let mut written = 0;
+            $(
+                if core::any::TypeId::of::<Target>() == core::any::TypeId::of::<$T>() {
+                    // SAFETY: We've verified Target == $T via TypeId
+                    slice[written] = unsafe { (*(&raw const self.$field).cast::<Target>()).clone() };
+                    written += 1;
+                }
+            )+
+            Ok(written)
+        }
+
+        unsafe fn as_ptr_of_unchecked<Target: 'static>(&self) -> *const Target {
+            $(
+                if core::any::TypeId::of::<Target>() == core::any::TypeId::of::<$T>() {
+                    return (&raw const self.$field).cast::<Target>();
+                }
+            )+
+
+            $crate::__private::unchecked_miss::<Target>(
+                &[$(core::any::type_name::<$T>()),+],
+                $crate::TupleSet::count::<Target>(self),
+            );
+        }
+
+        fn as_slice_of<Target: 'static>(&self) -> Option<&[Target]> {
+            let base = (&raw const *self).cast::<Target>();
+            let mut len = 0;
+            $(
+                if core::any::TypeId::of::<Target>() != core::any::TypeId::of::<$T>()
+                    || !core::ptr::eq((&raw const self.$field).cast::<Target>(), base.wrapping_add(len))
+                {
+                    return None;
+                }
+                len += 1;
+            )+
+
+            // SAFETY: every element is a Target, laid out contiguously from
+            // the start of the value
+            Some(unsafe { core::slice::from_raw_parts(base, len) })
+        }
+
+        fn as_mut_slice_of<Target: 'static>(&mut self) -> Option<&mut [Target]> {
+            let base = (&raw mut *self).cast::<Target>();
+            let mut len = 0;
+            $(
+                if core::any::TypeId::of::<Target>() != core::any::TypeId::of::<$T>()
+                    || !core::ptr::eq((&raw mut self.$field).cast::<Target>(), base.wrapping_add(len))
+                {
+                    return None;
+                }
+                len += 1;
+            )+
+
+            // SAFETY: every element is a Target, laid out contiguously from
+            // the start of the value
+            Some(unsafe { core::slice::from_raw_parts_mut(base, len) })
+        }
+
+        fn get<Target: 'static>(&self) -> Option<&Target> {
+            // SAFETY: the pointer was derived from `self` to its only field of
+            // type `Target`
+            $crate::TupleSet::as_ptr_of::<Target>(self).map(|ptr| unsafe { &*ptr })
+        }
+
+        fn get_mut<Target: 'static>(&mut self) -> Option<&mut Target> {
+            // SAFETY: the pointer was derived from `self` to its only field of
+            // type `Target`
+            $crate::TupleSet::as_mut_ptr_of::<Target>(self).map(|ptr| unsafe { &mut *ptr })
+        }
 
-            unsafe fn get_unchecked<Target: 'static>(&self) -> &Target {
-                $(
-                    if TypeId::of::<Target>() == TypeId::of::<$T>() {
-                        // SAFETY: We've verified Target == $T via TypeId
-                        unsafe {
-                            let ptr = &self.$idx as *const $T as *const Target;
-                            return &*ptr;
-                        }
+        fn as_ptr_of<Target: 'static>(&self) -> Option<*const Target> {
+            // Locates the field in a single pass, bailing out on a second match
+            let mut found = None;
+            $(
+                if core::any::TypeId::of::<Target>() == core::any::TypeId::of::<$T>() {
+                    if found.is_some() {
+                        $crate::__private::lookup_miss::<Target, Self>(self);
+                        return None;
                     }
-				)+
+                    found = Some((&raw const self.$field).cast::<Target>());
+                }
+            )+
 
-                panic!(
-                    "Type '{}' not found in tuple. This is undefined behavior.",
-                    core::any::type_name::<Target>()
-                );
+            if found.is_none() {
+                $crate::__private::lookup_miss::<Target, Self>(self);
             }
+            found
+        }
 
-            unsafe fn map_unchecked<Target: 'static, F, R>(&mut self, f: F) -> R
-            where
-                F: FnOnce(&mut Target) -> R,
-            {
-                $(
-                    if TypeId::of::<Target>() == TypeId::of::<$T>() {
-                        // SAFETY: We've verified Target == $T via TypeId
-                        unsafe {
-                            let ptr = &mut self.$idx as *mut $T as *mut Target;
-                            return f(&mut *ptr);
-                        }
+        fn as_mut_ptr_of<Target: 'static>(&mut self) -> Option<*mut Target> {
+            // Locates the field in a single pass, bailing out on a second match
+            let mut found = None;
+            $(
+                if core::any::TypeId::of::<Target>() == core::any::TypeId::of::<$T>() {
+                    if found.is_some() {
+                        $crate::__private::lookup_miss::<Target, Self>(self);
+                        return None;
                     }
-                )+
+                    found = Some((&raw mut self.$field).cast::<Target>());
+                }
+            )+
 
-                panic!(
-                    "Type '{}' not found in tuple. This is undefined behavior.",
-                    core::any::type_name::<Target>()
-                );
+            if found.is_none() {
+                $crate::__private::lookup_miss::<Target, Self>(self);
             }
+            found
+        }
+
+        unsafe fn as_mut_ptr_of_unchecked<Target: 'static>(&mut self) -> *mut Target {
+            $(
+                if core::any::TypeId::of::<Target>() == core::any::TypeId::of::<$T>() {
+                    return (&raw mut self.$field).cast::<Target>();
+                }
+            )+
+
+            $crate::__private::unchecked_miss::<Target>(
+                &[$(core::any::type_name::<$T>()),+],
+                $crate::TupleSet::count::<Target>(self),
+            );
+        }
+    };
+}
+
+// Macro to generate implementations
+macro_rules! impl_tuple_traits {
+    ($($idx:tt: $T:ident $U:ident),+) => {
+        impl<$($T: 'static),+> TupleSet for ($($T,)+) {
+            $crate::__impl_tuple_set_fields!($($idx: $T),+);
         }
+
+        layout::impl_tuple_layout!($($idx: $T),+);
+        at::impl_tuple_at!($($idx: $T),+);
+        array::impl_tuple_array!($($idx: $T),+);
+        arith::impl_tuple_arith!($($idx: $T),+);
+        types::impl_tuple_types!($($idx: $T),+);
+        arity::impl_tuple_arity!($($idx: $T),+);
+        inline::impl_tuple_inline_fields!($($idx: $T),+);
+        dyn_fields::impl_tuple_dyn_fields!($($idx: $T),+);
+        convert::impl_tuple_convert!($($idx: $T $U),+);
+        apply::impl_tuple_apply!($($idx: $T),+);
+        codec::impl_tuple_codec!($($idx: $T),+);
+        patch::impl_tuple_patch!($($idx: $T),+);
+        partial::impl_tuple_partial!($($idx: $T),+);
+        permutation::impl_tuple_permutation!($($idx: $T),+);
+        selection::impl_tuple_selection!($($idx: $T),+);
+        view::impl_tuple_view!($($idx: $T),+);
+        frozen::impl_tuple_frozen!($($idx: $T),+);
+        #[cfg(feature = "alloc")]
+        validated::impl_tuple_validatable!($($idx: $T),+);
+        #[cfg(feature = "alloc")]
+        registry::impl_tuple_constructible!($($idx: $T),+);
+        #[cfg(feature = "serde")]
+        serialization::impl_tuple_serde!($($idx: $T),+);
+        #[cfg(feature = "rkyv")]
+        archived::impl_tuple_rkyv!($($idx: $T),+);
+        #[cfg(feature = "pyo3")]
+        python::impl_tuple_pyo3!($($idx: $T),+);
+        #[cfg(feature = "wasm")]
+        wasm::impl_tuple_wasm!($($idx: $T),+);
+        #[cfg(feature = "std")]
+        context::impl_tuple_context!($($idx: $T),+);
+        #[cfg(feature = "std")]
+        sync::impl_tuple_lockable!($($idx: $T),+);
+        #[cfg(feature = "unstable-const")]
+        const_access::impl_tuple_const!($($idx: $T),+);
+        #[cfg(feature = "unstable-const-type-id")]
+        const_count::impl_tuple_count_of!($($idx: $T),+);
+        #[cfg(feature = "algebra")]
+        algebra::impl_tuple_algebra!($($idx: $T),+);
+        #[cfg(feature = "algebra")]
+        builder::impl_tuple_builder!($($idx: $T),+);
+        #[cfg(feature = "algebra")]
+        canonical::impl_tuple_canonical!($($idx: $T),+);
+        #[cfg(feature = "algebra")]
+        flatten::impl_tuple_flatten!($($idx: $T),+);
+        #[cfg(feature = "algebra")]
+        type_eq::impl_tuple_type_eq!($($idx: $T),+);
     };
 }
 
+// Expands to `1` for each type, used to compute tuple arities in macros
+macro_rules! one {
+    ($T:ident) => {
+        1
+    };
+}
+
+pub(crate) use one;
+
 // Recursive macro to generate all tuple implementations from 1 to N elements
-// This builds up from 1-element tuples to N-element tuples
+// This builds up from 1-element tuples to N-element tuples. Each element
+// carries a second type identifier, used by traits relating two tuples of the
+// same arity.
 macro_rules! impl_tuple_traits_recursive {
     // Generate impl for current accumulated state, then add next element
-    (@ [$($done_idx:tt: $done_T:ident),+] $idx:tt: $T:ident $(, $($rest:tt)*)?) => {
-        impl_tuple_traits!($($done_idx: $done_T),+);
-        impl_tuple_traits_recursive!(@ [$($done_idx: $done_T,)+ $idx: $T] $($($rest)*)?);
+    (@ [$($done_idx:tt: $done_T:ident $done_U:ident),+] $idx:tt: $T:ident $U:ident $(, $($rest:tt)*)?) => {
+        impl_tuple_traits!($($done_idx: $done_T $done_U),+);
+        impl_tuple_traits_recursive!(@ [$($done_idx: $done_T $done_U,)+ $idx: $T $U] $($($rest)*)?);
     };
     // Final case: generate the last impl
-    (@ [$($done_idx:tt: $done_T:ident),+]) => {
-        impl_tuple_traits!($($done_idx: $done_T),+);
+    (@ [$($done_idx:tt: $done_T:ident $done_U:ident),+]) => {
+        impl_tuple_traits!($($done_idx: $done_T $done_U),+);
     };
     // Entry point: start with first element in accumulator
-    ($first_idx:tt: $first_T:ident $(, $($rest:tt)*)?) => {
-        impl_tuple_traits_recursive!(@ [$first_idx: $first_T] $($($rest)*)?);
+    ($first_idx:tt: $first_T:ident $first_U:ident $(, $($rest:tt)*)?) => {
+        impl_tuple_traits_recursive!(@ [$first_idx: $first_T $first_U] $($($rest)*)?);
     };
 }
 
-// Generate implementations for tuples up to 128 elements
+// Generate implementations for tuples up to 64 elements
 impl_tuple_traits_recursive!(
-    0: T1, 1: T2, 2: T3, 3: T4, 4: T5, 5: T6, 6: T7, 7: T8,
-    8: T9, 9: T10, 10: T11, 11: T12, 12: T13, 13: T14, 14: T15, 15: T16,
-    16: T17, 17: T18, 18: T19, 19: T20, 20: T21, 21: T22, 22: T23, 23: T24,
-    24: T25, 25: T26, 26: T27, 27: T28, 28: T29, 29: T30, 30: T31, 31: T32,
-    32: T33, 33: T34, 34: T35, 35: T36, 36: T37, 37: T38, 38: T39, 39: T40,
-    40: T41, 41: T42, 42: T43, 43: T44, 44: T45, 45: T46, 46: T47, 47: T48,
-    48: T49, 49: T50, 50: T51, 51: T52, 52: T53, 53: T54, 54: T55, 55: T56,
-    56: T57, 57: T58, 58: T59, 59: T60, 60: T61, 61: T62, 62: T63, 63: T64
+    0: T1 U1, 1: T2 U2, 2: T3 U3, 3: T4 U4, 4: T5 U5, 5: T6 U6,
+    6: T7 U7, 7: T8 U8, 8: T9 U9, 9: T10 U10, 10: T11 U11, 11: T12 U12,
+    12: T13 U13, 13: T14 U14, 14: T15 U15, 15: T16 U16, 16: T17 U17, 17: T18 U18,
+    18: T19 U19, 19: T20 U20, 20: T21 U21, 21: T22 U22, 22: T23 U23, 23: T24 U24,
+    24: T25 U25, 25: T26 U26, 26: T27 U27, 27: T28 U28, 28: T29 U29, 29: T30 U30,
+    30: T31 U31, 31: T32 U32, 32: T33 U33, 33: T34 U34, 34: T35 U35, 35: T36 U36,
+    36: T37 U37, 37: T38 U38, 38: T39 U39, 39: T40 U40, 40: T41 U41, 41: T42 U42,
+    42: T43 U43, 43: T44 U44, 44: T45 U45, 45: T46 U46, 46: T47 U47, 47: T48 U48,
+    48: T49 U49, 49: T50 U50, 50: T51 U51, 51: T52 U52, 52: T53 U53, 53: T54 U54,
+    54: T55 U55, 55: T56 U56, 56: T57 U57, 57: T58 U58, 58: T59 U59, 59: T60 U60,
+    60: T61 U61, 61: T62 U62, 62: T63 U63, 63: T64 U64
 );
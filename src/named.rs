@@ -0,0 +1,64 @@
+//! String-keyed tuple fields, relying on unstable const generics.
+
+use core::ops::{Deref, DerefMut};
+
+/// Wrapper associating a value with the compile-time key `NAME`.
+///
+/// Since `Named<"connect", u64>` and `Named<"read", u64>` are distinct
+/// types, fields sharing the same inner type become individually addressable
+/// by a [`TupleSet`](crate::TupleSet), e.g. through
+/// [`get_named`](crate::TupleSet::get_named). The wrapper dereferences to the
+/// inner value.
+///
+/// Requires the `unstable-named` feature and a nightly compiler.
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::{Named, TupleSet};
+///
+/// let mut timeouts = (Named::<"connect", u64>::new(5), Named::<"read", u64>::new(30));
+/// assert_eq!(timeouts.get_named::<"read", u64>(), Some(&30));
+///
+/// timeouts.set_named::<"connect", u64>(10);
+/// assert_eq!(*timeouts.0, 10);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct Named<const NAME: &'static str, T>(T);
+
+impl<const NAME: &'static str, T> Named<NAME, T> {
+    /// The key of the field.
+    pub const NAME: &'static str = NAME;
+
+    /// Wraps `value` under the key `NAME`.
+    #[must_use]
+    pub const fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Consumes the wrapper, returning the inner value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<const NAME: &'static str, T> Deref for Named<NAME, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<const NAME: &'static str, T> DerefMut for Named<NAME, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<const NAME: &'static str, T> From<T> for Named<NAME, T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
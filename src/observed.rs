@@ -0,0 +1,120 @@
+//! Tuple wrapper notifying an observer of every mutation.
+
+use core::{any::Any, ops::Deref};
+
+use crate::{TupleSet, TupleTypes, types};
+
+/// Description of a mutation of a field, passed to the observer of an
+/// [`ObservedTuple`].
+#[derive(Debug, Clone, Copy)]
+pub struct Mutation<'a> {
+    /// Position of the mutated field in the tuple.
+    pub position: usize,
+    /// Name of the type of the mutated field.
+    pub type_name: &'static str,
+    /// Previous value of the field, when available.
+    pub old: Option<&'a dyn Any>,
+    /// New value of the field.
+    pub new: &'a dyn Any,
+}
+
+/// Tuple wrapper invoking an observer after every `set` or `map`.
+///
+/// The wrapper dereferences to the tuple for read-only access, so mutations
+/// can only go through the observed methods.
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::ObservedTuple;
+///
+/// let mut log = Vec::new();
+/// let mut context = ObservedTuple::new((3u32, "idle"), |mutation| {
+///     log.push((mutation.position, mutation.type_name));
+/// });
+///
+/// assert!(context.set("busy").is_none());
+/// assert_eq!(context.map(|retries: &mut u32| *retries += 1), Some(()));
+/// assert_eq!(*context, (4, "busy"));
+/// assert_eq!(context.into_inner(), (4, "busy"));
+/// assert_eq!(log, [(1, "&str"), (0, "u32")]);
+/// ```
+pub struct ObservedTuple<T, F> {
+    tuple: T,
+    observer: F,
+}
+
+impl<T, F> ObservedTuple<T, F>
+where
+    T: TupleSet + TupleTypes,
+    F: FnMut(&Mutation<'_>),
+{
+    /// Creates a new wrapper notifying `observer` of every mutation of
+    /// `tuple`.
+    pub fn new(tuple: T, observer: F) -> Self {
+        Self { tuple, observer }
+    }
+
+    /// Sets the value for type `U` if it appears exactly once in the tuple,
+    /// and notifies the observer with both the previous and the new value.
+    ///
+    /// Returns `None` on success, `Some(value)` if the type is not found or
+    /// appears multiple times.
+    pub fn set<U: 'static>(&mut self, value: U) -> Option<U> {
+        let Some(position) = types::unique_position::<T, U>() else {
+            return Some(value);
+        };
+        let Some(field) = self.tuple.get_mut::<U>() else {
+            return Some(value);
+        };
+
+        let old = core::mem::replace(field, value);
+        let new = &*field;
+        (self.observer)(&Mutation {
+            position,
+            type_name: core::any::type_name::<U>(),
+            old: Some(&old),
+            new,
+        });
+
+        None
+    }
+
+    /// Applies a mapping function to the value of type `U` if it appears
+    /// exactly once in the tuple, and notifies the observer with the new
+    /// value.
+    ///
+    /// Returns `Some(result)` with the function's return value on success,
+    /// or `None` if the type is not found or appears multiple times.
+    pub fn map<U: 'static, G, R>(&mut self, f: G) -> Option<R>
+    where
+        G: FnOnce(&mut U) -> R,
+    {
+        let position = types::unique_position::<T, U>()?;
+        let field = self.tuple.get_mut::<U>()?;
+
+        let result = f(field);
+        let new = &*field;
+        (self.observer)(&Mutation {
+            position,
+            type_name: core::any::type_name::<U>(),
+            old: None,
+            new,
+        });
+
+        Some(result)
+    }
+
+    /// Consumes the wrapper, returning the underlying tuple.
+    pub fn into_inner(self) -> T {
+        self.tuple
+    }
+}
+
+impl<T, F> Deref for ObservedTuple<T, F> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.tuple
+    }
+}
@@ -0,0 +1,137 @@
+//! Incremental application of functions, collecting their arguments by type.
+
+use core::fmt;
+
+use crate::{Apply, Patchable, TupleSet};
+
+/// Trait for argument tuples collectible one element at a time into their
+/// [`Patch`](Patchable::Patch), i.e. a tuple of `Option`s of the same shape.
+pub trait PartialArgs: Patchable + Sized {
+    /// Returns the patch holding no argument.
+    fn vacant() -> Self::Patch;
+
+    /// Returns true if the patch holds every argument.
+    fn is_complete(patch: &Self::Patch) -> bool;
+
+    /// Takes every argument out of the patch, leaving it vacant, if it holds
+    /// all of them.
+    fn take_complete(patch: &mut Self::Patch) -> Option<Self>;
+}
+
+/// Function whose arguments, of the types in `Args`, are provided one at a
+/// time by type, invoking it once every argument is available.
+///
+/// Each argument type must appear exactly once in `Args`, so that values
+/// can be routed to their slot by type.
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::Partial;
+///
+/// fn connect(host: &'static str, port: u16, secure: bool) -> String {
+///     format!("{}://{host}:{port}", if secure { "https" } else { "http" })
+/// }
+///
+/// let mut partial = Partial::<_, (&str, u16, bool)>::new(connect);
+/// assert_eq!(partial.provide(true), Ok(None));
+/// assert_eq!(partial.provide(8443u16), Ok(None));
+/// assert_eq!(partial.provide('x'), Err('x'));
+///
+/// // The last argument invokes the function, and empties the slots
+/// let url = partial.provide("localhost").unwrap();
+/// assert_eq!(url.as_deref(), Some("https://localhost:8443"));
+/// assert!(!partial.is_complete());
+/// ```
+pub struct Partial<F, Args: PartialArgs> {
+    function: F,
+    slots: Args::Patch,
+}
+
+impl<F, Args, R> Partial<F, Args>
+where
+    Args: PartialArgs + for<'a> Apply<&'a mut F, Output = R>,
+    Args::Patch: TupleSet,
+{
+    /// Wraps `function`, with no argument provided yet.
+    pub fn new(function: F) -> Self {
+        Self::from_patch(function, Args::vacant())
+    }
+
+    /// Wraps `function`, with the arguments already held by `slots`.
+    pub fn from_patch(function: F, slots: Args::Patch) -> Self {
+        Self { function, slots }
+    }
+
+    /// Provides the argument of type `T`, replacing any value previously
+    /// provided for it.
+    ///
+    /// If every argument is then available, the function is invoked and its
+    /// result returned, leaving every slot empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns `value` back if `T` is not one of the argument types.
+    pub fn provide<T: 'static>(&mut self, value: T) -> Result<Option<R>, T> {
+        if !self.slots.contains_unique::<Option<T>>() {
+            return Err(value);
+        }
+        self.slots.set(Some(value));
+        Ok(self.call())
+    }
+
+    /// Invokes the function if every argument is available, leaving every
+    /// slot empty.
+    ///
+    /// Returns `None`, keeping the provided arguments, otherwise.
+    pub fn call(&mut self) -> Option<R> {
+        let args = Args::take_complete(&mut self.slots)?;
+        Some(args.apply(&mut self.function))
+    }
+
+    /// Returns true if every argument has been provided.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        Args::is_complete(&self.slots)
+    }
+
+    /// Returns the wrapped function and the arguments provided so far.
+    pub fn into_parts(self) -> (F, Args::Patch) {
+        (self.function, self.slots)
+    }
+}
+
+impl<F, Args: PartialArgs> fmt::Debug for Partial<F, Args>
+where
+    Args::Patch: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Partial").field("slots", &self.slots).finish_non_exhaustive()
+    }
+}
+
+macro_rules! impl_tuple_partial {
+    ($($idx:tt: $T:ident),+) => {
+        impl<$($T),+> $crate::PartialArgs for ($($T,)+) {
+            #[inline]
+            fn vacant() -> Self::Patch {
+                ($(None::<$T>,)+)
+            }
+
+            #[inline]
+            fn is_complete(patch: &Self::Patch) -> bool {
+                true $(&& patch.$idx.is_some())+
+            }
+
+            #[inline]
+            fn take_complete(patch: &mut Self::Patch) -> Option<Self> {
+                if !Self::is_complete(patch) {
+                    return None;
+                }
+                Some(($(patch.$idx.take()?,)+))
+            }
+        }
+    };
+}
+
+pub(crate) use impl_tuple_partial;
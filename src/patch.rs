@@ -0,0 +1,71 @@
+//! Partial updates of tuples through tuples of `Option`s.
+
+/// Trait for tuples that can be partially updated through a patch, i.e. a
+/// tuple of the same shape whose fields are each wrapped in an `Option`.
+pub trait Patchable {
+    /// The patch type, wrapping each field in an `Option`.
+    type Patch;
+
+    /// Overwrites every field for which the patch holds `Some` value, leaving
+    /// the others untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::Patchable;
+    ///
+    /// let mut config = (3u32, "localhost", false);
+    /// config.apply_patch((None, Some("example.com"), Some(true)));
+    /// assert_eq!(config, (3, "example.com", true));
+    /// ```
+    fn apply_patch(&mut self, patch: Self::Patch);
+}
+
+/// Trait for tuples able to compute the patch turning them into another value
+/// of the same type.
+pub trait Diffable: Patchable {
+    /// Returns the patch that, applied to `self`, yields `other`.
+    ///
+    /// Fields that are equal in both tuples are left as `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::{Diffable, Patchable};
+    ///
+    /// let mut old = (3u32, "localhost", false);
+    /// let new = (3u32, "example.com", false);
+    /// let patch = old.diff_as_patch(&new);
+    /// assert_eq!(patch, (None, Some("example.com"), None));
+    ///
+    /// old.apply_patch(patch);
+    /// assert_eq!(old, new);
+    /// ```
+    fn diff_as_patch(&self, other: &Self) -> Self::Patch;
+}
+
+macro_rules! impl_tuple_patch {
+    ($($idx:tt: $T:ident),+) => {
+        impl<$($T),+> $crate::Patchable for ($($T,)+) {
+            type Patch = ($(Option<$T>,)+);
+
+            #[inline]
+            fn apply_patch(&mut self, patch: Self::Patch) {
+                $(
+                    if let Some(value) = patch.$idx {
+                        self.$idx = value;
+                    }
+                )+
+            }
+        }
+
+        impl<$($T: Clone + PartialEq),+> $crate::Diffable for ($($T,)+) {
+            #[inline]
+            fn diff_as_patch(&self, other: &Self) -> Self::Patch {
+                ($((self.$idx != other.$idx).then(|| other.$idx.clone()),)+)
+            }
+        }
+    };
+}
+
+pub(crate) use impl_tuple_patch;
@@ -0,0 +1,46 @@
+//! Relations between tuples holding the same types in different orders.
+
+/// Trait comparing a tuple with another tuple holding the same types,
+/// possibly in a different order, e.g. contexts defined independently by two
+/// crates.
+pub trait UnorderedEq<Other: ?Sized> {
+    /// Returns true if `other` is a permutation of the types of `self` and
+    /// holds equal values for each of them.
+    ///
+    /// Returns false if the two tuples differ in arity or if any type of
+    /// `self` does not appear exactly once in both tuples, as values could
+    /// not be matched by type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::UnorderedEq;
+    ///
+    /// let left = (3u32, "localhost", true);
+    /// assert!(left.unordered_eq(&(true, 3u32, "localhost")));
+    /// assert!(!left.unordered_eq(&(false, 3u32, "localhost")));
+    /// assert!(!left.unordered_eq(&(true, 3u32)));
+    /// assert!(!(1u8, 1u8).unordered_eq(&(1u8, 1u8)));
+    /// ```
+    fn unordered_eq(&self, other: &Other) -> bool;
+}
+
+macro_rules! impl_tuple_permutation {
+    ($($idx:tt: $T:ident),+) => {
+        impl<$($T: 'static + PartialEq),+, Other> $crate::UnorderedEq<Other> for ($($T,)+)
+        where
+            Other: $crate::TupleSet + $crate::TupleArity + ?Sized,
+        {
+            #[inline]
+            fn unordered_eq(&self, other: &Other) -> bool {
+                <Self as $crate::TupleArity>::ARITY == Other::ARITY
+                    $(
+                        && $crate::TupleSet::contains_unique::<$T>(self)
+                        && other.get::<$T>() == Some(&self.$idx)
+                    )+
+            }
+        }
+    };
+}
+
+pub(crate) use impl_tuple_permutation;
@@ -0,0 +1,231 @@
+//! PyO3 conversions of tuples into Python dicts keyed by type name.
+//!
+//! Positional conversions to and from Python tuples are already provided by
+//! PyO3 itself, for tuples of up to 12 elements.
+
+use pyo3::{
+    Borrowed, Bound, IntoPyObject, Py, PyAny, PyErr, PyResult, Python,
+    exceptions::PyKeyError,
+    pyclass, pymethods,
+    types::{PyAnyMethods, PyDict, PyDictMethods},
+};
+
+/// Trait for tuples convertible into a Python dict keyed by the type name of
+/// each field.
+pub trait IntoPyKeyed<'py> {
+    /// Converts the tuple into a dict from type names to field values.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ValueError` if a type appears multiple times in the tuple,
+    /// or the error raised converting a field.
+    fn into_py_keyed(self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>>;
+}
+
+/// Trait for tuples extractable from a Python dict keyed by the type name of
+/// each field.
+pub trait FromPyKeyed<'py>: Sized {
+    /// Extracts the tuple from a dict from type names to field values.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ValueError` if a type appears multiple times in the tuple,
+    /// a `KeyError` if a key is unknown or missing, or the error raised
+    /// extracting a field.
+    fn from_py_keyed(dict: &Bound<'py, PyDict>) -> PyResult<Self>;
+}
+
+/// Wrapper converting a tuple to and from a Python dict keyed by the type
+/// name of each field, as reported by [`core::any::type_name`].
+///
+/// # Examples
+///
+/// ```
+/// use pyo3::{
+///     IntoPyObject, Python,
+///     types::{PyAnyMethods, PyDictMethods},
+/// };
+/// use tuple_set::PyKeyed;
+///
+/// Python::initialize();
+/// Python::attach(|py| {
+///     let dict = PyKeyed::new((42u32, true)).into_pyobject(py).unwrap();
+///     assert_eq!(dict.get_item("u32").unwrap().unwrap().extract::<u32>().unwrap(), 42);
+///
+///     let decoded: PyKeyed<(u32, bool)> = dict.extract().unwrap();
+///     assert_eq!(decoded.into_inner(), (42, true));
+/// });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct PyKeyed<T> {
+    tuple: T,
+}
+
+impl<T> PyKeyed<T> {
+    /// Wraps `tuple` to be converted into a dict keyed by type name.
+    pub fn new(tuple: T) -> Self {
+        Self { tuple }
+    }
+
+    /// Returns the wrapped tuple.
+    pub fn into_inner(self) -> T {
+        self.tuple
+    }
+}
+
+impl<T> core::ops::Deref for PyKeyed<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.tuple
+    }
+}
+
+impl<T> core::ops::DerefMut for PyKeyed<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.tuple
+    }
+}
+
+impl<T> From<T> for PyKeyed<T> {
+    fn from(tuple: T) -> Self {
+        Self::new(tuple)
+    }
+}
+
+impl<'py, T: IntoPyKeyed<'py>> IntoPyObject<'py> for PyKeyed<T> {
+    type Target = PyDict;
+    type Output = Bound<'py, PyDict>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> PyResult<Self::Output> {
+        self.tuple.into_py_keyed(py)
+    }
+}
+
+impl<'py, T: FromPyKeyed<'py>> pyo3::FromPyObject<'_, 'py> for PyKeyed<T> {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, 'py, PyAny>) -> PyResult<Self> {
+        T::from_py_keyed(&obj.cast::<PyDict>()?.to_owned()).map(Self::new)
+    }
+}
+
+/// Python-visible, read-only view over the fields of a tuple, looked up by
+/// type name.
+///
+/// Since `#[pyclass]` types cannot be generic, the view holds the fields
+/// converted into a dict keyed by type name. It is exposed to Python as
+/// `TupleView`, whose `get(type_name)` method raises a `KeyError` for
+/// unknown type names.
+///
+/// # Examples
+///
+/// ```
+/// use pyo3::{Python, types::PyAnyMethods};
+/// use tuple_set::TupleView;
+///
+/// Python::initialize();
+/// Python::attach(|py| {
+///     let view = TupleView::new(py, (8080u16, String::from("localhost"))).unwrap();
+///     assert_eq!(view.get(py, "u16").unwrap().extract::<u16>().unwrap(), 8080);
+///     assert!(view.get(py, "u8").is_err());
+/// });
+/// ```
+#[pyclass(frozen, module = "tuple_set")]
+pub struct TupleView {
+    fields: Py<PyDict>,
+}
+
+impl TupleView {
+    /// Creates a view over the fields of `tuple`.
+    ///
+    /// # Errors
+    ///
+    /// See [`IntoPyKeyed::into_py_keyed`].
+    pub fn new<'py, T: IntoPyKeyed<'py>>(py: Python<'py>, tuple: T) -> PyResult<Self> {
+        Ok(Self { fields: tuple.into_py_keyed(py)?.unbind() })
+    }
+}
+
+#[pymethods]
+impl TupleView {
+    /// Returns the field with the given type name.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `KeyError` if no field has the given type name.
+    pub fn get<'py>(&self, py: Python<'py>, type_name: &str) -> PyResult<Bound<'py, PyAny>> {
+        self.fields
+            .bind(py)
+            .get_item(type_name)?
+            .ok_or_else(|| PyKeyError::new_err(alloc::format!("unknown type name `{type_name}`")))
+    }
+
+    /// Returns the number of fields.
+    pub fn __len__(&self, py: Python<'_>) -> usize {
+        self.fields.bind(py).len()
+    }
+}
+
+/// Returns a `ValueError` if a type appears multiple times in the tuple.
+pub(crate) fn check_unique(type_ids: &[core::any::TypeId], names: &[&'static str]) -> PyResult<()> {
+    match crate::types::first_duplicate(type_ids) {
+        Some(position) => Err(pyo3::exceptions::PyValueError::new_err(alloc::format!(
+            "type `{}` appears multiple times and cannot be used as a key",
+            names[position]
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Returns a `KeyError` for the first key of `dict` that is not in `names`.
+pub(crate) fn unknown_key(dict: &Bound<'_, PyDict>, names: &[&'static str]) -> PyErr {
+    dict.iter()
+        .map(|(key, _)| key)
+        .find(|key| key.extract::<&str>().map_or(true, |key| !names.contains(&key)))
+        .map_or_else(
+            || PyKeyError::new_err("unknown type name"),
+            |key| PyKeyError::new_err(alloc::format!("unknown type name {key}")),
+        )
+}
+
+macro_rules! impl_tuple_pyo3 {
+    ($($idx:tt: $T:ident),+) => {
+        impl<'py, $($T: 'static + pyo3::IntoPyObject<'py>),+> $crate::IntoPyKeyed<'py> for ($($T,)+) {
+            fn into_py_keyed(self, py: pyo3::Python<'py>) -> pyo3::PyResult<pyo3::Bound<'py, pyo3::types::PyDict>> {
+                use pyo3::types::PyDictMethods;
+
+                let names = [$(core::any::type_name::<$T>()),+];
+                $crate::python::check_unique(&[$(core::any::TypeId::of::<$T>()),+], &names)?;
+
+                let dict = pyo3::types::PyDict::new(py);
+                $(dict.set_item(names[$idx], self.$idx)?;)+
+                Ok(dict)
+            }
+        }
+
+        impl<'py, $($T: 'static + pyo3::conversion::FromPyObjectOwned<'py>),+> $crate::FromPyKeyed<'py> for ($($T,)+) {
+            fn from_py_keyed(dict: &pyo3::Bound<'py, pyo3::types::PyDict>) -> pyo3::PyResult<Self> {
+                use pyo3::types::{PyAnyMethods, PyDictMethods};
+
+                let names = [$(core::any::type_name::<$T>()),+];
+                $crate::python::check_unique(&[$(core::any::TypeId::of::<$T>()),+], &names)?;
+                if dict.len() > names.len() {
+                    return Err($crate::python::unknown_key(dict, &names));
+                }
+
+                Ok(($(
+                    dict.get_item(names[$idx])?
+                        .ok_or_else(|| pyo3::exceptions::PyKeyError::new_err(names[$idx]))?
+                        .extract::<$T>()
+                        .map_err(Into::into)?,
+                )+))
+            }
+        }
+    };
+}
+
+pub(crate) use impl_tuple_pyo3;
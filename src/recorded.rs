@@ -0,0 +1,171 @@
+//! Tuple wrapper recording every mutation, so that it can be rolled back.
+
+use alloc::{boxed::Box, vec::Vec};
+use core::{any::Any, ops::Deref};
+
+use crate::{TupleSet, TupleTypes, types};
+
+/// Mutation recorded by a [`Recorded`] tuple.
+#[derive(Debug, Clone, Copy)]
+pub struct Revision<'a> {
+    /// Position of the mutated field in the tuple.
+    pub position: usize,
+    /// Name of the type of the mutated field.
+    pub type_name: &'static str,
+    /// Value of the field before the mutation.
+    pub old: &'a dyn Any,
+}
+
+/// Entry of the history of a [`Recorded`] tuple.
+struct Entry<T> {
+    position: usize,
+    type_name: &'static str,
+    old: Box<dyn Any>,
+    restore: fn(&mut T, Box<dyn Any>),
+}
+
+/// Tuple wrapper recording the previous value of every field mutated by
+/// `set` or `map`, so that mutations can be undone in reverse order.
+///
+/// This gives time-travel debugging to tuple-backed state machines: the
+/// history can be inspected through [`revisions`](Recorded::revisions), and
+/// rewound one step with [`undo`](Recorded::undo) or several with
+/// [`rollback_to`](Recorded::rollback_to). The wrapper dereferences to the
+/// tuple for read-only access, so mutations can only go through the recorded
+/// methods.
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::Recorded;
+///
+/// let mut machine = Recorded::new((0u32, "idle"));
+/// machine.set("running");
+/// machine.map(|ticks: &mut u32| *ticks += 5);
+/// machine.set("done");
+/// assert_eq!(*machine, (5, "done"));
+///
+/// assert!(machine.undo());
+/// assert_eq!(*machine, (5, "running"));
+///
+/// machine.rollback_to(0);
+/// assert_eq!(*machine, (0, "idle"));
+/// assert!(!machine.undo());
+/// ```
+pub struct Recorded<T> {
+    tuple: T,
+    history: Vec<Entry<T>>,
+}
+
+impl<T: TupleSet + TupleTypes> Recorded<T> {
+    /// Creates a new wrapper around `tuple`, with an empty history.
+    pub fn new(tuple: T) -> Self {
+        Self { tuple, history: Vec::new() }
+    }
+
+    /// Sets the value for type `U` if it appears exactly once in the tuple,
+    /// recording the previous value.
+    ///
+    /// Returns `None` on success, `Some(value)` if the type is not found or
+    /// appears multiple times.
+    pub fn set<U: 'static>(&mut self, value: U) -> Option<U> {
+        let Some(position) = types::unique_position::<T, U>() else {
+            return Some(value);
+        };
+        let old = match self.tuple.replace(value) {
+            Ok(old) => old,
+            Err(value) => return Some(value),
+        };
+
+        self.record::<U>(position, old);
+
+        None
+    }
+
+    /// Applies a mapping function to the value of type `U` if it appears
+    /// exactly once in the tuple, recording a clone of the previous value.
+    ///
+    /// Returns `Some(result)` with the function's return value on success,
+    /// or `None` if the type is not found or appears multiple times.
+    pub fn map<U: 'static + Clone, G, R>(&mut self, f: G) -> Option<R>
+    where
+        G: FnOnce(&mut U) -> R,
+    {
+        let position = types::unique_position::<T, U>()?;
+        let field = self.tuple.get_mut::<U>()?;
+
+        let old = field.clone();
+        let result = f(field);
+        self.record::<U>(position, old);
+
+        Some(result)
+    }
+
+    /// Restores the field changed by the latest recorded mutation to its
+    /// previous value, removing the mutation from the history.
+    ///
+    /// Returns `false` if the history is empty.
+    pub fn undo(&mut self) -> bool {
+        let Some(entry) = self.history.pop() else {
+            return false;
+        };
+
+        (entry.restore)(&mut self.tuple, entry.old);
+
+        true
+    }
+
+    /// Undoes the latest mutations until only the first `len` remain in the
+    /// history, returning the number of mutations undone.
+    pub fn rollback_to(&mut self, len: usize) -> usize {
+        let mut undone = 0;
+        while self.history.len() > len && self.undo() {
+            undone += 1;
+        }
+        undone
+    }
+
+    /// Returns the recorded mutations, from the oldest to the latest.
+    pub fn revisions(&self) -> impl ExactSizeIterator<Item = Revision<'_>> {
+        self.history.iter().map(|entry| Revision {
+            position: entry.position,
+            type_name: entry.type_name,
+            old: &*entry.old,
+        })
+    }
+
+    /// Clears the history, making the current values permanent.
+    pub fn clear_history(&mut self) {
+        self.history.clear();
+    }
+
+    /// Consumes the wrapper, returning the underlying tuple.
+    pub fn into_inner(self) -> T {
+        self.tuple
+    }
+
+    // Appends the previous value of the field of type `U` to the history
+    fn record<U: 'static>(&mut self, position: usize, old: U) {
+        self.history.push(Entry {
+            position,
+            type_name: core::any::type_name::<U>(),
+            old: Box::new(old),
+            restore: restore::<T, U>,
+        });
+    }
+}
+
+// Writes back a value recorded for the field of type `U`
+fn restore<T: TupleSet, U: 'static>(tuple: &mut T, old: Box<dyn Any>) {
+    if let Ok(old) = old.downcast::<U>() {
+        tuple.set(*old);
+    }
+}
+
+impl<T> Deref for Recorded<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.tuple
+    }
+}
@@ -0,0 +1,144 @@
+//! Registry of per-type constructors, building tuples without `Default`.
+
+use alloc::{boxed::Box, collections::BTreeMap};
+use core::{
+    any::{Any, TypeId},
+    fmt,
+};
+
+/// Error returned when constructing a tuple whose element type has no
+/// registered constructor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MissingConstructor {
+    type_name: &'static str,
+}
+
+impl MissingConstructor {
+    /// Creates the error for the type `T`.
+    #[must_use]
+    pub fn of<T>() -> Self {
+        Self { type_name: core::any::type_name::<T>() }
+    }
+
+    /// Returns the name of the type lacking a constructor.
+    #[must_use]
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+}
+
+impl fmt::Display for MissingConstructor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no constructor registered for type `{}`", self.type_name)
+    }
+}
+
+impl core::error::Error for MissingConstructor {}
+
+/// Registry of `fn() -> T` constructors, keyed by the type they construct.
+///
+/// [`construct`](Registry::construct) builds an entire tuple by looking up
+/// the constructor of each element type, so that tuples of types without a
+/// meaningful `Default` can still be created generically.
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::Registry;
+///
+/// let mut registry = Registry::new();
+/// registry.register(|| 8080u16);
+/// registry.register(|| "localhost");
+///
+/// let config: (&str, u16) = registry.construct().unwrap();
+/// assert_eq!(config, ("localhost", 8080));
+///
+/// let error = registry.construct::<(u16, bool)>().unwrap_err();
+/// assert_eq!(error.type_name(), "bool");
+/// ```
+#[derive(Debug, Default)]
+pub struct Registry {
+    constructors: BTreeMap<TypeId, Box<dyn Any>>,
+}
+
+impl Registry {
+    /// Creates an empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the constructor of the values of type `T`.
+    ///
+    /// Returns the previously registered constructor, if any.
+    pub fn register<T: 'static>(&mut self, constructor: fn() -> T) -> Option<fn() -> T> {
+        self.constructors
+            .insert(TypeId::of::<T>(), Box::new(constructor))
+            .and_then(|previous| previous.downcast_ref::<fn() -> T>().copied())
+    }
+
+    /// Registers `T::default` as the constructor of the values of type `T`.
+    ///
+    /// Returns the previously registered constructor, if any.
+    pub fn register_default<T: 'static + Default>(&mut self) -> Option<fn() -> T> {
+        self.register(T::default)
+    }
+
+    /// Removes the constructor of the values of type `T`, returning it.
+    pub fn unregister<T: 'static>(&mut self) -> Option<fn() -> T> {
+        self.constructors
+            .remove(&TypeId::of::<T>())
+            .and_then(|constructor| constructor.downcast_ref::<fn() -> T>().copied())
+    }
+
+    /// Returns true if a constructor is registered for the type `T`.
+    #[must_use]
+    pub fn contains<T: 'static>(&self) -> bool {
+        self.constructors.contains_key(&TypeId::of::<T>())
+    }
+
+    /// Constructs a value of type `T` through its registered constructor.
+    ///
+    /// Returns `None` if no constructor is registered for `T`.
+    #[must_use]
+    pub fn construct_one<T: 'static>(&self) -> Option<T> {
+        let constructor = self.constructors.get(&TypeId::of::<T>())?;
+        constructor.downcast_ref::<fn() -> T>().map(|constructor| constructor())
+    }
+
+    /// Constructs a tuple by calling the registered constructor of each of
+    /// its element types.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MissingConstructor`] for the first element type without a
+    /// registered constructor.
+    pub fn construct<T: Constructible>(&self) -> Result<T, MissingConstructor> {
+        T::construct_from(self)
+    }
+}
+
+/// Trait for tuples constructible element by element from a [`Registry`].
+pub trait Constructible: Sized {
+    /// Constructs the tuple from the constructors registered in `registry`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MissingConstructor`] for the first element type without a
+    /// registered constructor.
+    fn construct_from(registry: &Registry) -> Result<Self, MissingConstructor>;
+}
+
+macro_rules! impl_tuple_constructible {
+    ($($idx:tt: $T:ident),+) => {
+        impl<$($T: 'static),+> $crate::Constructible for ($($T,)+) {
+            fn construct_from(registry: &$crate::Registry) -> Result<Self, $crate::MissingConstructor> {
+                Ok(($(
+                    registry.construct_one::<$T>().ok_or_else($crate::MissingConstructor::of::<$T>)?,
+                )+))
+            }
+        }
+    };
+}
+
+pub(crate) use impl_tuple_constructible;
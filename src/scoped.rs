@@ -0,0 +1,53 @@
+//! Temporary overrides of tuple fields.
+
+use core::ops::{Deref, DerefMut};
+
+use crate::TupleSet;
+
+/// Guard returned by [`TupleSet::scoped_set`], restoring the previous value of
+/// the overridden field when dropped.
+///
+/// The guard dereferences to the tuple, which remains accessible while the
+/// override is active.
+pub struct ScopedSet<'a, S: TupleSet + ?Sized, T: 'static> {
+    tuple: &'a mut S,
+    previous: Option<T>,
+}
+
+impl<'a, S: TupleSet + ?Sized, T: 'static> ScopedSet<'a, S, T> {
+    /// Creates a guard over a tuple whose field of type `T` was replaced, and
+    /// which will be reset to `previous` when the guard is dropped.
+    pub(crate) fn new(tuple: &'a mut S, previous: T) -> Self {
+        Self { tuple, previous: Some(previous) }
+    }
+
+    /// Returns the value that will be restored when the guard is dropped.
+    #[must_use]
+    pub fn previous(&self) -> &T {
+        self.previous.as_ref().expect("The previous value is only taken on drop")
+    }
+}
+
+impl<S: TupleSet + ?Sized, T: 'static> Deref for ScopedSet<'_, S, T> {
+    type Target = S;
+
+    fn deref(&self) -> &S {
+        self.tuple
+    }
+}
+
+impl<S: TupleSet + ?Sized, T: 'static> DerefMut for ScopedSet<'_, S, T> {
+    fn deref_mut(&mut self) -> &mut S {
+        self.tuple
+    }
+}
+
+impl<S: TupleSet + ?Sized, T: 'static> Drop for ScopedSet<'_, S, T> {
+    fn drop(&mut self) {
+        if let Some(previous) = self.previous.take() {
+            // SAFETY: the guard is only created once `T` was verified to
+            // appear exactly once, and the tuple type cannot change since.
+            unsafe { self.tuple.set_unchecked(previous) };
+        }
+    }
+}
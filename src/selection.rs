@@ -0,0 +1,46 @@
+//! Typed subsets of tuples, selected by the types of their elements.
+
+use crate::TupleSet;
+
+/// Trait for tuples of types selecting a subset of the fields of another
+/// tuple.
+pub trait Selection: Sized {
+    /// Returns clones of the selected fields of `tuple`, if every selected
+    /// type appears exactly once in it.
+    fn select_from<S: TupleSet + ?Sized>(tuple: &S) -> Option<Self>;
+
+    /// Writes the selected values into `tuple`, if every selected type appears
+    /// exactly once in it.
+    ///
+    /// Returns `None` on success, `Some(self)` otherwise, in which case
+    /// `tuple` is left untouched.
+    fn write_into<S: TupleSet + ?Sized>(self, tuple: &mut S) -> Option<Self>;
+}
+
+macro_rules! impl_tuple_selection {
+    ($($idx:tt: $T:ident),+) => {
+        impl<$($T: 'static + Clone),+> $crate::Selection for ($($T,)+) {
+            #[inline]
+            fn select_from<S: $crate::TupleSet + ?Sized>(tuple: &S) -> Option<Self> {
+                Some(($(tuple.get::<$T>()?.clone(),)+))
+            }
+
+            #[inline]
+            fn write_into<S: $crate::TupleSet + ?Sized>(self, tuple: &mut S) -> Option<Self> {
+                if !(true $(&& tuple.get_mut::<$T>().is_some())+) {
+                    return Some(self);
+                }
+
+                $(
+                    if let Some(field) = tuple.get_mut::<$T>() {
+                        *field = self.$idx;
+                    }
+                )+
+
+                None
+            }
+        }
+    };
+}
+
+pub(crate) use impl_tuple_selection;
@@ -0,0 +1,352 @@
+//! Serde support with a selectable representation of the tuple.
+
+use core::{fmt, marker::PhantomData};
+
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{self, DeserializeSeed, Visitor},
+};
+
+/// Representation serializing the tuple as a plain sequence of its fields,
+/// suited to compact binary formats.
+///
+/// Unlike the tuple implementations of serde, which stop at 16 elements, it
+/// is available for every arity supported by this crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Positional;
+
+/// Representation serializing the tuple as a map from the type name of each
+/// field to its value, suited to human-readable formats.
+///
+/// Every type must appear exactly once in the tuple for it to be usable as a
+/// key.
+///
+/// The keys are rendered by [`core::any::type_name`], whose output is not
+/// stable across compiler versions and spells paths in full, e.g.
+/// `alloc::string::String`. Data persisted with this representation may
+/// therefore fail to deserialize after a toolchain upgrade: prefer
+/// [`Positional`] for long-lived data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Keyed;
+
+/// Representation picking [`Keyed`] for human-readable formats and
+/// [`Positional`] otherwise, as reported by the format's
+/// `is_human_readable`.
+///
+/// Human-readable output inherits the unstable keys of [`Keyed`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Auto;
+
+/// Wrapper serializing a tuple with the representation `R`, one of
+/// [`Positional`], [`Keyed`] or [`Auto`].
+///
+/// The keyed output of [`Keyed`], and of [`Auto`] with human-readable
+/// formats, depends on the compiler version, as its keys are type names.
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::{Keyed, Positional, SerdeTuple};
+///
+/// let tuple = (42u32, true);
+///
+/// let keyed = serde_json::to_string(&SerdeTuple::<_, Keyed>::new(tuple)).unwrap();
+/// assert_eq!(keyed, r#"{"u32":42,"bool":true}"#);
+///
+/// let positional = serde_json::to_string(&SerdeTuple::<_, Positional>::new(tuple)).unwrap();
+/// assert_eq!(positional, "[42,true]");
+///
+/// let decoded: SerdeTuple<(u32, bool), Keyed> = serde_json::from_str(&keyed).unwrap();
+/// assert_eq!(decoded.into_inner(), tuple);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SerdeTuple<T, R = Auto> {
+    tuple: T,
+    representation: PhantomData<R>,
+}
+
+impl<T, R> SerdeTuple<T, R> {
+    /// Wraps `tuple` to be serialized with the representation `R`.
+    pub fn new(tuple: T) -> Self {
+        Self { tuple, representation: PhantomData }
+    }
+
+    /// Returns the wrapped tuple.
+    pub fn into_inner(self) -> T {
+        self.tuple
+    }
+}
+
+impl<T, R> core::ops::Deref for SerdeTuple<T, R> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.tuple
+    }
+}
+
+impl<T, R> core::ops::DerefMut for SerdeTuple<T, R> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.tuple
+    }
+}
+
+impl<T, R> From<T> for SerdeTuple<T, R> {
+    fn from(tuple: T) -> Self {
+        Self::new(tuple)
+    }
+}
+
+/// Trait for tuples serializable as a sequence of their fields, for every
+/// arity supported by this crate.
+///
+/// The encoding matches the one of the serde tuple implementations, so both
+/// are interchangeable for tuples of up to 16 elements.
+pub trait SerializePositional {
+    /// Serializes the tuple as a sequence of its fields.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serializing a field fails.
+    fn serialize_positional<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+}
+
+/// Trait for tuples deserializable from a sequence of their fields, for every
+/// arity supported by this crate.
+pub trait DeserializePositional<'de>: Sized {
+    /// Deserializes the tuple from a sequence of its fields.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sequence is too short, or if deserializing a
+    /// field fails.
+    fn deserialize_positional<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>;
+}
+
+/// Trait for tuples serializable as a map keyed by the type name of each
+/// field.
+pub trait SerializeKeyed {
+    /// Serializes the tuple as a map from type names to field values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a type appears multiple times in the tuple, or if
+    /// serializing a field fails.
+    fn serialize_keyed<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+}
+
+/// Trait for tuples deserializable from a map keyed by the type name of each
+/// field.
+pub trait DeserializeKeyed<'de>: Sized {
+    /// Deserializes the tuple from a map from type names to field values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a type appears multiple times in the tuple, if a
+    /// key is unknown, missing or repeated, or if deserializing a field fails.
+    fn deserialize_keyed<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>;
+}
+
+impl<T: SerializePositional> Serialize for SerdeTuple<T, Positional> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.tuple.serialize_positional(serializer)
+    }
+}
+
+impl<T: SerializeKeyed> Serialize for SerdeTuple<T, Keyed> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.tuple.serialize_keyed(serializer)
+    }
+}
+
+impl<T: SerializePositional + SerializeKeyed> Serialize for SerdeTuple<T, Auto> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            self.tuple.serialize_keyed(serializer)
+        } else {
+            self.tuple.serialize_positional(serializer)
+        }
+    }
+}
+
+impl<'de, T: DeserializePositional<'de>> Deserialize<'de> for SerdeTuple<T, Positional> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize_positional(deserializer).map(Self::new)
+    }
+}
+
+impl<'de, T: DeserializeKeyed<'de>> Deserialize<'de> for SerdeTuple<T, Keyed> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize_keyed(deserializer).map(Self::new)
+    }
+}
+
+impl<'de, T: DeserializePositional<'de> + DeserializeKeyed<'de>> Deserialize<'de>
+    for SerdeTuple<T, Auto>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            T::deserialize_keyed(deserializer).map(Self::new)
+        } else {
+            T::deserialize_positional(deserializer).map(Self::new)
+        }
+    }
+}
+
+impl SerializePositional for () {
+    fn serialize_positional<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_unit()
+    }
+}
+
+impl<'de> DeserializePositional<'de> for () {
+    fn deserialize_positional<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <()>::deserialize(deserializer)
+    }
+}
+
+/// Visitor deserializing a tuple from a sequence of its fields.
+pub(crate) struct PositionalVisitor<T>(pub(crate) PhantomData<T>);
+
+/// Seed deserializing a map key into the position of the matching type name.
+pub(crate) struct KeyPosition<'a>(pub(crate) &'a [&'static str]);
+
+impl<'de> DeserializeSeed<'de> for KeyPosition<'_> {
+    type Value = usize;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<usize, D::Error> {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl Visitor<'_> for KeyPosition<'_> {
+    type Value = usize;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("the type name of a tuple field")
+    }
+
+    fn visit_str<E: de::Error>(self, key: &str) -> Result<usize, E> {
+        self.0
+            .iter()
+            .position(|name| *name == key)
+            .ok_or_else(|| E::custom(format_args!("unknown type name `{key}`")))
+    }
+}
+
+/// Visitor deserializing a tuple from a map keyed by type names.
+pub(crate) struct KeyedVisitor<T>(pub(crate) PhantomData<T>);
+
+macro_rules! impl_tuple_serde {
+    ($($idx:tt: $T:ident),+) => {
+        impl<$($T: serde::Serialize),+> $crate::SerializePositional for ($($T,)+) {
+            fn serialize_positional<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                use serde::ser::SerializeTuple;
+
+                let mut sequence = serializer.serialize_tuple(0 $(+ $crate::one!($T))+)?;
+                $(sequence.serialize_element(&self.$idx)?;)+
+                sequence.end()
+            }
+        }
+
+        impl<'de, $($T: serde::Deserialize<'de>),+> serde::de::Visitor<'de>
+            for $crate::serialization::PositionalVisitor<($($T,)+)>
+        {
+            type Value = ($($T,)+);
+
+            fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(formatter, "a tuple of size {}", 0 $(+ $crate::one!($T))+)
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut sequence: A) -> Result<Self::Value, A::Error> {
+                use serde::de::Error;
+
+                Ok(($(
+                    sequence
+                        .next_element::<$T>()?
+                        .ok_or_else(|| A::Error::invalid_length($idx, &self))?,
+                )+))
+            }
+        }
+
+        impl<'de, $($T: serde::Deserialize<'de>),+> $crate::DeserializePositional<'de> for ($($T,)+) {
+            fn deserialize_positional<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserializer.deserialize_tuple(
+                    0 $(+ $crate::one!($T))+,
+                    $crate::serialization::PositionalVisitor::<Self>(core::marker::PhantomData),
+                )
+            }
+        }
+
+        impl<$($T: 'static + serde::Serialize),+> $crate::SerializeKeyed for ($($T,)+) {
+            fn serialize_keyed<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                use serde::ser::{Error, SerializeMap};
+
+                let type_ids = [$(core::any::TypeId::of::<$T>()),+];
+                let names = [$(core::any::type_name::<$T>()),+];
+                if let Some(position) = $crate::types::first_duplicate(&type_ids) {
+                    return Err(S::Error::custom(format_args!(
+                        "type `{}` appears multiple times and cannot be used as a key",
+                        names[position]
+                    )));
+                }
+
+                let mut map = serializer.serialize_map(Some(names.len()))?;
+                $(map.serialize_entry(names[$idx], &self.$idx)?;)+
+                map.end()
+            }
+        }
+
+        impl<'de, $($T: 'static + serde::Deserialize<'de>),+> serde::de::Visitor<'de>
+            for $crate::serialization::KeyedVisitor<($($T,)+)>
+        {
+            type Value = ($($T,)+);
+
+            fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                formatter.write_str("a map from type names to tuple fields")
+            }
+
+            fn visit_map<M: serde::de::MapAccess<'de>>(self, mut map: M) -> Result<Self::Value, M::Error> {
+                use serde::de::Error;
+
+                let names = [$(core::any::type_name::<$T>()),+];
+                let mut fields = ($(None::<$T>,)+);
+                while let Some(position) = map.next_key_seed($crate::serialization::KeyPosition(&names))? {
+                    match position {
+                        $(
+                            $idx => {
+                                if fields.$idx.is_some() {
+                                    return Err(M::Error::duplicate_field(names[$idx]));
+                                }
+                                fields.$idx = Some(map.next_value()?);
+                            }
+                        )+
+                        _ => unreachable!(),
+                    }
+                }
+
+                Ok(($(fields.$idx.ok_or_else(|| M::Error::missing_field(names[$idx]))?,)+))
+            }
+        }
+
+        impl<'de, $($T: 'static + serde::Deserialize<'de>),+> $crate::DeserializeKeyed<'de> for ($($T,)+) {
+            fn deserialize_keyed<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                use serde::de::Error;
+
+                let type_ids = [$(core::any::TypeId::of::<$T>()),+];
+                if let Some(position) = $crate::types::first_duplicate(&type_ids) {
+                    return Err(D::Error::custom(format_args!(
+                        "type `{}` appears multiple times and cannot be used as a key",
+                        [$(core::any::type_name::<$T>()),+][position]
+                    )));
+                }
+
+                deserializer.deserialize_map($crate::serialization::KeyedVisitor::<Self>(core::marker::PhantomData))
+            }
+        }
+    };
+}
+
+pub(crate) use impl_tuple_serde;
@@ -0,0 +1,106 @@
+//! Accessors whose lookups are checked at compile time.
+//!
+//! A Cargo feature cannot change the signatures of the accessors of
+//! [`TupleSet`](crate::TupleSet), as features must stay additive across the
+//! dependency graph. The `strict` feature instead provides the
+//! [`StrictTupleSet`] accessors, which cannot fail at runtime: a type missing
+//! from the tuple, or appearing in it multiple times, is rejected by the
+//! compiler.
+
+use crate::Field;
+
+/// Marker trait for tuples containing the type `T` exactly once, at the
+/// inferred position `I`.
+///
+/// The bound holds for exactly one `I` when `T` appears once in `Self`. When
+/// `T` appears multiple times the position is ambiguous, and when it does
+/// not appear no position satisfies the bound: both fail to compile wherever
+/// the tuple type is known.
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::{ContainsUnique, StrictTupleSet};
+///
+/// fn retries<C: ContainsUnique<u32, I>, I>(context: &C) -> u32 {
+///     *context.get_strict()
+/// }
+///
+/// assert_eq!(retries(&("localhost", 3u32)), 3);
+/// ```
+///
+/// Duplicated types are rejected at build time:
+///
+/// ```compile_fail
+/// use tuple_set::StrictTupleSet;
+///
+/// let tuple = (1u32, 2u32);
+/// let _: &u32 = tuple.get_strict();
+/// ```
+pub trait ContainsUnique<T, I>: Field<T, I> {}
+
+impl<S, T, I> ContainsUnique<T, I> for S where S: Field<T, I> {}
+
+/// Infallible counterparts of the checked accessors of
+/// [`TupleSet`](crate::TupleSet), whose lookups are resolved at compile time
+/// through [`ContainsUnique`].
+///
+/// Like [`Field`], these accessors also work with non-`'static` tuples.
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::StrictTupleSet;
+///
+/// let mut config = (8080u16, "localhost", false);
+///
+/// assert_eq!(*config.get_strict::<u16, _>(), 8080);
+/// assert_eq!(config.set_strict(true), false);
+/// config.map_strict(|port: &mut u16| *port += 1);
+/// assert_eq!(config, (8081, "localhost", true));
+/// ```
+///
+/// Missing types are rejected at build time:
+///
+/// ```compile_fail
+/// use tuple_set::StrictTupleSet;
+///
+/// let tuple = (1u32, "hello");
+/// let _: &bool = tuple.get_strict();
+/// ```
+pub trait StrictTupleSet {
+    /// Returns a reference to the value of type `T`.
+    fn get_strict<T, I>(&self) -> &T
+    where
+        Self: ContainsUnique<T, I>,
+    {
+        self.field()
+    }
+
+    /// Returns a mutable reference to the value of type `T`.
+    fn get_strict_mut<T, I>(&mut self) -> &mut T
+    where
+        Self: ContainsUnique<T, I>,
+    {
+        self.field_mut()
+    }
+
+    /// Replaces the value of type `T`, returning the previous one.
+    fn set_strict<T, I>(&mut self, value: T) -> T
+    where
+        Self: ContainsUnique<T, I>,
+    {
+        self.replace_field(value)
+    }
+
+    /// Applies `f` to the value of type `T`, returning its result.
+    fn map_strict<T, I, F, R>(&mut self, f: F) -> R
+    where
+        Self: ContainsUnique<T, I>,
+        F: FnOnce(&mut T) -> R,
+    {
+        f(self.field_mut())
+    }
+}
+
+impl<S> StrictTupleSet for S {}
@@ -0,0 +1,146 @@
+//! Tuple wrapper with per-field locking by type.
+
+use std::sync::{PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use crate::TupleSet;
+
+/// Trait for tuples whose fields can each be placed behind their own
+/// `RwLock`.
+pub trait Lockable: Sized {
+    /// The tuple holding each field behind an `RwLock`.
+    type Locks: TupleSet;
+
+    /// Places each field behind its own `RwLock`.
+    fn into_locks(self) -> Self::Locks;
+
+    /// Consumes the locks, returning the fields.
+    fn from_locks(locks: Self::Locks) -> Self;
+}
+
+/// Tuple wrapper storing each field behind its own `RwLock`, with readers and
+/// writers keyed by type.
+///
+/// Fields are locked independently, so threads accessing different types
+/// never contend. As with [`TupleSet`], a type must appear exactly once in the
+/// tuple to be accessible.
+///
+/// Lock poisoning is ignored by the accessors, as each field is expected to
+/// be left in a valid state by any panicking writer: a poisoned field can
+/// still be read and written. Callers relying on poisoning can check it with
+/// [`is_poisoned`](SyncTuple::is_poisoned) and reset it with
+/// [`clear_poison`](SyncTuple::clear_poison).
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::SyncTuple;
+///
+/// let registry = SyncTuple::new((0u64, "idle"));
+/// std::thread::scope(|scope| {
+///     scope.spawn(|| *registry.write::<u64>().unwrap() += 1);
+///     scope.spawn(|| *registry.write::<&str>().unwrap() = "busy");
+/// });
+/// assert_eq!(registry.into_inner(), (1, "busy"));
+/// ```
+pub struct SyncTuple<T: Lockable> {
+    locks: T::Locks,
+}
+
+impl<T: Lockable> SyncTuple<T> {
+    /// Creates a new wrapper placing each field of `tuple` behind its own
+    /// lock.
+    pub fn new(tuple: T) -> Self {
+        Self { locks: tuple.into_locks() }
+    }
+
+    /// Acquires shared read access to the field of type `F`, even if its
+    /// lock is poisoned.
+    ///
+    /// Returns `None` if the type is not found or appears multiple times.
+    pub fn read<F: 'static>(&self) -> Option<RwLockReadGuard<'_, F>> {
+        self.locks
+            .get::<RwLock<F>>()
+            .map(|lock| lock.read().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    /// Acquires exclusive write access to the field of type `F`, even if its
+    /// lock is poisoned.
+    ///
+    /// Returns `None` if the type is not found or appears multiple times.
+    pub fn write<F: 'static>(&self) -> Option<RwLockWriteGuard<'_, F>> {
+        self.locks
+            .get::<RwLock<F>>()
+            .map(|lock| lock.write().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    /// Returns whether the lock of the field of type `F` is poisoned, i.e.
+    /// whether a writer panicked while holding it.
+    ///
+    /// Returns `None` if the type is not found or appears multiple times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::panic::{AssertUnwindSafe, catch_unwind};
+    ///
+    /// use tuple_set::SyncTuple;
+    ///
+    /// let registry = SyncTuple::new((0u64, "idle"));
+    /// let _ = catch_unwind(AssertUnwindSafe(|| {
+    ///     let _guard = registry.write::<u64>();
+    ///     panic!("writer failed");
+    /// }));
+    /// assert_eq!(registry.is_poisoned::<u64>(), Some(true));
+    /// assert_eq!(registry.is_poisoned::<&str>(), Some(false));
+    /// ```
+    #[must_use]
+    pub fn is_poisoned<F: 'static>(&self) -> Option<bool> {
+        self.locks.get::<RwLock<F>>().map(RwLock::is_poisoned)
+    }
+
+    /// Clears the poisoned state of the lock of the field of type `F`.
+    ///
+    /// Returns `None` if the type is not found or appears multiple times.
+    pub fn clear_poison<F: 'static>(&self) -> Option<()> {
+        self.locks.get::<RwLock<F>>().map(RwLock::clear_poison)
+    }
+
+    /// Consumes the wrapper, returning the underlying tuple.
+    pub fn into_inner(self) -> T {
+        T::from_locks(self.locks)
+    }
+}
+
+impl<T: Lockable + Default> Default for SyncTuple<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: Lockable> From<T> for SyncTuple<T> {
+    fn from(tuple: T) -> Self {
+        Self::new(tuple)
+    }
+}
+
+macro_rules! impl_tuple_lockable {
+    ($($idx:tt: $T:ident),+) => {
+        impl<$($T: 'static),+> $crate::Lockable for ($($T,)+) {
+            type Locks = ($(std::sync::RwLock<$T>,)+);
+
+            #[inline]
+            fn into_locks(self) -> Self::Locks {
+                ($(std::sync::RwLock::new(self.$idx),)+)
+            }
+
+            #[inline]
+            fn from_locks(locks: Self::Locks) -> Self {
+                ($(
+                    locks.$idx.into_inner().unwrap_or_else(std::sync::PoisonError::into_inner),
+                )+)
+            }
+        }
+    };
+}
+
+pub(crate) use impl_tuple_lockable;
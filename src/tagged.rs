@@ -0,0 +1,106 @@
+//! Marker-tagged wrapper distinguishing fields sharing the same type.
+
+use core::{
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+
+/// Zero-cost wrapper associating a value with the marker type `Tag`.
+///
+/// Since `Tagged<Primary, u32>` and `Tagged<Secondary, u32>` are distinct
+/// types, two fields of the same inner type become individually addressable
+/// by a [`TupleSet`](crate::TupleSet), e.g. through
+/// [`get_tagged`](crate::TupleSet::get_tagged). The wrapper dereferences to
+/// the inner value.
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::{Tagged, TupleSet};
+///
+/// struct Primary;
+/// struct Secondary;
+///
+/// let mut ports = (Tagged::<Primary, u16>::new(80), Tagged::<Secondary, u16>::new(8080));
+/// assert_eq!(ports.get_tagged::<Primary, u16>(), Some(&80));
+///
+/// ports.set_tagged::<Secondary, u16>(8443);
+/// assert_eq!(*ports.1, 8443);
+/// ```
+#[repr(transparent)]
+pub struct Tagged<Tag, T> {
+    value: T,
+    tag: PhantomData<fn() -> Tag>,
+}
+
+impl<Tag, T> Tagged<Tag, T> {
+    /// Wraps `value` with the marker `Tag`.
+    #[must_use]
+    pub const fn new(value: T) -> Self {
+        Self { value, tag: PhantomData }
+    }
+
+    /// Consumes the wrapper, returning the inner value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<Tag, T> Deref for Tagged<Tag, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<Tag, T> DerefMut for Tagged<Tag, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<Tag, T> From<T> for Tagged<Tag, T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+// The trait impls below are written by hand so that they do not require the
+// marker type to implement them as well.
+
+impl<Tag, T: fmt::Debug> fmt::Debug for Tagged<Tag, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Tagged").field(&self.value).finish()
+    }
+}
+
+impl<Tag, T: Clone> Clone for Tagged<Tag, T> {
+    fn clone(&self) -> Self {
+        Self::new(self.value.clone())
+    }
+}
+
+impl<Tag, T: Copy> Copy for Tagged<Tag, T> {}
+
+impl<Tag, T: PartialEq> PartialEq for Tagged<Tag, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<Tag, T: Eq> Eq for Tagged<Tag, T> {}
+
+impl<Tag, T: Hash> Hash for Tagged<Tag, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+impl<Tag, T: Default> Default for Tagged<Tag, T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
@@ -0,0 +1,119 @@
+//! Field access dispatched through compile-time type equality.
+//!
+//! The accessors of [`TupleSet`](crate::TupleSet) compare `TypeId`s, which
+//! restricts them to `'static` element types and relies on the optimizer to
+//! remove the comparisons. The [`Field`] trait instead selects the field at
+//! compile time, through an inferred [`Position`](crate::Position) as in the
+//! type-set algebra, so that it works with borrowed element types and
+//! compiles down to a plain field access even without optimizations.
+
+/// Trait proving at compile time that `Self` and `T` are the same type.
+///
+/// Its only implementation is the reflexive one, so a bound `A: TypeEq<T>`
+/// holds exactly when `A` is `T`, and lets generic code convert between the
+/// two without any runtime check.
+pub trait TypeEq<T> {
+    /// Converts the value into `T`.
+    fn cast(self) -> T;
+
+    /// Converts a reference to the value into a reference to `T`.
+    fn cast_ref(&self) -> &T;
+
+    /// Converts a mutable reference to the value into one to `T`.
+    fn cast_mut(&mut self) -> &mut T;
+}
+
+impl<T> TypeEq<T> for T {
+    #[inline(always)]
+    fn cast(self) -> T {
+        self
+    }
+
+    #[inline(always)]
+    fn cast_ref(&self) -> &T {
+        self
+    }
+
+    #[inline(always)]
+    fn cast_mut(&mut self) -> &mut T {
+        self
+    }
+}
+
+/// Trait for tuples holding a field of type `T` at the inferred position
+/// `I`.
+///
+/// Unlike [`TupleSet`](crate::TupleSet), neither the tuple nor `T` needs to
+/// be `'static`. A type missing from the tuple fails to compile, as does a
+/// type appearing multiple times, whose position is ambiguous.
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::Field;
+///
+/// let name = String::from("alice");
+/// let mut user = (name.as_str(), 30u8, &name);
+///
+/// let age: &u8 = user.field();
+/// assert_eq!(*age, 30);
+/// *Field::<u8, _>::field_mut(&mut user) += 1;
+/// assert_eq!(user.replace_field("bob"), "alice");
+/// assert_eq!(user, ("bob", 31, &name));
+/// ```
+pub trait Field<T, I> {
+    /// Returns a reference to the field of type `T`.
+    fn field(&self) -> &T;
+
+    /// Returns a mutable reference to the field of type `T`.
+    fn field_mut(&mut self) -> &mut T;
+
+    /// Consumes the tuple, returning its field of type `T`.
+    fn into_field(self) -> T;
+
+    /// Replaces the field of type `T` with `value`, returning the previous
+    /// value.
+    #[inline]
+    fn replace_field(&mut self, value: T) -> T {
+        core::mem::replace(self.field_mut(), value)
+    }
+}
+
+// Generates one `Field` impl per position, walking the tuple while keeping
+// track of the elements before and after the current one
+macro_rules! impl_field {
+    (@ [$($before:ident),*] $idx:tt: $T:ident $(, $after_idx:tt: $after:ident)*) => {
+        impl<$($before,)* $T, $($after,)* Target> $crate::Field<Target, $crate::Position<$idx>>
+            for ($($before,)* $T, $($after,)*)
+        where
+            $T: $crate::TypeEq<Target>,
+        {
+            #[inline(always)]
+            fn field(&self) -> &Target {
+                $crate::TypeEq::cast_ref(&self.$idx)
+            }
+
+            #[inline(always)]
+            fn field_mut(&mut self) -> &mut Target {
+                $crate::TypeEq::cast_mut(&mut self.$idx)
+            }
+
+            #[inline(always)]
+            fn into_field(self) -> Target {
+                $crate::TypeEq::cast(self.$idx)
+            }
+        }
+
+        $crate::type_eq::impl_field!(@ [$($before,)* $T] $($after_idx: $after),*);
+    };
+    (@ [$($before:ident),*]) => {};
+}
+
+macro_rules! impl_tuple_type_eq {
+    ($($idx:tt: $T:ident),+) => {
+        $crate::type_eq::impl_field!(@ [] $($idx: $T),+);
+    };
+}
+
+pub(crate) use impl_field;
+pub(crate) use impl_tuple_type_eq;
@@ -0,0 +1,98 @@
+//! Map-like façade over tuples, for code written against type maps.
+
+use core::any::TypeId;
+
+use crate::{DynFields, TupleSet, TupleTypes};
+
+/// Read-only view over a tuple exposing the API of a map keyed by type, such
+/// as the one of `anymap`-style containers, on top of its static storage.
+///
+/// Each distinct type of the tuple is one entry of the map: a type appearing
+/// multiple times counts once in [`len`](TypeMapView::len) and
+/// [`keys`](TypeMapView::keys), while [`values_of`](TypeMapView::values_of)
+/// yields every one of its fields.
+///
+/// # Examples
+///
+/// ```
+/// use core::any::TypeId;
+///
+/// use tuple_set::TypeMapView;
+///
+/// let resources = (42u32, "config", 7u32);
+/// let map = TypeMapView::new(&resources);
+///
+/// assert_eq!(map.len(), 2);
+/// assert!(map.keys().eq([TypeId::of::<u32>(), TypeId::of::<&str>()]));
+/// assert!(map.contains_key::<&str>());
+/// assert_eq!(map.get::<&str>(), Some(&"config"));
+/// assert_eq!(map.get::<u32>(), None);
+/// assert!(map.values_of::<u32>().eq(&[42, 7]));
+/// ```
+pub struct TypeMapView<'a, S: ?Sized> {
+    tuple: &'a S,
+}
+
+impl<S: ?Sized> Clone for TypeMapView<'_, S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S: ?Sized> Copy for TypeMapView<'_, S> {}
+
+impl<'a, S: TupleSet + TupleTypes + DynFields> TypeMapView<'a, S> {
+    /// Creates a view over `tuple`.
+    #[must_use]
+    pub fn new(tuple: &'a S) -> Self {
+        Self { tuple }
+    }
+
+    /// Returns the underlying tuple.
+    #[must_use]
+    pub fn into_inner(self) -> &'a S {
+        self.tuple
+    }
+
+    /// Returns an iterator over the `TypeId` of each distinct type of the
+    /// tuple, in the order of their first occurrence.
+    pub fn keys(&self) -> impl Iterator<Item = TypeId> + use<S> {
+        let type_ids = S::type_ids();
+        (0..type_ids.as_ref().len()).filter_map(move |position| {
+            let type_ids = type_ids.as_ref();
+            let type_id = type_ids[position];
+            (!type_ids[..position].contains(&type_id)).then_some(type_id)
+        })
+    }
+
+    /// Returns the number of distinct types of the tuple.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.keys().count()
+    }
+
+    /// Returns whether the view has no keys, consistently with
+    /// [`len`](Self::len).
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns whether the tuple has at least one field of type `T`.
+    #[must_use]
+    pub fn contains_key<T: 'static>(&self) -> bool {
+        self.tuple.contains::<T>()
+    }
+
+    /// Returns a reference to the field of type `T`, if it appears exactly
+    /// once in the tuple.
+    #[must_use]
+    pub fn get<T: 'static>(&self) -> Option<&'a T> {
+        self.tuple.get::<T>()
+    }
+
+    /// Returns an iterator over the fields of type `T`, in positional order.
+    pub fn values_of<T: 'static>(&self) -> impl Iterator<Item = &'a T> + use<'a, S, T> {
+        self.tuple.dyn_fields().filter_map(|(_, value)| value.downcast_ref::<T>())
+    }
+}
@@ -0,0 +1,261 @@
+//! Type introspection of tuple elements.
+
+use core::{any::TypeId, fmt};
+
+/// Trait exposing the `TypeId` of each tuple element.
+pub trait TupleTypes {
+    /// Array type with one `TypeId` entry per tuple element.
+    type TypeIds: AsRef<[TypeId]> + Copy + fmt::Debug + Eq;
+
+    /// Returns the `TypeId` of each tuple element, in positional order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::any::TypeId;
+    ///
+    /// use tuple_set::TupleTypes;
+    ///
+    /// assert_eq!(<(u8, bool)>::type_ids(), [TypeId::of::<u8>(), TypeId::of::<bool>()]);
+    /// ```
+    fn type_ids() -> Self::TypeIds;
+
+    /// Array type with one type name entry per tuple element.
+    type TypeNames: AsRef<[&'static str]> + Copy + fmt::Debug + Eq;
+
+    /// Returns the name of the type of each tuple element, in positional
+    /// order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleTypes;
+    ///
+    /// assert_eq!(<(u8, bool)>::type_names(), ["u8", "bool"]);
+    /// ```
+    fn type_names() -> Self::TypeNames;
+
+    /// Writes the signature of the tuple type, such as `(i32, &str, f64)`,
+    /// into `writer` without allocating.
+    ///
+    /// Element types are rendered as by [`core::any::type_name`], and
+    /// single-element tuples keep their trailing comma.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleTypes;
+    ///
+    /// let mut signature = String::new();
+    /// <(i32, &str, f64)>::write_signature(&mut signature).unwrap();
+    /// assert_eq!(signature, "(i32, &str, f64)");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing into `writer` fails.
+    fn write_signature<W: fmt::Write + ?Sized>(writer: &mut W) -> fmt::Result {
+        write_signature(writer, Self::type_names().as_ref())
+    }
+
+    /// Returns true if no element type of `Self` appears in `Other`, so that
+    /// concatenating both tuple types keeps every type unique.
+    ///
+    /// Stable Rust cannot express type inequality, so disjointness is checked
+    /// at runtime, through `TypeId` comparisons that are constant-folded in
+    /// optimized builds. On nightly, the `unstable-const-type-id` feature
+    /// provides `DisjointFrom`, checking it at compile time instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuple_set::TupleTypes;
+    ///
+    /// assert!(<(u8, bool)>::is_disjoint_from::<(i32, char)>());
+    /// assert!(!<(u8, bool)>::is_disjoint_from::<(char, u8)>());
+    /// ```
+    #[must_use]
+    fn is_disjoint_from<Other: TupleTypes>() -> bool {
+        let other = Other::type_ids();
+        Self::type_ids().as_ref().iter().all(|id| !other.as_ref().contains(id))
+    }
+}
+
+/// Writes the signature of a tuple type whose elements have the given type
+/// names, keeping the trailing comma of single-element tuples.
+pub(crate) fn write_signature<W: fmt::Write + ?Sized>(
+    writer: &mut W,
+    names: &[&str],
+) -> fmt::Result {
+    writer.write_char('(')?;
+    for (position, name) in names.iter().enumerate() {
+        if position > 0 {
+            writer.write_str(", ")?;
+        }
+        writer.write_str(name)?;
+    }
+    if names.len() == 1 {
+        writer.write_char(',')?;
+    }
+    writer.write_char(')')
+}
+
+/// Returns the position of `U` in the tuple type `S` if it appears exactly
+/// once, in a single pass bailing out on a second match.
+pub(crate) fn unique_position<S: TupleTypes, U: 'static>() -> Option<usize> {
+    let mut found = None;
+    for (position, id) in S::type_ids().as_ref().iter().enumerate() {
+        if *id == TypeId::of::<U>() {
+            if found.is_some() {
+                return None;
+            }
+            found = Some(position);
+        }
+    }
+    found
+}
+
+/// Returns the position of the first type appearing more than once.
+pub(crate) fn first_duplicate(type_ids: &[TypeId]) -> Option<usize> {
+    (0..type_ids.len()).find(|&position| type_ids[..position].contains(&type_ids[position]))
+}
+
+/// Occurrences of a type in the two tuple types compared by a
+/// [`TypeOverlap`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OverlapEntry {
+    /// Name of the type.
+    pub type_name: &'static str,
+    /// Number of occurrences in the left tuple type.
+    pub left: usize,
+    /// Number of occurrences in the right tuple type.
+    pub right: usize,
+}
+
+impl OverlapEntry {
+    /// Returns true if the type appears in both tuple types.
+    #[must_use]
+    pub fn is_shared(&self) -> bool {
+        self.left > 0 && self.right > 0
+    }
+}
+
+/// Report of the types shared between the tuple types `A` and `B`, and of
+/// those unique to each side, with their number of occurrences.
+///
+/// Types appearing more than once on either side cannot be accessed by type,
+/// which the counts make apparent when diagnosing why a value was not copied
+/// between two tuples.
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::{OverlapEntry, TypeOverlap};
+///
+/// let overlap = TypeOverlap::of(&(1u8, "name", 2u8), &("other", 'x'));
+/// assert_eq!(
+///     overlap.shared().collect::<Vec<_>>(),
+///     [OverlapEntry { type_name: "&str", left: 1, right: 1 }]
+/// );
+/// assert_eq!(overlap.left_only().map(|entry| entry.left).collect::<Vec<_>>(), [2]);
+/// assert_eq!(overlap.right_only().map(|entry| entry.type_name).collect::<Vec<_>>(), ["char"]);
+/// ```
+#[derive(Debug)]
+pub struct TypeOverlap<A: TupleTypes, B: TupleTypes> {
+    left_ids: A::TypeIds,
+    left_names: A::TypeNames,
+    right_ids: B::TypeIds,
+    right_names: B::TypeNames,
+}
+
+impl<A: TupleTypes, B: TupleTypes> Default for TypeOverlap<A, B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A: TupleTypes, B: TupleTypes> TypeOverlap<A, B> {
+    /// Builds the report comparing the tuple types `A` and `B`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            left_ids: A::type_ids(),
+            left_names: A::type_names(),
+            right_ids: B::type_ids(),
+            right_names: B::type_names(),
+        }
+    }
+
+    /// Builds the report comparing the types of two tuple values.
+    #[must_use]
+    pub fn of(_left: &A, _right: &B) -> Self {
+        Self::new()
+    }
+
+    /// Returns one entry per distinct type of either tuple type, listing the
+    /// types of `A` first, in positional order of first occurrence.
+    pub fn entries(&self) -> impl Iterator<Item = OverlapEntry> + '_ {
+        let (left, right) = (self.left_ids.as_ref(), self.right_ids.as_ref());
+        let count = |ids: &[TypeId], id: &TypeId| ids.iter().filter(|other| *other == id).count();
+        let left_entries =
+            left.iter().enumerate().filter(|(position, id)| !left[..*position].contains(id));
+        let right_entries = right
+            .iter()
+            .enumerate()
+            .filter(|(position, id)| !left.contains(id) && !right[..*position].contains(id));
+        left_entries
+            .map(|(position, id)| (self.left_names.as_ref()[position], id))
+            .chain(right_entries.map(|(position, id)| (self.right_names.as_ref()[position], id)))
+            .map(move |(type_name, id)| OverlapEntry {
+                type_name,
+                left: count(left, id),
+                right: count(right, id),
+            })
+    }
+
+    /// Returns the entries of the types appearing in both tuple types.
+    pub fn shared(&self) -> impl Iterator<Item = OverlapEntry> + '_ {
+        self.entries().filter(OverlapEntry::is_shared)
+    }
+
+    /// Returns the entries of the types appearing only in `A`.
+    pub fn left_only(&self) -> impl Iterator<Item = OverlapEntry> + '_ {
+        self.entries().filter(|entry| entry.right == 0)
+    }
+
+    /// Returns the entries of the types appearing only in `B`.
+    pub fn right_only(&self) -> impl Iterator<Item = OverlapEntry> + '_ {
+        self.entries().filter(|entry| entry.left == 0)
+    }
+}
+
+impl<A: TupleTypes, B: TupleTypes> fmt::Display for TypeOverlap<A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for entry in self.entries() {
+            writeln!(f, "{}: {} left, {} right", entry.type_name, entry.left, entry.right)?;
+        }
+        Ok(())
+    }
+}
+
+macro_rules! impl_tuple_types {
+    ($($idx:tt: $T:ident),+) => {
+        impl<$($T: 'static),+> $crate::TupleTypes for ($($T,)+) {
+            type TypeIds = [TypeId; 0 $(+ $crate::one!($T))+];
+
+            #[inline]
+            fn type_ids() -> Self::TypeIds {
+                [$(TypeId::of::<$T>()),+]
+            }
+
+            type TypeNames = [&'static str; 0 $(+ $crate::one!($T))+];
+
+            #[inline]
+            fn type_names() -> Self::TypeNames {
+                [$(core::any::type_name::<$T>()),+]
+            }
+        }
+    };
+}
+
+pub(crate) use impl_tuple_types;
@@ -0,0 +1,192 @@
+//! Tuple wrapper validating every write with per-type validators.
+
+use alloc::boxed::Box;
+use core::{fmt, ops::Deref};
+
+use crate::TupleSet;
+
+/// Validator of values of type `T`, returning the reason of the rejection on
+/// failure.
+///
+/// Validators are boxed so that closures capturing their environment, such
+/// as configurable bounds, can be registered.
+pub type Validator<T> = Box<dyn Fn(&T) -> Result<(), &'static str>>;
+
+/// Trait for tuples that can be associated with one optional [`Validator`]
+/// per field.
+pub trait Validatable {
+    /// The tuple holding one optional validator per field.
+    type Validators: TupleSet;
+
+    /// Returns the tuple of validators with no registered validator.
+    fn no_validators() -> Self::Validators;
+}
+
+/// Error returned when a write to a [`ValidatedTuple`] is rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ValidationError<T> {
+    /// The type is not found or appears multiple times in the tuple.
+    NotUnique(T),
+    /// The validator of the type rejected the value.
+    Invalid {
+        /// The rejected value.
+        value: T,
+        /// The reason provided by the validator.
+        reason: &'static str,
+    },
+}
+
+impl<T> fmt::Display for ValidationError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotUnique(_) => {
+                write!(
+                    f,
+                    "type '{}' is not found or appears multiple times",
+                    core::any::type_name::<T>()
+                )
+            }
+            Self::Invalid { reason, .. } => write!(f, "invalid value: {reason}"),
+        }
+    }
+}
+
+impl<T: fmt::Debug> core::error::Error for ValidationError<T> {}
+
+/// Tuple wrapper running the registered per-type validator before every
+/// `set` or `map`, rejecting writes that fail validation.
+///
+/// The wrapper dereferences to the tuple for read-only access. Values already
+/// stored when a validator is registered are not validated.
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::{ValidatedTuple, ValidationError};
+///
+/// let max_retries = 10;
+/// let mut config = ValidatedTuple::new((3u32, "localhost"));
+/// config.set_validator::<u32>(
+///     move |retries| {
+///         if *retries <= max_retries { Ok(()) } else { Err("too many retries") }
+///     },
+/// );
+///
+/// assert_eq!(config.set(5u32), Ok(()));
+/// assert_eq!(
+///     config.set(50u32),
+///     Err(ValidationError::Invalid { value: 50, reason: "too many retries" })
+/// );
+/// assert_eq!(*config, (5, "localhost"));
+/// ```
+pub struct ValidatedTuple<T: Validatable> {
+    tuple: T,
+    validators: T::Validators,
+}
+
+impl<T: TupleSet + Validatable> ValidatedTuple<T> {
+    /// Creates a new wrapper around `tuple`, with no registered validator.
+    pub fn new(tuple: T) -> Self {
+        Self { tuple, validators: T::no_validators() }
+    }
+
+    /// Registers the validator of the values of type `U`, replacing any
+    /// previously registered one.
+    ///
+    /// Returns `None` on success, `Some(validator)` if the type is not found
+    /// or appears multiple times.
+    pub fn set_validator<U: 'static>(
+        &mut self,
+        validator: impl Fn(&U) -> Result<(), &'static str> + 'static,
+    ) -> Option<Validator<U>> {
+        let validator: Validator<U> = Box::new(validator);
+        let Some(slot) = self.validators.get_mut::<Option<Validator<U>>>() else {
+            return Some(validator);
+        };
+
+        *slot = Some(validator);
+
+        None
+    }
+
+    /// Sets the value for type `U` if it appears exactly once in the tuple and
+    /// passes validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValidationError::NotUnique`] if the type is not found or
+    /// appears multiple times, or [`ValidationError::Invalid`] if the value is
+    /// rejected by the validator.
+    pub fn set<U: 'static>(&mut self, value: U) -> Result<(), ValidationError<U>> {
+        if !self.tuple.contains_unique::<U>() {
+            return Err(ValidationError::NotUnique(value));
+        }
+
+        if let Err(reason) = self.validate(&value) {
+            return Err(ValidationError::Invalid { value, reason });
+        }
+
+        unsafe { self.tuple.set_unchecked(value) };
+
+        Ok(())
+    }
+
+    /// Applies a mapping function to a copy of the value of type `U`, storing
+    /// the result only if it passes validation.
+    ///
+    /// Returns `None` if the type is not found or appears multiple times,
+    /// `Some(Err(reason))` if the mapped value is rejected by the validator,
+    /// in which case the field is left untouched, or `Some(Ok(result))` with
+    /// the function's return value on success.
+    pub fn map<U: 'static + Clone, G, R>(&mut self, f: G) -> Option<Result<R, &'static str>>
+    where
+        G: FnOnce(&mut U) -> R,
+    {
+        let mut value = self.tuple.get::<U>()?.clone();
+        let result = f(&mut value);
+
+        if let Err(reason) = self.validate(&value) {
+            return Some(Err(reason));
+        }
+
+        unsafe { self.tuple.set_unchecked(value) };
+
+        Some(Ok(result))
+    }
+
+    /// Consumes the wrapper, returning the underlying tuple.
+    pub fn into_inner(self) -> T {
+        self.tuple
+    }
+
+    // Runs the validator registered for `U`, if any
+    fn validate<U: 'static>(&self, value: &U) -> Result<(), &'static str> {
+        match self.validators.get::<Option<Validator<U>>>() {
+            Some(Some(validator)) => validator(value),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<T: Validatable> Deref for ValidatedTuple<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.tuple
+    }
+}
+
+macro_rules! impl_tuple_validatable {
+    ($($idx:tt: $T:ident),+) => {
+        impl<$($T: 'static),+> $crate::Validatable for ($($T,)+) {
+            type Validators = ($(Option<$crate::Validator<$T>>,)+);
+
+            #[inline]
+            fn no_validators() -> Self::Validators {
+                ($(None::<$crate::Validator<$T>>,)+)
+            }
+        }
+    };
+}
+
+pub(crate) use impl_tuple_validatable;
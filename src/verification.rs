@@ -0,0 +1,59 @@
+//! Kani proof harnesses for the `TypeId`-then-cast accessors.
+//!
+//! The harnesses check, for representative tuple shapes and arbitrary field
+//! values, that the checked accessors only ever read or write the field whose
+//! type was requested, and leave the tuple untouched when the lookup fails.
+//! Run them with `cargo kani`.
+
+use crate::TupleSet;
+
+#[kani::proof]
+fn get_reads_the_requested_field() {
+    let tuple: (u8, u32, bool) = (kani::any(), kani::any(), kani::any());
+
+    assert!(core::ptr::eq(tuple.get::<u8>().unwrap(), &tuple.0));
+    assert!(core::ptr::eq(tuple.get::<u32>().unwrap(), &tuple.1));
+    assert!(core::ptr::eq(tuple.get::<bool>().unwrap(), &tuple.2));
+    assert!(tuple.get::<u16>().is_none());
+}
+
+#[kani::proof]
+fn get_rejects_duplicated_types() {
+    let tuple: (u16, i64, u16) = (kani::any(), kani::any(), kani::any());
+
+    assert!(tuple.get::<u16>().is_none());
+    assert!(core::ptr::eq(tuple.get::<i64>().unwrap(), &tuple.1));
+}
+
+#[kani::proof]
+fn set_writes_only_the_requested_field() {
+    let original: (u8, u32, i16) = (kani::any(), kani::any(), kani::any());
+    let value: u32 = kani::any();
+
+    let mut tuple = original;
+    assert!(tuple.set(value).is_none());
+    assert_eq!(tuple, (original.0, value, original.2));
+}
+
+#[kani::proof]
+fn set_leaves_the_tuple_untouched_on_failure() {
+    let original: (u8, u32, u8) = (kani::any(), kani::any(), kani::any());
+    let duplicated: u8 = kani::any();
+    let missing: i32 = kani::any();
+
+    let mut tuple = original;
+    assert_eq!(tuple.set(duplicated), Some(duplicated));
+    assert_eq!(tuple.set(missing), Some(missing));
+    assert_eq!(tuple, original);
+}
+
+#[kani::proof]
+fn map_mutates_only_the_requested_field() {
+    let original: (i64, u8, bool) = (kani::any(), kani::any(), kani::any());
+
+    let mut tuple = original;
+    let previous = tuple.map(|flag: &mut bool| core::mem::replace(flag, !*flag));
+    assert_eq!(previous, Some(original.2));
+    assert_eq!(tuple, (original.0, original.1, !original.2));
+    assert!(tuple.map(|_: &mut u64| ()).is_none());
+}
@@ -0,0 +1,189 @@
+//! Views caching the position of a set of fields, for repeated access.
+
+use core::fmt;
+
+use crate::{InlineFields, TupleTypes};
+
+/// Trait for tuples of types whose fields a [`CachedView`] resolves once.
+pub trait ViewTargets: 'static {
+    /// Array type with one byte offset per target type.
+    type Offsets: AsRef<[usize]> + Copy + fmt::Debug + Eq;
+
+    /// Returns the byte offset, from the start of `tuple`, of the field of
+    /// each target type, if each of them appears exactly once in `tuple`.
+    fn resolve<S: InlineFields>(tuple: &S) -> Option<Self::Offsets>;
+
+    /// Returns the position of `T` among the target types, if any.
+    fn slot<T: 'static>() -> Option<usize>;
+}
+
+/// Trait for tuples of types whose fields
+/// [`split_by_types`](crate::TupleSet::split_by_types) borrows mutably at
+/// once.
+pub trait SplitTargets: ViewTargets + TupleTypes {
+    /// Tuple of mutable references to the field of each target type.
+    type Refs<'a>;
+
+    /// Returns mutable references to the fields at `offsets` bytes from
+    /// `base`.
+    ///
+    /// # Safety
+    ///
+    /// `offsets` must have been resolved by [`ViewTargets::resolve`] on the
+    /// tuple `base` points to, which must stay valid for `'a`, and no other
+    /// reference to the resolved fields may be used during `'a`.
+    #[doc(hidden)]
+    unsafe fn refs_at<'a>(base: *mut u8, offsets: Self::Offsets) -> Self::Refs<'a>;
+}
+
+/// Mutable view over a tuple caching the position of the fields of the types
+/// in `Targets`.
+///
+/// The fields are located once, when the view is created: afterwards,
+/// [`get`](CachedView::get), [`get_mut`](CachedView::get_mut) and
+/// [`set`](CachedView::set) go straight to the cached offset, without
+/// checking that the type appears exactly once in the tuple. As the offsets
+/// only depend on the type of the tuple, [`rebind`](CachedView::rebind)
+/// moves the view to another tuple of the same type without resolving them
+/// again, amortizing the lookups in loops over many tuples. Reusing the
+/// offsets is only sound for types whose fields are stored inline, hence the
+/// [`InlineFields`] bound.
+///
+/// # Examples
+///
+/// ```
+/// use tuple_set::CachedView;
+///
+/// let mut particles = [(1.0f32, 0.5f64, 0u32), (2.0, -0.5, 0)];
+/// let [first, rest @ ..] = &mut particles;
+///
+/// let mut view = CachedView::<_, (f32, f64, u32)>::new(first).unwrap();
+/// for particle in rest {
+///     *view.get_mut::<f32>() += *view.get::<f64>() as f32;
+///     view.set(1u32);
+///     view = view.rebind(particle);
+/// }
+/// *view.get_mut::<f32>() += *view.get::<f64>() as f32;
+///
+/// assert_eq!(particles, [(1.5, 0.5, 1), (1.5, -0.5, 0)]);
+/// ```
+pub struct CachedView<'a, S, Targets: ViewTargets> {
+    tuple: &'a mut S,
+    offsets: Targets::Offsets,
+}
+
+impl<'a, S: InlineFields, Targets: ViewTargets> CachedView<'a, S, Targets> {
+    /// Creates a view over `tuple`, resolving the field of each type in
+    /// `Targets`.
+    ///
+    /// Returns `None` if any type of `Targets` is not found or appears
+    /// multiple times in `tuple`.
+    pub fn new(tuple: &'a mut S) -> Option<Self> {
+        let offsets = Targets::resolve(&*tuple)?;
+        Some(Self { tuple, offsets })
+    }
+
+    /// Moves the view to another tuple of the same type, reusing the
+    /// resolved offsets.
+    #[must_use]
+    pub fn rebind<'b>(self, tuple: &'b mut S) -> CachedView<'b, S, Targets> {
+        CachedView { tuple, offsets: self.offsets }
+    }
+
+    /// Returns the underlying tuple.
+    #[must_use]
+    pub fn into_inner(self) -> &'a mut S {
+        self.tuple
+    }
+
+    /// Returns the cached byte offset of the field of type `T`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T` is not one of the types in `Targets`.
+    fn offset_of<T: 'static>(&self) -> usize {
+        match Targets::slot::<T>() {
+            Some(slot) => self.offsets.as_ref()[slot],
+            None => panic!(
+                "Type '{}' is not among the targets of the view.",
+                core::any::type_name::<T>()
+            ),
+        }
+    }
+
+    /// Returns a reference to the field of type `T`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T` is not one of the types in `Targets`.
+    #[must_use]
+    pub fn get<T: 'static>(&self) -> &T {
+        let offset = self.offset_of::<T>();
+        // SAFETY: the offset was resolved on a tuple of the same type, to a
+        // field of type `T`, which `InlineFields` keeps at the same offset
+        unsafe { &*(&raw const *self.tuple).byte_add(offset).cast::<T>() }
+    }
+
+    /// Returns a mutable reference to the field of type `T`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T` is not one of the types in `Targets`.
+    pub fn get_mut<T: 'static>(&mut self) -> &mut T {
+        let offset = self.offset_of::<T>();
+        // SAFETY: the offset was resolved on a tuple of the same type, to a
+        // field of type `T`, which `InlineFields` keeps at the same offset
+        unsafe { &mut *(&raw mut *self.tuple).byte_add(offset).cast::<T>() }
+    }
+
+    /// Replaces the field of type `T` with `value`, returning the previous
+    /// value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T` is not one of the types in `Targets`.
+    pub fn set<T: 'static>(&mut self, value: T) -> T {
+        core::mem::replace(self.get_mut(), value)
+    }
+}
+
+impl<S: fmt::Debug, Targets: ViewTargets> fmt::Debug for CachedView<'_, S, Targets> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CachedView")
+            .field("tuple", &self.tuple)
+            .field("offsets", &self.offsets)
+            .finish()
+    }
+}
+
+macro_rules! impl_tuple_view {
+    ($($idx:tt: $T:ident),+) => {
+        impl<$($T: 'static),+> $crate::ViewTargets for ($($T,)+) {
+            type Offsets = [usize; 0 $(+ $crate::one!($T))+];
+
+            fn resolve<S: $crate::InlineFields>(tuple: &S) -> Option<Self::Offsets> {
+                let base = (&raw const *tuple).addr();
+                Some([$($crate::TupleSet::as_ptr_of::<$T>(tuple)?.addr() - base),+])
+            }
+
+            #[inline]
+            fn slot<T: 'static>() -> Option<usize> {
+                [$(core::any::TypeId::of::<$T>()),+]
+                    .iter()
+                    .position(|&type_id| type_id == core::any::TypeId::of::<T>())
+            }
+        }
+
+        impl<$($T: 'static),+> $crate::SplitTargets for ($($T,)+) {
+            type Refs<'a> = ($(&'a mut $T,)+);
+
+            #[inline]
+            unsafe fn refs_at<'a>(base: *mut u8, offsets: Self::Offsets) -> Self::Refs<'a> {
+                // SAFETY: upheld by the caller
+                unsafe { ($(&mut *base.add(offsets[$idx]).cast::<$T>(),)+) }
+            }
+        }
+    };
+}
+
+pub(crate) use impl_tuple_view;
@@ -0,0 +1,314 @@
+//! Bridge exposing tuple fields to JavaScript by type name.
+
+use alloc::string::{String, ToString};
+use core::fmt;
+
+use wasm_bindgen::{JsError, JsValue};
+
+/// Error returned when accessing a tuple field by type name fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BridgeError {
+    /// No field of the tuple has the requested type name.
+    NotFound,
+    /// Multiple fields of the tuple have the requested type name.
+    Ambiguous {
+        /// Number of fields having the requested type name.
+        count: usize,
+    },
+    /// The provided value cannot be converted into the type of the field.
+    InvalidValue {
+        /// Name of the type of the field.
+        type_name: &'static str,
+    },
+}
+
+impl fmt::Display for BridgeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotFound => f.write_str("type name not found in tuple"),
+            Self::Ambiguous { count } => write!(f, "type name appears {count} times in tuple"),
+            Self::InvalidValue { type_name } => {
+                write!(f, "value cannot be converted into `{type_name}`")
+            }
+        }
+    }
+}
+
+impl core::error::Error for BridgeError {}
+
+impl From<BridgeError> for JsValue {
+    fn from(error: BridgeError) -> Self {
+        JsError::new(&error.to_string()).into()
+    }
+}
+
+/// Trait for values convertible to and from a `JsValue`.
+pub trait JsField: Sized {
+    /// Converts the value into a `JsValue`.
+    fn to_js(&self) -> JsValue;
+
+    /// Converts a `JsValue` back into a value.
+    ///
+    /// Returns `None` if the `JsValue` does not represent a value of this
+    /// type, e.g. a fractional or out-of-range number for an integer type.
+    fn from_js(value: JsValue) -> Option<Self>;
+}
+
+// JavaScript numbers are doubles, which represent integers of up to 32 bits
+// exactly: the value is narrowed and accepted only if it round-trips
+macro_rules! impl_js_number {
+    ($($N:ty),+) => {
+        $(
+            impl JsField for $N {
+                #[inline]
+                fn to_js(&self) -> JsValue {
+                    JsValue::from(*self)
+                }
+
+                #[inline]
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                fn from_js(value: JsValue) -> Option<Self> {
+                    let number = value.as_f64()?;
+                    let narrowed = number as $N;
+                    (f64::from(narrowed) == number).then_some(narrowed)
+                }
+            }
+        )+
+    };
+}
+
+impl_js_number!(i8, i16, i32, u8, u16, u32);
+
+// 64 and 128-bit integers are represented as `BigInt`s
+macro_rules! impl_js_bigint {
+    ($($N:ty),+) => {
+        $(
+            impl JsField for $N {
+                #[inline]
+                fn to_js(&self) -> JsValue {
+                    JsValue::from(*self)
+                }
+
+                #[inline]
+                fn from_js(value: JsValue) -> Option<Self> {
+                    Self::try_from(value).ok()
+                }
+            }
+        )+
+    };
+}
+
+impl_js_bigint!(i64, u64, i128, u128);
+
+impl JsField for f32 {
+    #[inline]
+    fn to_js(&self) -> JsValue {
+        JsValue::from(*self)
+    }
+
+    #[inline]
+    #[allow(clippy::cast_possible_truncation)]
+    fn from_js(value: JsValue) -> Option<Self> {
+        value.as_f64().map(|number| number as f32)
+    }
+}
+
+impl JsField for f64 {
+    #[inline]
+    fn to_js(&self) -> JsValue {
+        JsValue::from(*self)
+    }
+
+    #[inline]
+    fn from_js(value: JsValue) -> Option<Self> {
+        value.as_f64()
+    }
+}
+
+impl JsField for bool {
+    #[inline]
+    fn to_js(&self) -> JsValue {
+        JsValue::from(*self)
+    }
+
+    #[inline]
+    fn from_js(value: JsValue) -> Option<Self> {
+        value.as_bool()
+    }
+}
+
+impl JsField for String {
+    #[inline]
+    fn to_js(&self) -> JsValue {
+        JsValue::from(self.as_str())
+    }
+
+    #[inline]
+    fn from_js(value: JsValue) -> Option<Self> {
+        value.as_string()
+    }
+}
+
+/// `None` is represented as `undefined`, and both `null` and `undefined`
+/// convert back into `None`.
+impl<T: JsField> JsField for Option<T> {
+    #[inline]
+    fn to_js(&self) -> JsValue {
+        self.as_ref().map_or(JsValue::UNDEFINED, JsField::to_js)
+    }
+
+    #[inline]
+    fn from_js(value: JsValue) -> Option<Self> {
+        if value.is_null() || value.is_undefined() {
+            Some(None)
+        } else {
+            T::from_js(value).map(Some)
+        }
+    }
+}
+
+/// Trait for tuples whose fields can be read and written as `JsValue`s by
+/// type name.
+///
+/// Type names are the ones reported by [`core::any::type_name`], such as
+/// `"u32"` or `"alloc::string::String"`.
+pub trait JsFields {
+    /// Returns the field with the given type name as a `JsValue`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BridgeError::NotFound`] or [`BridgeError::Ambiguous`] if the
+    /// type name does not identify exactly one field.
+    fn get_js(&self, type_name: &str) -> Result<JsValue, BridgeError>;
+
+    /// Overwrites the field with the given type name with `value`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BridgeError::NotFound`] or [`BridgeError::Ambiguous`] if the
+    /// type name does not identify exactly one field, or
+    /// [`BridgeError::InvalidValue`] if `value` cannot be converted into the
+    /// type of the field, in which case the field is left untouched.
+    fn set_js(&mut self, type_name: &str, value: JsValue) -> Result<(), BridgeError>;
+}
+
+/// Returns the position of the only field named `type_name`.
+pub(crate) fn position_of_name(
+    names: &[&'static str],
+    type_name: &str,
+) -> Result<usize, BridgeError> {
+    let mut matches = names.iter().enumerate().filter(|(_, name)| **name == type_name);
+    let (position, _) = matches.next().ok_or(BridgeError::NotFound)?;
+    match matches.count() {
+        0 => Ok(position),
+        others => Err(BridgeError::Ambiguous { count: others + 1 }),
+    }
+}
+
+/// Wrapper exposing a context tuple to a WebAssembly host by type name.
+///
+/// Since `#[wasm_bindgen]` types cannot be generic, hosts wrap a bridge over
+/// their concrete tuple in their own exported type, forwarding to
+/// [`get_by_name`](JsBridge::get_by_name) and
+/// [`set_by_name`](JsBridge::set_by_name). [`BridgeError`] converts into a
+/// JavaScript `Error`, so that it can be propagated with `?`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use tuple_set::JsBridge;
+/// use wasm_bindgen::JsValue;
+///
+/// let mut context = JsBridge::new((3u32, String::from("localhost")));
+/// context.set_by_name("u32", JsValue::from(8u32)).unwrap();
+/// assert_eq!(context.get_by_name("u32").unwrap(), JsValue::from(8u32));
+/// assert_eq!(context.into_inner().0, 8);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct JsBridge<T> {
+    tuple: T,
+}
+
+impl<T> JsBridge<T> {
+    /// Wraps `tuple` to expose its fields by type name.
+    pub fn new(tuple: T) -> Self {
+        Self { tuple }
+    }
+
+    /// Returns the wrapped tuple.
+    pub fn into_inner(self) -> T {
+        self.tuple
+    }
+}
+
+impl<T: JsFields> JsBridge<T> {
+    /// Returns the field with the given type name as a `JsValue`.
+    ///
+    /// # Errors
+    ///
+    /// See [`JsFields::get_js`].
+    pub fn get_by_name(&self, type_name: &str) -> Result<JsValue, BridgeError> {
+        self.tuple.get_js(type_name)
+    }
+
+    /// Overwrites the field with the given type name with `value`.
+    ///
+    /// # Errors
+    ///
+    /// See [`JsFields::set_js`].
+    pub fn set_by_name(&mut self, type_name: &str, value: JsValue) -> Result<(), BridgeError> {
+        self.tuple.set_js(type_name, value)
+    }
+}
+
+impl<T> core::ops::Deref for JsBridge<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.tuple
+    }
+}
+
+impl<T> core::ops::DerefMut for JsBridge<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.tuple
+    }
+}
+
+impl<T> From<T> for JsBridge<T> {
+    fn from(tuple: T) -> Self {
+        Self::new(tuple)
+    }
+}
+
+macro_rules! impl_tuple_wasm {
+    ($($idx:tt: $T:ident),+) => {
+        impl<$($T: $crate::JsField),+> $crate::JsFields for ($($T,)+) {
+            fn get_js(&self, type_name: &str) -> Result<wasm_bindgen::JsValue, $crate::BridgeError> {
+                let names = [$(core::any::type_name::<$T>()),+];
+                match $crate::wasm::position_of_name(&names, type_name)? {
+                    $($idx => Ok($crate::JsField::to_js(&self.$idx)),)+
+                    _ => unreachable!(),
+                }
+            }
+
+            fn set_js(&mut self, type_name: &str, value: wasm_bindgen::JsValue) -> Result<(), $crate::BridgeError> {
+                let names = [$(core::any::type_name::<$T>()),+];
+                match $crate::wasm::position_of_name(&names, type_name)? {
+                    $(
+                        $idx => {
+                            self.$idx = <$T as $crate::JsField>::from_js(value)
+                                .ok_or($crate::BridgeError::InvalidValue { type_name: names[$idx] })?;
+                        }
+                    )+
+                    _ => unreachable!(),
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+pub(crate) use impl_tuple_wasm;
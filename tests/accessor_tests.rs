@@ -0,0 +1,57 @@
+use tuple_set::Accessor;
+
+#[test]
+fn test_accessor_requires_unique_field() {
+    let tuple = (1u8, 2u32, "three");
+    assert!(Accessor::<_, u32>::new(&tuple).is_some());
+    assert!(Accessor::<_, bool>::new(&tuple).is_none());
+
+    let duplicated = (1u8, 2u8, 3u32);
+    assert!(Accessor::<_, u8>::new(&duplicated).is_none());
+    assert!(Accessor::<_, u32>::new(&duplicated).is_some());
+}
+
+#[test]
+fn test_accessor_get_and_set() {
+    let mut tuple = (1u8, 2u32, "three", 4.0f64);
+    let byte = Accessor::<_, u8>::new(&tuple).unwrap();
+    let float = Accessor::<_, f64>::new(&tuple).unwrap();
+
+    assert_eq!(*byte.get(&tuple), 1);
+    assert_eq!(*float.get(&tuple), 4.0);
+    *byte.get_mut(&mut tuple) += 10;
+    assert_eq!(float.set(&mut tuple, 8.0), 4.0);
+    assert_eq!(tuple, (11, 2, "three", 8.0));
+}
+
+#[test]
+fn test_accessor_reused_across_tuples() {
+    let mut tuples: Vec<(u16, bool, u64)> = (0..8).map(|i| (i, false, 0)).collect();
+    let index = Accessor::<_, u16>::new(&tuples[0]).unwrap();
+    let even = Accessor::<_, bool>::new(&tuples[0]).unwrap();
+    let tripled = Accessor::<_, u64>::new(&tuples[0]).unwrap();
+
+    for tuple in &mut tuples {
+        let value = *index.get(tuple);
+        even.set(tuple, value % 2 == 0);
+        tripled.set(tuple, u64::from(value) * 3);
+    }
+
+    assert_eq!(tuples[3], (3, false, 9));
+    assert_eq!(tuples[4], (4, true, 12));
+    assert_eq!(index.offset(), (&raw const tuples[0].0).addr() - (&raw const tuples[0]).addr());
+}
+
+tuple_set::define_tuple!(
+    Endpoint => host: &'static str [host_mut, set_host], port: u16 [port_mut, set_port]
+);
+
+#[test]
+fn test_accessor_on_defined_tuple() {
+    let mut endpoint = Endpoint::new("localhost", 80);
+    let port = Accessor::<_, u16>::new(&endpoint).unwrap();
+
+    *port.get_mut(&mut endpoint) = 8080;
+    assert_eq!(*port.get(&endpoint), 8080);
+    assert_eq!(*port.get(&Endpoint::new("example.org", 443)), 443);
+}
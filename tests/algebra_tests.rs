@@ -0,0 +1,125 @@
+#![cfg(feature = "algebra")]
+
+use tuple_set::{
+    Concat, Contains, ContainsAll, Difference, EndsWith, Intersection, Pluck, Prepend, StartsWith,
+    Subset, TupleSet, Union,
+};
+
+fn type_name_of<T>() -> &'static str {
+    core::any::type_name::<T>()
+}
+
+#[test]
+fn test_pluck() {
+    let (value, rest): (&str, _) = (42i32, "hello", 2.5f64).pluck();
+    assert_eq!(value, "hello");
+    assert_eq!(rest, (42, 2.5));
+
+    let (value, rest): (i32, ()) = (42i32,).pluck();
+    assert_eq!(value, 42);
+    assert_eq!(rest, ());
+}
+
+#[test]
+fn test_prepend_and_concat() {
+    assert_eq!(().prepend(1u8), (1u8,));
+    assert_eq!((2u16, 3u32).prepend(1u8), (1u8, 2u16, 3u32));
+    assert_eq!(().concat((1u8,)), (1u8,));
+    assert_eq!((1u8, 2u16).concat((3u32, 4u64)), (1u8, 2u16, 3u32, 4u64));
+}
+
+#[test]
+fn test_split_subset() {
+    let ((c, a), rest): ((char, i32), _) = (42i32, "hello", 'x', 2.5f64).split_subset();
+    assert_eq!((c, a), ('x', 42));
+    assert_eq!(rest, ("hello", 2.5));
+}
+
+#[test]
+fn test_difference() {
+    fn difference<A: Difference<B, I>, B, I>() -> &'static str {
+        type_name_of::<A::Output>()
+    }
+
+    assert_eq!(difference::<(i32, bool, char), (char,), _>(), type_name_of::<(i32, bool)>());
+    assert_eq!(difference::<(i32, bool), (bool, i32), _>(), type_name_of::<()>());
+}
+
+#[test]
+fn test_intersection() {
+    fn exclusive<A: Intersection<B, S, I>, B, S, I>() -> (&'static str, &'static str) {
+        (type_name_of::<A::SelfOnly>(), type_name_of::<A::OtherOnly>())
+    }
+
+    assert_eq!(
+        exclusive::<(i32, bool, u8), (u8, char, i32), (i32, u8), _>(),
+        (type_name_of::<(bool,)>(), type_name_of::<(char,)>())
+    );
+}
+
+#[test]
+fn test_union() {
+    let merged: (i32, bool, char, u8) =
+        Union::<_, (i32,), _>::union((1i32, true), ('x', 2i32, 3u8));
+    assert_eq!(merged, (1, true, 'x', 3));
+}
+
+#[test]
+fn test_union_generic_merge() {
+    fn merge<A, B, S, I>(a: A, b: B) -> A::Output
+    where
+        A: Union<B, S, I>,
+    {
+        a.union(b)
+    }
+
+    let merged = merge::<_, _, (u8, u16), _>((1u8, 2u16, 'a'), (4u16, 5u8, true));
+    assert_eq!(merged, (1u8, 2u16, 'a', true));
+}
+
+#[test]
+fn test_contains() {
+    fn get_flag<C: Contains<bool, I> + TupleSet, I>(context: &C) -> Option<bool> {
+        context.get::<bool>().copied()
+    }
+
+    assert_eq!(get_flag(&(1u8, true)), Some(true));
+    assert_eq!(get_flag(&(false,)), Some(false));
+}
+
+#[test]
+fn test_contains_all() {
+    fn sum<C: ContainsAll<(u8, u16, u32), I> + TupleSet, I>(context: &C) -> u32 {
+        u32::from(*context.get::<u8>().unwrap())
+            + u32::from(*context.get::<u16>().unwrap())
+            + *context.get::<u32>().unwrap()
+    }
+
+    assert_eq!(sum(&(1u8, "hello", 2u16, 3u32)), 6);
+    assert_eq!(sum(&(3u32, 2u16, 1u8)), 6);
+}
+
+#[test]
+fn test_starts_with() {
+    let record = (1u8, "name", true, 'x');
+    assert_eq!(StartsWith::<(u8,)>::into_prefix(record), (1,));
+    assert_eq!(StartsWith::<(u8, &str, bool)>::into_prefix(record), (1, "name", true));
+    assert_eq!(StartsWith::<(u8, &str, bool, char)>::into_prefix(record), record);
+}
+
+#[test]
+fn test_ends_with() {
+    let record = (1u8, "name", true, 'x');
+    assert_eq!(EndsWith::<(char,)>::into_suffix(record), ('x',));
+    assert_eq!(EndsWith::<(&str, bool, char)>::into_suffix(record), ("name", true, 'x'));
+    assert_eq!(EndsWith::<(u8, &str, bool, char)>::into_suffix(record), record);
+}
+
+#[test]
+fn test_prefix_with_repeated_types() {
+    fn legacy<T: StartsWith<(u32, u32)>>(record: T) -> (u32, u32) {
+        record.into_prefix()
+    }
+
+    assert_eq!(legacy((1u32, 2u32, 3u32)), (1, 2));
+}
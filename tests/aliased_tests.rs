@@ -0,0 +1,42 @@
+use core::{cmp::Reverse, num::Wrapping};
+
+use tuple_set::{Tagged, TransparentAlias, TupleSet};
+
+#[derive(Debug, PartialEq)]
+#[repr(transparent)]
+struct Name(String);
+
+// SAFETY: `Name` is a transparent wrapper over any `String`
+unsafe impl TransparentAlias for Name {
+    type Inner = String;
+}
+
+struct Primary;
+
+#[test]
+fn test_domain_wrappers() {
+    let mut user = (Name(String::from("alice")), 30u8);
+    assert_eq!(user.get_aliased::<Name>().map(String::as_str), Some("alice"));
+    assert_eq!(user.map_aliased::<Name, _, _>(|name| name.push_str("@example.com")), Some(()));
+    assert_eq!(user.set_aliased::<Name>(String::from("bob")), None);
+    assert_eq!(user.0, Name(String::from("bob")));
+}
+
+#[test]
+fn test_core_wrappers() {
+    let mut tuple = (Wrapping(u32::MAX), Reverse(3i8), Tagged::<Primary, u16>::new(80));
+    tuple.map_aliased::<Wrapping<u32>, _, _>(|counter| *counter = counter.wrapping_add(2));
+    assert_eq!(tuple.0, Wrapping(1));
+    assert_eq!(tuple.get_aliased::<Reverse<i8>>(), Some(&3));
+    assert_eq!(tuple.set_aliased::<Tagged<Primary, u16>>(443), None);
+    assert_eq!(*tuple.2, 443);
+}
+
+#[test]
+fn test_missing_or_duplicated_wrappers() {
+    let mut tuple = (Wrapping(1u8), Wrapping(2u8), 3u8);
+    assert_eq!(tuple.get_aliased::<Wrapping<u8>>(), None);
+    assert_eq!(tuple.set_aliased::<Wrapping<u8>>(9), Some(9));
+    assert_eq!(tuple.map_aliased::<Wrapping<u16>, _, _>(|value| *value), None);
+    assert_eq!(tuple, (Wrapping(1), Wrapping(2), 3));
+}
@@ -0,0 +1,32 @@
+use tuple_set::Apply;
+
+fn describe(name: &str, age: u8, admin: bool) -> String {
+    format!("{name} ({age}){}", if admin { " [admin]" } else { "" })
+}
+
+#[test]
+fn test_apply_function() {
+    assert_eq!(("alice", 30u8, true).apply(describe), "alice (30) [admin]");
+    assert_eq!((1u8,).apply(u16::from), 1u16);
+    assert_eq!(().apply(|| 5), 5);
+}
+
+#[test]
+fn test_apply_closure_by_value() {
+    let owned = (String::from("a"), vec![1u8, 2]);
+    let joined = owned.apply(|mut text: String, bytes: Vec<u8>| {
+        text.extend(bytes.iter().map(|byte| char::from(b'0' + byte)));
+        text
+    });
+    assert_eq!(joined, "a12");
+}
+
+#[test]
+fn test_apply_long_tuple() {
+    let sum = (1u8, 2u8, 3u8, 4u8, 5u8, 6u8, 7u8, 8u8, 9u8, 10u8, 11u8, 12u8, 13u8, 14u8).apply(
+        |a, b, c, d, e, f, g, h, i, j, k, l, m, n| {
+            [a, b, c, d, e, f, g, h, i, j, k, l, m, n].iter().map(|&x| u32::from(x)).sum::<u32>()
+        },
+    );
+    assert_eq!(sum, 105);
+}
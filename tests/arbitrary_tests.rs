@@ -0,0 +1,55 @@
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use tuple_set::{Frozen, Homogeneous, Patchable, Tagged, TupleSet};
+
+struct Primary;
+
+#[test]
+fn test_wrappers_expose_generated_tuples() {
+    let bytes = [1, 0, 0, 0, 2, 1];
+
+    let mut u = Unstructured::new(&bytes);
+    let tagged = Tagged::<Primary, u32>::arbitrary(&mut u).unwrap();
+    assert_eq!(*tagged, 1);
+
+    let mut u = Unstructured::new(&bytes);
+    let frozen = Frozen::<(u32, u8)>::arbitrary(&mut u).unwrap();
+    assert_eq!(frozen.get::<u8>(), Some(&2));
+
+    let mut u = Unstructured::new(&bytes);
+    let homogeneous = Homogeneous::<(u8, u8)>::arbitrary(&mut u).unwrap();
+    assert_eq!(homogeneous.0, (1, 0));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_validated_tuple_holds_no_validator() {
+    let bytes = [1, 0, 0, 0, 2, 1];
+    let mut u = Unstructured::new(&bytes);
+    let mut validated = tuple_set::ValidatedTuple::<(u32, u8)>::arbitrary(&mut u).unwrap();
+    assert_eq!(validated.set(7u32), Ok(()));
+    assert_eq!(*validated, (7, 2));
+}
+
+#[test]
+fn test_generated_patches_apply() {
+    let bytes = [1, 5, 0, 1];
+    let mut u = Unstructured::new(&bytes);
+    let patch = <(u8, bool) as Patchable>::Patch::arbitrary(&mut u).unwrap();
+
+    let mut tuple = (0u8, false);
+    tuple.apply_patch(patch);
+    assert_eq!(tuple, (patch.0.unwrap_or(0), patch.1.unwrap_or(false)));
+}
+
+#[cfg(feature = "algebra")]
+#[test]
+fn test_generated_builders_complete() {
+    use tuple_set::{TupleBuilder, Vacant};
+
+    let bytes = [9, 0, 0, 0];
+    let mut u = Unstructured::new(&bytes);
+    let builder = TupleBuilder::<(u32, bool), (u32, Vacant<bool>)>::arbitrary(&mut u).unwrap();
+    assert_eq!(builder.set(true).build(), (9, true));
+}
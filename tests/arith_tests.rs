@@ -0,0 +1,43 @@
+use core::time::Duration;
+
+use tuple_set::{
+    AddAssignEach, AddEach, DivAssignEach, DivEach, MulAssignEach, MulEach, SubAssignEach, SubEach,
+};
+
+type Metrics = (u64, Duration, f64);
+
+#[test]
+fn test_accumulate_metrics() {
+    let mut total: Metrics = (0, Duration::ZERO, 0.0);
+    for sample in [(1, Duration::from_millis(5), 0.5), (2, Duration::from_millis(10), 1.5)] {
+        total.add_assign_each(sample);
+    }
+    assert_eq!(total, (3, Duration::from_millis(15), 2.0));
+}
+
+#[test]
+fn test_by_value_ops() {
+    assert_eq!((1i32, 2.0f32).add_each((2, 0.5)), (3, 2.5));
+    assert_eq!((1i32, 2.0f32).sub_each((2, 0.5)), (-1, 1.5));
+    assert_eq!((3i32, 2.0f32).mul_each((2, 0.5)), (6, 1.0));
+    assert_eq!((7i32, 2.0f32).div_each((2, 0.5)), (3, 4.0));
+}
+
+#[test]
+fn test_assign_ops() {
+    let mut tuple = (10i64, 4u8);
+    tuple.sub_assign_each((3, 1));
+    assert_eq!(tuple, (7, 3));
+    tuple.mul_assign_each((2, 3));
+    assert_eq!(tuple, (14, 9));
+    tuple.div_assign_each((7, 3));
+    assert_eq!(tuple, (2, 3));
+}
+
+#[test]
+fn test_large_tuple() {
+    let ones =
+        (1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8);
+    let twos = ones.add_each(ones);
+    assert_eq!((twos.0, twos.16), (2, 2));
+}
@@ -0,0 +1,39 @@
+use tuple_set::{TupleArity, TupleSet, TupleTypes, define_tuple};
+
+define_tuple!(Endpoint => host: &'static str [host_mut, set_host], port: u16 [port_mut, set_port]);
+
+#[test]
+fn test_arity_of_tuples() {
+    assert_eq!(<(u8,)>::ARITY, 1);
+    assert_eq!(<(u8, bool, char)>::ARITY, 3);
+    assert_eq!(('a', 1u8).arity(), 2);
+}
+
+#[test]
+fn test_arity_sizes_arrays() {
+    type Record = (u8, u16, u32, u64);
+    let positions: [usize; Record::ARITY] = core::array::from_fn(|position| position);
+    assert_eq!(positions, [0, 1, 2, 3]);
+}
+
+// Returns the arity and the number of `TypeId`s of the type of `_tuple`
+fn arity_of<T: TupleArity + TupleTypes>(_tuple: &T) -> (usize, usize) {
+    (T::ARITY, T::type_ids().as_ref().len())
+}
+
+#[test]
+fn test_arity_matches_type_ids() {
+    let wide = (
+        0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+        0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+        0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+        0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+    );
+    assert_eq!(arity_of(&wide), (64, 64));
+}
+
+#[test]
+fn test_arity_of_defined_tuple() {
+    assert_eq!(Endpoint::ARITY, 2);
+    assert_eq!(Endpoint::new("localhost", 8080).arity(), 2);
+}
@@ -0,0 +1,76 @@
+use tuple_set::{Homogeneous, HomogeneousTuple};
+
+fn total<T: HomogeneousTuple<Array = [u32; 3]>>(tuple: T) -> u32 {
+    tuple.into_array().iter().sum()
+}
+
+#[test]
+fn test_into_array() {
+    assert_eq!((1u8,).into_array(), [1]);
+    assert_eq!((String::from("a"), String::from("b")).into_array(), ["a", "b"]);
+    assert_eq!(total((1, 2, 3)), 6);
+}
+
+/// Tuples have no guaranteed layout, so a tuple may or may not be viewed as an
+/// array, but an array must hold the elements in positional order.
+fn assert_positional<T: PartialEq + core::fmt::Debug, const N: usize>(
+    array: Option<&[T; N]>,
+    expected: &[T; N],
+) {
+    if let Some(array) = array {
+        assert_eq!(array, expected);
+    }
+}
+
+#[test]
+fn test_as_array_ref() {
+    let tuple = (1.5f64, 2.5f64);
+    assert_positional(tuple.as_array_ref(), &[1.5, 2.5]);
+}
+
+#[test]
+fn test_as_array_mut() {
+    let mut tuple = (4i64, -2i64, 9i64);
+    if let Some(array) = tuple.as_array_mut() {
+        assert_eq!(array, &[4, -2, 9]);
+        array.reverse();
+        assert_eq!(tuple, (9, -2, 4));
+    } else {
+        assert_eq!(tuple, (4, -2, 9));
+    }
+}
+
+#[test]
+fn test_as_array_large_tuple() {
+    let tuple = (
+        0u16, 1u16, 2u16, 3u16, 4u16, 5u16, 6u16, 7u16, 8u16, 9u16, 10u16, 11u16, 12u16, 13u16,
+        14u16, 15u16, 16u16,
+    );
+    assert_positional(tuple.as_array_ref(), &tuple.into_array());
+    assert_eq!(tuple.into_array().len(), 17);
+}
+
+#[test]
+fn test_iter_and_iter_mut() {
+    let mut tuple = (3i32, -1i32, 2i32);
+    assert_eq!(tuple.iter().copied().collect::<Vec<_>>(), [3, -1, 2]);
+    for value in tuple.iter_mut() {
+        *value = value.abs();
+    }
+    assert_eq!(tuple, (3, 1, 2));
+}
+
+#[test]
+fn test_homogeneous_into_iterator() {
+    let words = Homogeneous((String::from("a"), String::from("b")));
+    assert_eq!((&words).into_iter().map(String::len).sum::<usize>(), 2);
+    let owned: Vec<String> = words.into_iter().collect();
+    assert_eq!(owned, ["a", "b"]);
+
+    let mut counters = Homogeneous((1u8, 2u8, 3u8));
+    for counter in &mut counters {
+        *counter *= 10;
+    }
+    assert_eq!(counters.0, (10, 20, 30));
+    assert_eq!(counters.into_iter().next_back(), Some(30));
+}
@@ -0,0 +1,36 @@
+#![cfg(feature = "arrayvec")]
+
+use tuple_set::{CapacityExceeded, TupleSet};
+
+#[test]
+fn test_drain_all_of() {
+    let mut tuple = (1u32, 'x', 2u32, 3u32);
+    let drained = tuple.drain_all_of::<u32, 3>().unwrap();
+    assert_eq!(drained.as_slice(), [1, 2, 3]);
+    assert_eq!(tuple, (0, 'x', 0, 0));
+}
+
+#[test]
+fn test_drain_all_of_capacity_exceeded() {
+    let mut tuple = (1u32, 2u32);
+    assert_eq!(
+        tuple.drain_all_of::<u32, 1>().map(|drained| drained.len()),
+        Err(CapacityExceeded { required: 2, capacity: 1 })
+    );
+    assert_eq!(tuple, (1, 2));
+}
+
+#[test]
+fn test_drain_all_of_missing_type() {
+    let mut tuple = (1u32, 'x');
+    assert!(tuple.drain_all_of::<String, 2>().unwrap().is_empty());
+    assert_eq!(tuple, (1, 'x'));
+}
+
+#[test]
+fn test_drain_all_of_owned_values() {
+    let mut tuple = (vec![1u8], "label", vec![2u8, 3]);
+    let drained = tuple.drain_all_of::<Vec<u8>, 2>().unwrap();
+    assert_eq!(drained.into_inner().unwrap(), [vec![1], vec![2, 3]]);
+    assert!(tuple.0.is_empty() && tuple.2.is_empty());
+}
@@ -0,0 +1,48 @@
+use tuple_set::{AsRefs, Cloned, Copied};
+
+fn total((a, b, c): (&u8, &u16, &u32)) -> u64 {
+    u64::from(*a) + u64::from(*b) + u64::from(*c)
+}
+
+#[test]
+fn test_as_refs() {
+    let tuple = (1u8, 2u16, 3u32);
+    assert_eq!(total(tuple.as_refs()), 6);
+
+    let single = (String::from("single"),);
+    let (first,) = single.as_refs();
+    assert_eq!(first, "single");
+}
+
+#[test]
+fn test_as_muts() {
+    let mut tuple = (1u8, vec![1u8], 'a');
+    let (number, list, letter) = tuple.as_muts();
+    *number += 1;
+    list.push(2);
+    *letter = 'b';
+    assert_eq!(tuple, (2, vec![1, 2], 'b'));
+}
+
+#[test]
+fn test_cloned() {
+    let name = String::from("node");
+    let ports = vec![80u16, 443];
+    let owned: (String, Vec<u16>, u8) = (&name, &ports, &7u8).cloned();
+    assert_eq!(owned, (name.clone(), ports.clone(), 7));
+
+    let job = (String::from("backup"), 3u32);
+    assert_eq!(job.as_refs().cloned(), job);
+}
+
+#[test]
+fn test_copied() {
+    let (a, b) = (&1u8, &'x').copied();
+    assert_eq!((a, b), (1, 'x'));
+
+    let mut sample = (2u32, 1.5f64);
+    let snapshot = sample.as_refs().copied();
+    sample.0 += 1;
+    assert_eq!(snapshot, (2, 1.5));
+    assert_eq!(sample, (3, 1.5));
+}
@@ -0,0 +1,60 @@
+use tuple_set::{TupleSet, define_ffi_mirror};
+
+define_ffi_mirror!(Rgb => r: u8, g: u8, b: u8);
+
+/// Tuples have no guaranteed layout, so a homogeneous tuple may or may not be
+/// viewed as a slice, but a slice must hold the elements in positional order.
+fn assert_positional<T: PartialEq + core::fmt::Debug>(slice: Option<&[T]>, expected: &[T]) {
+    if let Some(slice) = slice {
+        assert_eq!(slice, expected);
+    }
+}
+
+#[test]
+fn test_as_slice_of_homogeneous() {
+    let tuple = (1u64, 2u64, 3u64, 4u64);
+    assert_positional(tuple.as_slice_of::<u64>(), &[1, 2, 3, 4]);
+}
+
+#[test]
+fn test_as_slice_of_repr_c() {
+    let mut rgb = Rgb::new(10, 20, 30);
+    assert_eq!(rgb.as_slice_of::<u8>(), Some(&[10, 20, 30][..]));
+    rgb.as_mut_slice_of::<u8>().unwrap().reverse();
+    assert_eq!((rgb.r, rgb.g, rgb.b), (30, 20, 10));
+}
+
+#[test]
+fn test_as_slice_of_heterogeneous() {
+    assert_eq!((1u64, 2u32).as_slice_of::<u64>(), None);
+    assert_eq!((1u64, 2u64).as_slice_of::<u32>(), None);
+}
+
+#[test]
+fn test_as_mut_slice_of() {
+    let mut tuple = (5i16, -1i16, 3i16);
+    if let Some(slice) = tuple.as_mut_slice_of::<i16>() {
+        assert_eq!(slice, [5, -1, 3]);
+        slice.sort_unstable();
+        slice[0] = 0;
+        assert_eq!(tuple, (0, 3, 5));
+    } else {
+        assert_eq!(tuple, (5, -1, 3));
+    }
+}
+
+#[test]
+fn test_as_slice_of_large_tuple() {
+    let tuple = (
+        0u8, 1u8, 2u8, 3u8, 4u8, 5u8, 6u8, 7u8, 8u8, 9u8, 10u8, 11u8, 12u8, 13u8, 14u8, 15u8, 16u8,
+        17u8, 18u8, 19u8, 20u8,
+    );
+    let expected: Vec<u8> = (0..=20).collect();
+    assert_positional(tuple.as_slice_of::<u8>(), &expected);
+}
+
+#[test]
+fn test_as_slice_of_zero_sized() {
+    let tuple = ((), (), ());
+    assert_positional(tuple.as_slice_of::<()>(), &[(), (), ()]);
+}
@@ -0,0 +1,71 @@
+use tuple_set::{At, TupleFirst, TupleLast};
+
+fn first<T: At<0>>(tuple: &T) -> &T::Element {
+    tuple.at()
+}
+
+fn ends<T: TupleFirst + TupleLast>(tuple: &T) -> (&T::First, &T::Last) {
+    (tuple.first(), tuple.last())
+}
+
+#[test]
+fn test_at_with_duplicate_types() {
+    let tuple = (1u32, 2u32, 3u32);
+    assert_eq!(*At::<0>::at(&tuple), 1);
+    assert_eq!(*At::<1>::at(&tuple), 2);
+    assert_eq!(*At::<2>::at(&tuple), 3);
+}
+
+#[test]
+fn test_at_mut() {
+    let mut tuple = (1i32, "hello");
+    *At::<0>::at_mut(&mut tuple) += 41;
+    *At::<1>::at_mut(&mut tuple) = "world";
+    assert_eq!(tuple, (42, "world"));
+}
+
+#[test]
+fn test_into_at() {
+    let tuple = (1u8, String::from("owned"), 'x');
+    assert_eq!(At::<1>::into_at(tuple), "owned");
+}
+
+#[test]
+fn test_at_generic_bound() {
+    assert_eq!(*first(&(7u64,)), 7);
+    assert_eq!(*first(&('a', 1u8, 2u16)), 'a');
+}
+
+#[test]
+fn test_at_large_tuple() {
+    let tuple = (
+        0u8, 1u8, 2u8, 3u8, 4u8, 5u8, 6u8, 7u8, 8u8, 9u8, 10u8, 11u8, 12u8, 13u8, 14u8, 15u8, 16u8,
+        17u8, 18u8, 19u8, 20u8,
+    );
+    assert_eq!(*At::<20>::at(&tuple), 20);
+    assert_eq!(*At::<13>::at(&tuple), 13);
+}
+
+#[test]
+fn test_first_last_generic() {
+    assert_eq!(ends(&(1u8,)), (&1, &1));
+    assert_eq!(ends(&(1u8, "middle", 'z')), (&1, &'z'));
+}
+
+#[test]
+fn test_first_last_by_value() {
+    let tuple = (String::from("head"), 0u8, String::from("tail"));
+    assert_eq!(tuple.clone().into_first(), "head");
+    assert_eq!(tuple.into_last(), "tail");
+}
+
+#[test]
+fn test_last_mut_large_tuple() {
+    let mut tuple = (
+        0u8, 1u8, 2u8, 3u8, 4u8, 5u8, 6u8, 7u8, 8u8, 9u8, 10u8, 11u8, 12u8, 13u8, 14u8, 15u8, 16u8,
+        17u8, 18u8, 19u8, 20u8,
+    );
+    *tuple.last_mut() = 42;
+    *tuple.first_mut() = 24;
+    assert_eq!((tuple.0, tuple.20), (24, 42));
+}
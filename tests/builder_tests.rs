@@ -0,0 +1,45 @@
+#![cfg(feature = "algebra")]
+
+use tuple_set::{Position, TupleBuilder};
+
+#[test]
+fn test_builder_any_order() {
+    let built: (u8, String, bool) =
+        TupleBuilder::new().set(true).set(String::from("name")).set(1u8).build();
+    assert_eq!(built, (1, String::from("name"), true));
+}
+
+#[test]
+fn test_builder_single_field() {
+    let built: (char,) = TupleBuilder::new().set('x').build();
+    assert_eq!(built, ('x',));
+}
+
+#[test]
+fn test_builder_repeated_type_with_explicit_position() {
+    let built: (u32, u32) =
+        TupleBuilder::new().set::<u32, Position<1>>(2).set::<u32, Position<0>>(1).build();
+    assert_eq!(built, (1, 2));
+}
+
+#[test]
+fn test_builder_large_tuple() {
+    type Large = (u8, u16, u32, u64, i8, i16, i32, i64, char, bool, f32, f64, &'static str);
+    let built: Large = TupleBuilder::default()
+        .set("s")
+        .set(1.0f64)
+        .set(1.0f32)
+        .set(true)
+        .set('c')
+        .set(8i64)
+        .set(4i32)
+        .set(2i16)
+        .set(1i8)
+        .set(8u64)
+        .set(4u32)
+        .set(2u16)
+        .set(1u8)
+        .build();
+    assert_eq!(built.12, "s");
+    assert_eq!((built.0, built.3, built.9), (1, 8, true));
+}
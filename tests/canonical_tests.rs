@@ -0,0 +1,45 @@
+#![cfg(feature = "algebra")]
+
+use tuple_set::{Canonicalize, canonical_order};
+
+#[derive(Debug, PartialEq)]
+struct Host(&'static str);
+
+#[derive(Debug, PartialEq)]
+struct Port(u16);
+
+#[derive(Debug, PartialEq)]
+struct Timeout(u32);
+
+canonical_order!(after &'static str => Host, Port);
+canonical_order!(after Port => Timeout);
+
+#[test]
+fn test_canonicalize_primitives() {
+    assert_eq!((3u32, 'x', true).canonicalize(), (true, 'x', 3u32));
+    assert_eq!((1.5f64, 2i8).canonicalize(), (2i8, 1.5f64));
+    assert_eq!((7u8,).canonicalize(), (7u8,));
+    assert_eq!(().canonicalize(), ());
+}
+
+#[test]
+fn test_canonicalize_permutations_share_shape() {
+    let expected = (true, Host("localhost"), Port(80), Timeout(30));
+    assert_eq!((Timeout(30), Port(80), Host("localhost"), true).canonicalize(), expected);
+    assert_eq!((Host("localhost"), true, Timeout(30), Port(80)).canonicalize(), expected);
+    assert_eq!((Port(80), Timeout(30), true, Host("localhost")).canonicalize(), expected);
+}
+
+#[test]
+fn test_canonical_associated_type() {
+    fn normalize<T: Canonicalize>(tuple: T) -> T::Canonical {
+        tuple.canonicalize()
+    }
+
+    let canonical: <(Port, &str, u16) as Canonicalize>::Canonical =
+        normalize((Port(443), "example.com", 8u16));
+    let (port_count, name, port): (u16, &str, Port) = canonical;
+    assert_eq!(port_count, 8);
+    assert_eq!(name, "example.com");
+    assert_eq!(port, Port(443));
+}
@@ -0,0 +1,46 @@
+use tuple_set::{CodecError, TupleCodec};
+
+#[test]
+fn test_encoded_size() {
+    assert_eq!(<(u8,)>::ENCODED_SIZE, 1);
+    assert_eq!(<(u16, u32, u64, bool, char)>::ENCODED_SIZE, 19);
+    assert_eq!(<(i128, f32, f64)>::ENCODED_SIZE, 28);
+}
+
+#[test]
+fn test_roundtrip() {
+    let tuple = (0xABu8, -2i16, 3.5f32, u64::MAX, false, 'λ', -7i128);
+    let mut buffer = [0u8; 64];
+    let written = tuple.to_le_bytes(&mut buffer).unwrap();
+    assert_eq!(written, <(u8, i16, f32, u64, bool, char, i128)>::ENCODED_SIZE);
+    assert_eq!(TupleCodec::from_le_bytes(&buffer[..written]), Ok(tuple));
+}
+
+#[test]
+fn test_little_endian_layout() {
+    let mut buffer = [0u8; 6];
+    (0x0102u16, 0x03040506u32).to_le_bytes(&mut buffer).unwrap();
+    assert_eq!(buffer, [0x02, 0x01, 0x06, 0x05, 0x04, 0x03]);
+}
+
+#[test]
+fn test_buffer_too_short() {
+    let mut buffer = [0u8; 3];
+    assert_eq!(
+        (1u16, 2u16).to_le_bytes(&mut buffer),
+        Err(CodecError::BufferTooShort { required: 4, available: 3 })
+    );
+    assert_eq!(
+        <(u16, u16)>::from_le_bytes(&buffer),
+        Err(CodecError::BufferTooShort { required: 4, available: 3 })
+    );
+}
+
+#[test]
+fn test_invalid_value() {
+    assert_eq!(<(u8, bool)>::from_le_bytes(&[0, 2]), Err(CodecError::InvalidValue { position: 1 }));
+    assert_eq!(
+        <(char,)>::from_le_bytes(&0xD800u32.to_le_bytes()),
+        Err(CodecError::InvalidValue { position: 0 })
+    );
+}
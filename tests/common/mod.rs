@@ -1,5 +1,16 @@
 // Common test utilities and macros
 
+use std::{cell::Cell, rc::Rc};
+
+/// Value counting how many times it has been dropped
+pub struct Tracked<T>(pub T, pub Rc<Cell<usize>>);
+
+impl<T> Drop for Tracked<T> {
+    fn drop(&mut self) {
+        self.1.set(self.1.get() + 1);
+    }
+}
+
 /// Macro to generate tests for a tuple of specific size
 #[macro_export]
 macro_rules! gen_tuple_tests {
@@ -37,6 +48,21 @@ macro_rules! gen_tuple_tests {
                 let mut tuple = ($($v,)+);
                 unsafe { tuple.map_unchecked::<bool, _, _>(|x: &mut bool| *x = true); }
             }
+
+            #[test]
+            fn set_drops_previous() {
+                let drops = std::rc::Rc::new(std::cell::Cell::new(0));
+                let arity = [$(stringify!($v)),+].len();
+                {
+                    let mut tuple = ($($crate::common::Tracked($v, drops.clone()),)+);
+                    assert!(tuple.set($crate::common::Tracked(7i32, drops.clone())).is_none());
+                    assert_eq!(drops.get(), 1);
+                    assert_eq!(tuple.get::<$crate::common::Tracked<i32>>().unwrap().0, 7);
+                    unsafe { tuple.set_unchecked($crate::common::Tracked(8i32, drops.clone())) };
+                    assert_eq!(drops.get(), 2);
+                }
+                assert_eq!(drops.get(), arity + 2);
+            }
         }
     };
 }
@@ -0,0 +1,29 @@
+#![cfg(feature = "unstable-const")]
+#![cfg_attr(feature = "unstable-const", feature(const_trait_impl))]
+
+use tuple_set::ConstTupleSet;
+
+const CONFIG: (u16, &str, bool, u8, u8) = (8080, "localhost", true, 1, 2);
+
+#[test]
+fn test_const_count() {
+    const PORTS: usize = CONFIG.const_count::<u16>();
+    const BYTES: usize = CONFIG.const_count::<u8>();
+    const FLOATS: usize = CONFIG.const_count::<f32>();
+    assert_eq!((PORTS, BYTES, FLOATS), (1, 2, 0));
+}
+
+#[test]
+fn test_const_position() {
+    const HOST: Option<usize> = CONFIG.const_position::<&str>();
+    const BYTE: Option<usize> = CONFIG.const_position::<u8>();
+    assert_eq!(HOST, Some(1));
+    assert_eq!(BYTE, None);
+}
+
+#[test]
+fn test_const_get() {
+    const HOST: &str = CONFIG.const_get::<&str>().copied().unwrap();
+    const _: () = assert!(CONFIG.const_get::<u8>().is_none());
+    assert_eq!(HOST, "localhost");
+}
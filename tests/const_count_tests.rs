@@ -0,0 +1,65 @@
+#![cfg(feature = "unstable-const-type-id")]
+
+use tuple_set::{CountOf, DisjointFrom, TupleSet, TupleTypes};
+
+type Config = (u16, &'static str, bool, u8, u8);
+
+#[test]
+fn test_count_of_unique_duplicated_and_missing() {
+    const PORTS: usize = <Config as CountOf<u16>>::COUNT;
+    const BYTES: usize = <Config as CountOf<u8>>::COUNT;
+    const FLOATS: usize = <Config as CountOf<f32>>::COUNT;
+    assert_eq!((PORTS, BYTES, FLOATS), (1, 2, 0));
+}
+
+#[test]
+fn test_count_of_matches_runtime_count() {
+    let config: Config = (8080, "localhost", true, 1, 2);
+    assert_eq!(<Config as CountOf<u8>>::COUNT, config.count::<u8>());
+    assert_eq!(<Config as CountOf<&str>>::COUNT, config.count::<&str>());
+}
+
+#[test]
+fn test_count_of_in_const_assertion() {
+    fn port<C: TupleSet + CountOf<u16>>(config: &C) -> u16 {
+        const { assert!(C::COUNT == 1, "exactly one port expected") };
+        *config.get::<u16>().unwrap()
+    }
+
+    assert_eq!(port(&(8080u16, "localhost")), 8080);
+}
+
+// Returns the number of `u8` and `bool` elements of the type of `_tuple`
+fn counts_of<T: CountOf<u8> + CountOf<bool>>(_tuple: &T) -> (usize, usize) {
+    (<T as CountOf<u8>>::COUNT, <T as CountOf<bool>>::COUNT)
+}
+
+#[test]
+fn test_count_of_max_arity() {
+    let wide = (
+        0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+        0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+        0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+        0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, false,
+    );
+    assert_eq!(counts_of(&wide), (63, 1));
+}
+
+#[test]
+fn test_disjoint_from() {
+    const { assert!(<(u8, u16) as DisjointFrom<(u32, u64, bool)>>::DISJOINT) };
+    const { assert!(!<Config as DisjointFrom<(f32, bool)>>::DISJOINT) };
+    const { assert!(!<(u8,) as DisjointFrom<(u8,)>>::DISJOINT) };
+}
+
+#[test]
+fn test_disjoint_from_matches_runtime_check() {
+    assert_eq!(
+        <Config as DisjointFrom<(char, u8)>>::DISJOINT,
+        Config::is_disjoint_from::<(char, u8)>()
+    );
+    assert_eq!(
+        <(char, f64) as DisjointFrom<Config>>::DISJOINT,
+        <(char, f64)>::is_disjoint_from::<Config>()
+    );
+}
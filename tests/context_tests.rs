@@ -0,0 +1,59 @@
+#![cfg(feature = "std")]
+
+use std::panic;
+
+use tuple_set::{current, with_context, with_current};
+
+#[derive(Debug, Clone, PartialEq)]
+struct RequestId(u64);
+
+#[test]
+fn test_lookup_outside_context() {
+    assert_eq!(current::<u32>(), None);
+    assert_eq!(with_current(|value: &u32| *value), None);
+}
+
+#[test]
+fn test_nested_contexts() {
+    let outer = (RequestId(7), 3u32, "outer");
+    with_context(&outer, || {
+        assert_eq!(current::<RequestId>(), Some(RequestId(7)));
+        assert_eq!(with_current(|name: &&str| name.len()), Some(5));
+
+        // Types appearing multiple times are looked up in outer contexts
+        with_context(&(1u32, 2u32, "inner"), || {
+            assert_eq!(current::<&str>(), Some("inner"));
+            assert_eq!(current::<u32>(), Some(3));
+            assert_eq!(current::<RequestId>(), Some(RequestId(7)));
+        });
+
+        assert_eq!(current::<&str>(), Some("outer"));
+    });
+    assert_eq!(current::<RequestId>(), None);
+}
+
+#[test]
+fn test_context_in_lookup_closure() {
+    with_context(&(1u8,), || {
+        let nested = with_current(|outer: &u8| {
+            with_context(&(2u8,), || current::<u8>().map(|inner| inner + outer))
+        });
+        assert_eq!(nested, Some(Some(3)));
+    });
+}
+
+#[test]
+fn test_context_popped_on_panic() {
+    let result = panic::catch_unwind(|| with_context(&(5i64,), || panic!("boom")));
+    assert!(result.is_err());
+    assert_eq!(current::<i64>(), None);
+}
+
+#[test]
+fn test_contexts_are_thread_local() {
+    with_context(&(1i16,), || {
+        let other = std::thread::spawn(current::<i16>).join().unwrap();
+        assert_eq!(other, None);
+        assert_eq!(current::<i16>(), Some(1));
+    });
+}
@@ -0,0 +1,45 @@
+extern crate alloc;
+
+use alloc::string::String;
+
+use tuple_set::TupleSet;
+
+#[test]
+fn test_convert_field() {
+    let mut tuple = (7u16, 0u64, 'x');
+    assert!(tuple.convert_field::<u16, u64>());
+    assert_eq!(tuple, (7, 7, 'x'));
+}
+
+#[test]
+fn test_convert_field_clones_source() {
+    let mut tuple = ("raw", String::new());
+    assert!(tuple.convert_field::<&str, String>());
+    assert_eq!(tuple.0, "raw");
+    assert_eq!(tuple.1, "raw");
+}
+
+#[test]
+fn test_convert_field_source_not_found() {
+    let mut tuple = (7u16, 0u64);
+    assert!(!tuple.convert_field::<u8, u64>());
+    assert_eq!(tuple, (7, 0));
+}
+
+#[test]
+fn test_convert_field_destination_not_found() {
+    let mut tuple = (7u16, 0u64);
+    assert!(!tuple.convert_field::<u16, u32>());
+    assert_eq!(tuple, (7, 0));
+}
+
+#[test]
+fn test_convert_field_with_duplicates() {
+    let mut tuple = (7u16, 0u64, 1u64);
+    assert!(!tuple.convert_field::<u16, u64>());
+    assert_eq!(tuple, (7, 0, 1));
+
+    let mut tuple = (7u16, 8u16, 0u64);
+    assert!(!tuple.convert_field::<u16, u64>());
+    assert_eq!(tuple, (7, 8, 0));
+}
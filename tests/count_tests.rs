@@ -46,3 +46,19 @@ fn test_contains_unique_false_duplicates() {
     let tuple = (42i32, "hello", 100i32);
     assert!(!tuple.contains_unique::<i32>());
 }
+
+#[test]
+fn test_contains() {
+    let tuple = (42i32, "hello", 100i32);
+    assert!(tuple.contains::<i32>());
+    assert!(tuple.contains::<&str>());
+    assert!(!tuple.contains::<bool>());
+}
+
+#[test]
+fn test_contains_duplicate() {
+    let tuple = (42i32, "hello", 100i32);
+    assert!(tuple.contains_duplicate::<i32>());
+    assert!(!tuple.contains_duplicate::<&str>());
+    assert!(!tuple.contains_duplicate::<bool>());
+}
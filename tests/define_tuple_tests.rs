@@ -0,0 +1,62 @@
+use tuple_set::{DynFields, TupleSet, TupleTypes, define_tuple};
+
+define_tuple!(
+    #[derive(Debug, Clone, PartialEq)]
+    pub Config =>
+        retries: u32 [retries_mut, set_retries],
+        host: String [host_mut, set_host],
+        debug: bool [debug_mut, set_debug]
+);
+
+define_tuple!(Span => start: u32 [start_mut, set_start], end: u32 [end_mut, set_end],);
+
+fn retries_of<C: TupleSet>(config: &C) -> Option<u32> {
+    config.get::<u32>().copied()
+}
+
+#[test]
+fn test_named_accessors() {
+    let mut config = Config::new(3, String::from("localhost"), false);
+    config.set_debug(true);
+    config.set_retries(4);
+    *config.retries_mut() += 1;
+    config.host_mut().push_str(":8080");
+    assert_eq!(*config.retries(), 5);
+    assert_eq!(config.host(), "localhost:8080");
+    assert!(*config.debug());
+}
+
+#[test]
+fn test_by_type_access() {
+    let mut config = Config::from((3, String::from("localhost"), false));
+    assert_eq!(retries_of(&config), Some(3));
+    assert_eq!(config.set(5u32), None);
+    assert_eq!(config.map(|debug: &mut bool| core::mem::replace(debug, true)), Some(false));
+    assert_eq!(<Config as TupleTypes>::type_names().len(), 3);
+    let tuple: (u32, String, bool) = config.into();
+    assert_eq!(tuple, (5, String::from("localhost"), true));
+}
+
+#[test]
+fn test_repeated_types_by_name() {
+    let mut span = Span::new(1, 4);
+    span.set_end(10);
+    *span.start_mut() += 1;
+    assert_eq!((*span.start(), *span.end()), (2, 10));
+    assert_eq!(span.count::<u32>(), 2);
+    assert_eq!(span.get::<u32>(), None);
+    assert_eq!(span.0, (2, 10));
+}
+
+#[test]
+fn test_dyn_fields() {
+    let mut config = Config::new(3, String::from("localhost"), false);
+    let names: Vec<_> = config.dyn_fields().map(|(name, _)| name).collect();
+    assert_eq!(names, ["u32", "alloc::string::String", "bool"]);
+    for (_, value) in config.dyn_fields_mut() {
+        if let Some(debug) = value.downcast_mut::<bool>() {
+            *debug = true;
+        }
+    }
+    assert!(*config.debug());
+}
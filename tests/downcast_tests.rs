@@ -0,0 +1,32 @@
+#![cfg(feature = "alloc")]
+
+use std::any::Any;
+
+use tuple_set::TupleSet;
+
+type Slot = Box<dyn Any>;
+
+#[test]
+fn test_get_downcast_first_match() {
+    let tuple: (Slot, u8, Slot, Slot) = (Box::new('x'), 1, Box::new(2u32), Box::new(3u32));
+    assert_eq!(tuple.get_downcast::<u32>(), Some(&2));
+    assert_eq!(tuple.get_downcast::<char>(), Some(&'x'));
+}
+
+#[test]
+fn test_get_downcast_ignores_static_fields() {
+    let tuple: (u8, String, Slot) = (1, String::from("static"), Box::new(()));
+    assert_eq!(tuple.get_downcast::<u8>(), None);
+    assert_eq!(tuple.get_downcast::<String>(), None);
+    assert_eq!(tuple.get_downcast::<()>(), Some(&()));
+    assert_eq!((1u8, 2u16).get_downcast::<u8>(), None);
+}
+
+#[test]
+fn test_get_downcast_mut() {
+    let mut tuple: (Slot, Slot) = (Box::new(String::from("a")), Box::new(String::from("b")));
+    tuple.get_downcast_mut::<String>().unwrap().push('!');
+    assert_eq!(tuple.0.downcast_ref::<String>().unwrap(), "a!");
+    assert_eq!(tuple.1.downcast_ref::<String>().unwrap(), "b");
+    assert!(tuple.get_downcast_mut::<u8>().is_none());
+}
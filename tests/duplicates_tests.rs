@@ -0,0 +1,28 @@
+use core::any::TypeId;
+
+use tuple_set::TupleSet;
+
+#[test]
+fn test_duplicates_none() {
+    assert_eq!((1u8, "host", true).duplicates().count(), 0);
+    assert_eq!((1u8,).duplicates().count(), 0);
+}
+
+#[test]
+fn test_duplicates_reports_each_type_once() {
+    let tuple = (1u8, 'a', 2u16, 'b', 3u8, 'c', 4u8);
+    let duplicates: Vec<_> = tuple.duplicates().collect();
+    assert_eq!(duplicates, [(TypeId::of::<u8>(), "u8", 3), (TypeId::of::<char>(), "char", 3)]);
+}
+
+#[test]
+fn test_duplicates_explain_failed_lookups() {
+    let mut tuple = (String::from("a"), String::from("b"), 1u32);
+    assert!(tuple.get::<String>().is_none());
+    assert!(tuple.set(String::new()).is_some());
+
+    let (id, name, count) = tuple.duplicates().next().unwrap();
+    assert_eq!(id, TypeId::of::<String>());
+    assert!(name.ends_with("String"));
+    assert_eq!(count, tuple.count::<String>());
+}
@@ -0,0 +1,33 @@
+use core::any::Any;
+
+use tuple_set::DynFields;
+
+#[test]
+fn test_dyn_fields_order_and_names() {
+    let tuple = (1u8, "two", 3.0f64, Some('c'));
+    let names: Vec<_> = tuple.dyn_fields().map(|(name, _)| name).collect();
+    assert_eq!(names, ["u8", "&str", "f64", "core::option::Option<char>"]);
+    assert_eq!(tuple.dyn_fields().len(), 4);
+}
+
+#[test]
+fn test_dyn_fields_downcast() {
+    let tuple = (1u8, String::from("two"));
+    let values: Vec<&dyn Any> = tuple.dyn_fields().map(|(_, value)| value).collect();
+    assert_eq!(values[0].downcast_ref::<u8>(), Some(&1));
+    assert_eq!(values[1].downcast_ref::<String>().map(String::as_str), Some("two"));
+    assert!(values[1].downcast_ref::<u8>().is_none());
+}
+
+#[test]
+fn test_dyn_fields_mut_edits_in_place() {
+    let mut tuple = (1u32, 2u32, false);
+    for (name, value) in tuple.dyn_fields_mut() {
+        if name == "u32" {
+            *value.downcast_mut::<u32>().unwrap() *= 10;
+        } else if let Some(flag) = value.downcast_mut::<bool>() {
+            *flag = true;
+        }
+    }
+    assert_eq!(tuple, (10, 20, true));
+}
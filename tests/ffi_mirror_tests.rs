@@ -0,0 +1,61 @@
+use core::mem::{align_of, offset_of, size_of};
+
+use tuple_set::{TupleSet, TupleTypes, define_ffi_mirror};
+
+define_ffi_mirror!(
+    #[derive(Debug, Clone, PartialEq)]
+    pub Config => retries: u32, timeout: u64, debug: bool
+);
+
+define_ffi_mirror!(Rgb => r: u8, g: u8, b: u8,);
+
+/// Hand-written equivalent of `Config`, laid out by the compiler.
+#[repr(C)]
+struct HandWrittenConfig {
+    retries: u32,
+    timeout: u64,
+    debug: bool,
+}
+
+#[test]
+fn test_c_layout() {
+    assert_eq!(offset_of!(Config, retries), offset_of!(HandWrittenConfig, retries));
+    assert_eq!(offset_of!(Config, timeout), offset_of!(HandWrittenConfig, timeout));
+    assert_eq!(offset_of!(Config, debug), offset_of!(HandWrittenConfig, debug));
+    assert_eq!(size_of::<Config>(), size_of::<HandWrittenConfig>());
+    assert_eq!(align_of::<Config>(), align_of::<HandWrittenConfig>());
+    assert_eq!((offset_of!(Rgb, r), offset_of!(Rgb, g), offset_of!(Rgb, b)), (0, 1, 2));
+}
+
+#[test]
+fn test_tuple_conversions() {
+    let config = Config::from((3, 30, false));
+    assert_eq!(config, Config { retries: 3, timeout: 30, debug: false });
+    let tuple: (u32, u64, bool) = config.into();
+    assert_eq!(tuple, (3, 30, false));
+}
+
+#[test]
+fn test_by_type_access() {
+    let mut config = Config::new(3, 30, false);
+    assert_eq!(config.arity(), 3);
+    assert_eq!(config.set(5u32), None);
+    assert_eq!(config.map(|timeout: &mut u64| core::mem::replace(timeout, 60)), Some(30));
+    assert_eq!(config.get::<bool>(), Some(&false));
+    assert_eq!(config.get::<i8>(), None);
+    assert_eq!(config, Config::new(5, 60, false));
+    assert_eq!(<Config as TupleTypes>::type_names(), ["u32", "u64", "bool"]);
+
+    let migrated: (bool, u32) = config.migrate();
+    assert_eq!(migrated, (false, 5));
+}
+
+#[test]
+fn test_repeated_types() {
+    let mut rgb = Rgb::new(1, 2, 3);
+    assert_eq!(rgb.count::<u8>(), 3);
+    assert_eq!(rgb.get::<u8>(), None);
+    assert_eq!(rgb.as_slice_of::<u8>(), Some(&[1, 2, 3][..]));
+    rgb.for_each_of_mut(|channel: &mut u8| *channel *= 2);
+    assert_eq!((rgb.r, rgb.g, rgb.b), (2, 4, 6));
+}
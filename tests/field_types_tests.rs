@@ -0,0 +1,36 @@
+use tuple_set::{FieldTypes, field_types};
+
+const SHAPE: FieldTypes<4> = field_types!((u32, Vec<u8>, Option<&'static str>, u32));
+
+#[test]
+fn test_field_types_descriptors() {
+    assert_eq!(
+        SHAPE.fields(),
+        &[(0, "u32"), (1, "Vec<u8>"), (2, "Option<&'static str>"), (3, "u32")]
+    );
+    assert_eq!(SHAPE.len(), 4);
+    assert!(!SHAPE.is_empty());
+}
+
+#[test]
+fn test_field_types_lookups() {
+    assert_eq!(SHAPE.type_name(1), Some("Vec<u8>"));
+    assert_eq!(SHAPE.type_name(4), None);
+    // The first match is returned for repeated types
+    assert_eq!(SHAPE.position("u32"), Some(0));
+    assert_eq!(SHAPE.position("u3"), None);
+    assert_eq!(SHAPE.position("U32"), None);
+}
+
+#[test]
+fn test_field_types_in_const() {
+    const POSITION: Option<usize> = SHAPE.position("Vec<u8>");
+    const _: () = assert!(field_types!((bool,)).len() == 1);
+    assert_eq!(POSITION, Some(1));
+}
+
+#[test]
+fn test_field_types_trailing_comma() {
+    assert_eq!(field_types!((char,)).fields(), &[(0, "char")]);
+    assert_eq!(field_types!((i8, i16,)).type_name(1), Some("i16"));
+}
@@ -0,0 +1,33 @@
+use tuple_set::TupleSet;
+
+#[test]
+fn test_fill_all_occurrences() {
+    let mut tuple = (0u16, 'x', 0u16, 0u16);
+    assert_eq!(tuple.fill_from_iter(1u16..), 3);
+    assert_eq!(tuple, (1, 'x', 2, 3));
+}
+
+#[test]
+fn test_fill_stops_when_exhausted() {
+    let mut tuple = (0i32, 0i32, 0i32);
+    assert_eq!(tuple.fill_from_iter(vec![7]), 1);
+    assert_eq!(tuple, (7, 0, 0));
+    assert_eq!(tuple.fill_from_iter(core::iter::empty::<i32>()), 0);
+    assert_eq!(tuple, (7, 0, 0));
+}
+
+#[test]
+fn test_fill_missing_type() {
+    let mut tuple = (1u8, "hello");
+    let mut source = [1.0f64, 2.0].into_iter();
+    assert_eq!(tuple.fill_from_iter(source.by_ref()), 0);
+    // Items are only consumed when a field is filled
+    assert_eq!(source.len(), 2);
+}
+
+#[test]
+fn test_fill_drops_replaced_values() {
+    let mut tuple = (String::from("old"), 3u8, String::from("older"));
+    assert_eq!(tuple.fill_from_iter(["a", "b", "c"].map(String::from)), 2);
+    assert_eq!(tuple, (String::from("a"), 3, String::from("b")));
+}
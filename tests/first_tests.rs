@@ -0,0 +1,37 @@
+use tuple_set::TupleSet;
+
+#[test]
+fn test_get_first() {
+    let tuple = ('a', 1u8, 'b', 2u8, 'c');
+    assert_eq!(tuple.get_first::<char>(), Some(&'a'));
+    assert_eq!(tuple.get_first::<u8>(), Some(&1));
+    assert_eq!(tuple.get_first::<u16>(), None);
+}
+
+#[test]
+fn test_get_first_unique() {
+    let tuple = (42i32, "hello");
+    assert_eq!(tuple.get_first::<i32>(), tuple.get::<i32>());
+}
+
+#[test]
+fn test_set_first() {
+    let mut tuple = (String::from("a"), 1u8, String::from("b"));
+    assert!(tuple.set_first(String::from("z")).is_none());
+    assert_eq!(tuple, (String::from("z"), 1, String::from("b")));
+    assert_eq!(tuple.set_first(2u16), Some(2));
+}
+
+#[test]
+fn test_map_first() {
+    let mut tuple = (1i64, 2i64, 3i64);
+    assert_eq!(
+        tuple.map_first(|value: &mut i64| {
+            *value *= 10;
+            *value
+        }),
+        Some(10)
+    );
+    assert_eq!(tuple, (10, 2, 3));
+    assert_eq!(tuple.map_first(|value: &mut bool| *value = true), None);
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "algebra")]
+
+use tuple_set::{FlattenAll, Tagged, TupleSet, flatten_leaf};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Port(u16);
+
+flatten_leaf!(Port);
+
+struct Primary;
+
+#[test]
+fn test_flatten_already_flat() {
+    assert_eq!(().flatten_all(), ());
+    assert_eq!((1u8,).flatten_all(), (1u8,));
+    assert_eq!((1u8, 'a', true).flatten_all(), (1u8, 'a', true));
+}
+
+#[test]
+fn test_flatten_nested() {
+    assert_eq!(((1u8, 2u16), 3u32).flatten_all(), (1u8, 2u16, 3u32));
+    assert_eq!((1u8, (2u16, (3u32, (4u64,)))).flatten_all(), (1u8, 2u16, 3u32, 4u64));
+    assert_eq!((((((1u8,),),),),).flatten_all(), (1u8,));
+}
+
+#[test]
+fn test_flatten_skips_empty_tuples() {
+    assert_eq!(((), 1u8, ((), ((),)), 2u16).flatten_all(), (1u8, 2u16));
+    assert_eq!(((), ((),)).flatten_all(), ());
+}
+
+#[test]
+fn test_flatten_leaves() {
+    let name = String::from("node");
+    let flat = ((&name, Some(3u8)), ([1u8, 2], (Port(80),))).flatten_all();
+    assert_eq!(flat, (&name, Some(3u8), [1u8, 2], Port(80)));
+
+    let tagged = (Tagged::<Primary, _>::new(1u32), (2u32,)).flatten_all();
+    assert_eq!(tagged.0.into_inner(), 1);
+    assert_eq!(tagged.1, 2);
+}
+
+#[test]
+fn test_flatten_then_lookup() {
+    let mut flat = ((Port(443), "example.org"), ((30u64,), false)).flatten_all();
+    assert_eq!(flat.get::<Port>(), Some(&Port(443)));
+    assert_eq!(flat.get::<u64>(), Some(&30));
+    flat.map(|value: &mut bool| *value = true);
+    assert!(flat.3);
+}
@@ -0,0 +1,37 @@
+use tuple_set::TupleSet;
+
+#[test]
+fn test_fold_of_sums_every_occurrence() {
+    let tuple = (1u64, "a", 2u64, 'b', 3u64);
+    assert_eq!(tuple.fold_of(0, |sum, value: &u64| sum + value), 6);
+}
+
+#[test]
+fn test_fold_of_in_positional_order() {
+    let tuple = ('a', 0u8, 'b', 'c');
+    let mut word = [' '; 3];
+    let len = tuple.fold_of(0, |index, value: &char| {
+        word[index] = *value;
+        index + 1
+    });
+    assert_eq!(len, 3);
+    assert_eq!(word, ['a', 'b', 'c']);
+}
+
+#[test]
+fn test_fold_of_missing_returns_init() {
+    let tuple = (42i32, "hello");
+    assert_eq!(tuple.fold_of(7usize, |_, _: &bool| unreachable!()), 7);
+}
+
+#[test]
+fn test_fold_of_max_arity() {
+    let tuple = (
+        1u32, 1u32, 1u32, 1u32, 1u32, 1u32, 1u32, 1u32, 1u32, 1u32, 1u32, 1u32, 1u32, 1u32, 1u32,
+        1u32, 1u32, 1u32, 1u32, 1u32, 1u32, 1u32, 1u32, 1u32, 1u32, 1u32, 1u32, 1u32, 1u32, 1u32,
+        1u32, 1u32, 1u32, 1u32, 1u32, 1u32, 1u32, 1u32, 1u32, 1u32, 1u32, 1u32, 1u32, 1u32, 1u32,
+        1u32, 1u32, 1u32, 1u32, 1u32, 1u32, 1u32, 1u32, 1u32, 1u32, 1u32, 1u32, 1u32, 1u32, 1u32,
+        1u32, 1u32, 1u32, 2u32,
+    );
+    assert_eq!(tuple.fold_of(0, |sum, value: &u32| sum + value), 65);
+}
@@ -0,0 +1,37 @@
+use tuple_set::TupleSet;
+
+#[test]
+fn test_for_each_of_positional_order() {
+    let tuple = ('a', 1u8, 'b', "skip", 'c');
+    let mut visited = String::new();
+    tuple.for_each_of(|c: &char| visited.push(*c));
+    assert_eq!(visited, "abc");
+}
+
+#[test]
+fn test_for_each_of_keeps_references() {
+    let tuple = (String::from("a"), 1u8, String::from("b"));
+    let mut refs: Vec<&String> = Vec::new();
+    tuple.for_each_of(|value| refs.push(value));
+    assert_eq!(refs, [&tuple.0, &tuple.2]);
+}
+
+#[test]
+fn test_for_each_of_mut() {
+    let mut tuple = (1i32, 2u8, 3i32, 4i32);
+    let mut refs: Vec<&mut i32> = Vec::new();
+    tuple.for_each_of_mut(|value| refs.push(value));
+    for value in refs {
+        *value = -*value;
+    }
+    assert_eq!(tuple, (-1, 2, -3, -4));
+}
+
+#[test]
+fn test_for_each_of_missing_type() {
+    let mut tuple = (1u8, 'x');
+    let mut calls = 0;
+    tuple.for_each_of(|_: &f64| calls += 1);
+    tuple.for_each_of_mut(|_: &mut f64| calls += 1);
+    assert_eq!(calls, 0);
+}
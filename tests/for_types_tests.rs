@@ -0,0 +1,36 @@
+use tuple_set::for_types;
+
+#[test]
+fn test_for_types_applies_to_each_type() {
+    let mut tuple = (1u8, 2u16, 3u32, 4u64);
+    assert_eq!(for_types!((u8, u32, u64) in tuple => |x| *x *= 10), 3);
+    assert_eq!(tuple, (10, 2, 30, 40));
+}
+
+#[test]
+fn test_for_types_skips_missing_and_duplicated() {
+    let mut tuple = (1u8, 1u8, 2u32);
+    assert_eq!(for_types!((u8, u32, bool,) in tuple => |x| *x = Default::default()), 1);
+    assert_eq!(tuple, (1, 1, 0));
+}
+
+#[test]
+fn test_for_types_through_reference() {
+    let mut owned = (String::from("a"), vec![1u8]);
+    let tuple = &mut owned;
+    let cleared = for_types!((String, Vec<u8>) in *tuple => |x| x.clear());
+    assert_eq!(cleared, 2);
+    assert!(owned.0.is_empty() && owned.1.is_empty());
+}
+
+#[test]
+fn test_for_types_captures_environment() {
+    let mut tuple = (1i32, 2i64);
+    let mut visited = 0;
+    for_types!((i32, i64) in tuple => |x| {
+        visited += 1;
+        *x = -*x;
+    });
+    assert_eq!(visited, 2);
+    assert_eq!(tuple, (-1, -2));
+}
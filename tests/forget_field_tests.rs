@@ -0,0 +1,52 @@
+extern crate alloc;
+
+use alloc::rc::Rc;
+use core::cell::Cell;
+
+use tuple_set::TupleSet;
+
+#[derive(Default)]
+struct DropCounter(Option<Rc<Cell<usize>>>);
+
+impl Drop for DropCounter {
+    fn drop(&mut self) {
+        if let Some(counter) = &self.0 {
+            counter.set(counter.get() + 1);
+        }
+    }
+}
+
+#[test]
+fn test_forget_field_does_not_drop() {
+    let drops = Rc::new(Cell::new(0));
+    {
+        let mut tuple = (42i32, DropCounter(Some(drops.clone())));
+        assert!(tuple.forget_field::<DropCounter>());
+        assert!(tuple.1.0.is_none());
+    }
+    assert_eq!(drops.get(), 0);
+}
+
+#[test]
+fn test_take_drops_when_released() {
+    let drops = Rc::new(Cell::new(0));
+    {
+        let mut tuple = (42i32, DropCounter(Some(drops.clone())));
+        let taken = tuple.take::<DropCounter>();
+        assert!(taken.is_some());
+    }
+    assert_eq!(drops.get(), 1);
+}
+
+#[test]
+fn test_forget_field_not_found() {
+    let mut tuple = (42i32, "hello");
+    assert!(!tuple.forget_field::<u8>());
+}
+
+#[test]
+fn test_forget_field_with_duplicates() {
+    let mut tuple = (42i32, 7i32);
+    assert!(!tuple.forget_field::<i32>());
+    assert_eq!(tuple, (42, 7));
+}
@@ -0,0 +1,31 @@
+use tuple_set::{Frozen, TupleSet};
+
+fn describe(context: &Frozen<&(u32, &'static str, bool)>) -> (u32, bool) {
+    (*context.get::<u32>().unwrap(), *context.get::<bool>().unwrap())
+}
+
+#[test]
+fn test_frozen_borrowed() {
+    let context = (3u32, "localhost", true);
+    let frozen = Frozen::new(&context);
+    assert_eq!(describe(&frozen), (3, true));
+    assert_eq!(frozen.count::<&str>(), 1);
+    assert!(frozen.contains_unique::<bool>());
+}
+
+#[test]
+fn test_frozen_owned() {
+    let frozen = Frozen::new((3u32, 1u32, 'x'));
+    assert_eq!(frozen.count::<u32>(), 2);
+    assert_eq!(frozen.get::<u32>(), None);
+    assert_eq!(frozen.get::<char>(), Some(&'x'));
+    assert_eq!(*frozen, (3, 1, 'x'));
+}
+
+#[test]
+fn test_frozen_is_copy_when_borrowed() {
+    let context = (3u32,);
+    let frozen = Frozen::new(&context);
+    let copy = frozen;
+    assert_eq!(frozen, copy);
+}
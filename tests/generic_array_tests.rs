@@ -0,0 +1,20 @@
+#![cfg(feature = "generic-array")]
+
+use generic_array::typenum::{U0, U1, U2, U3};
+use tuple_set::TupleSet;
+
+#[test]
+fn test_occurrences_in_positional_order() {
+    let tuple = (1u16, 'x', 2u16, 3u16);
+    let refs = tuple.occurrences::<u16, U3>().unwrap();
+    assert_eq!(refs.as_slice(), [&1, &2, &3]);
+    assert_eq!(tuple.occurrences::<char, U1>().unwrap().as_slice(), [&'x']);
+}
+
+#[test]
+fn test_occurrences_length_mismatch() {
+    let tuple = (1u16, 2u16, 3u16);
+    assert!(tuple.occurrences::<u16, U2>().is_none());
+    assert!(tuple.occurrences::<f32, U1>().is_none());
+    assert!(tuple.occurrences::<f32, U0>().unwrap().is_empty());
+}
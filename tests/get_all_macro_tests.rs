@@ -0,0 +1,27 @@
+use tuple_set::get_all;
+
+#[test]
+fn test_get_all_in_listed_order() {
+    let tuple = (1u8, 2u16, 3u32, 4u64);
+    assert_eq!(get_all!(tuple, u64, u8, u32), (Some(&4), Some(&1), Some(&3)));
+}
+
+#[test]
+fn test_get_all_missing_and_duplicated() {
+    let tuple = (1u8, 1u8, 2u32);
+    assert_eq!(get_all!(tuple, u8, u32, bool,), (None, Some(&2), None));
+}
+
+#[test]
+fn test_get_all_single_type() {
+    let tuple = (String::from("a"), vec![1u8]);
+    let (name,) = get_all!(tuple, String);
+    assert_eq!(name.map(String::as_str), Some("a"));
+}
+
+#[test]
+fn test_get_all_through_reference() {
+    let owned = (1i32, 2i64);
+    let tuple = &owned;
+    assert_eq!(get_all!(*tuple, i32, i64), (Some(&1), Some(&2)));
+}
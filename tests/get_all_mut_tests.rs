@@ -0,0 +1,29 @@
+#![cfg(feature = "alloc")]
+
+use tuple_set::TupleSet;
+
+#[test]
+fn test_get_all_mut_in_positional_order() {
+    let mut tuple = ('a', 1u8, 'b', 2u8, 'c');
+    for (index, value) in tuple.get_all_mut::<char>().into_iter().enumerate() {
+        *value = char::from(b'x' + u8::try_from(index).unwrap());
+    }
+    assert_eq!(tuple, ('x', 1, 'y', 2, 'z'));
+}
+
+#[test]
+fn test_get_all_mut_simultaneous_borrows() {
+    let mut tuple = (String::from("left"), 0u64, String::from("right"));
+    let mut strings = tuple.get_all_mut::<String>();
+    let (left, right) = strings.split_at_mut(1);
+    core::mem::swap(left[0], right[0]);
+    assert_eq!(tuple.0, "right");
+    assert_eq!(tuple.2, "left");
+}
+
+#[test]
+fn test_get_all_mut_missing() {
+    let mut tuple = (42i32, "hello");
+    assert!(tuple.get_all_mut::<bool>().is_empty());
+    assert_eq!(tuple.get_all_mut::<i32>().len(), 1);
+}
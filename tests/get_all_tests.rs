@@ -0,0 +1,25 @@
+#![cfg(feature = "alloc")]
+
+use tuple_set::TupleSet;
+
+#[test]
+fn test_get_all_in_positional_order() {
+    let tuple = ('a', 1u8, 'b', 2u8, 'c');
+    assert_eq!(tuple.get_all::<char>(), [&'a', &'b', &'c']);
+    assert_eq!(tuple.get_all::<u8>(), [&1, &2]);
+}
+
+#[test]
+fn test_get_all_unique_and_missing() {
+    let tuple = (42i32, "hello");
+    assert_eq!(tuple.get_all::<i32>(), [tuple.get::<i32>().unwrap()]);
+    assert!(tuple.get_all::<bool>().is_empty());
+}
+
+#[test]
+fn test_get_all_matches_count() {
+    let tuple = (String::from("a"), 1u64, String::from("b"));
+    let strings = tuple.get_all::<String>();
+    assert_eq!(strings.len(), tuple.count::<String>());
+    assert_eq!(strings.iter().map(|value| value.as_str()).collect::<String>(), "ab");
+}
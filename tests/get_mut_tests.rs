@@ -0,0 +1,39 @@
+use tuple_set::TupleSet;
+
+#[test]
+fn test_get_mut_from_tuple() {
+    let mut tuple = (42i32, String::from("hello"), 2.5f64);
+    tuple.get_mut::<String>().unwrap().push_str(", world");
+    *tuple.get_mut::<f64>().unwrap() *= 2.0;
+    assert_eq!(tuple, (42, String::from("hello, world"), 5.0));
+}
+
+#[test]
+fn test_get_mut_held_within_scope() {
+    let mut tuple = (vec![1u8], 'x');
+    let values = tuple.get_mut::<Vec<u8>>().unwrap();
+    values.push(2);
+    values.push(3);
+    assert_eq!(tuple.0, [1, 2, 3]);
+}
+
+#[test]
+fn test_get_mut_not_found_or_duplicated() {
+    let mut tuple = (42i32, "hello", 100i32);
+    assert!(tuple.get_mut::<bool>().is_none());
+    assert!(tuple.get_mut::<i32>().is_none());
+}
+
+#[test]
+fn test_get_mut_unchecked() {
+    let mut tuple = (42i32, "hello", 2.5f64);
+    unsafe { *tuple.get_mut_unchecked::<i32>() = 7 };
+    assert_eq!(tuple.0, 7);
+}
+
+#[test]
+#[should_panic(expected = "Type 'bool' not found in tuple (i32, &str) (arity 2, 0 occurrences)")]
+fn test_get_mut_unchecked_panic_not_found() {
+    let mut tuple = (42i32, "hello");
+    let _: &mut bool = unsafe { tuple.get_mut_unchecked() };
+}
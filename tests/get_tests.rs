@@ -43,3 +43,10 @@ fn test_get_large_tuple() {
     let value: Option<&char> = tuple.get();
     assert_eq!(value, Some(&'x'));
 }
+
+#[test]
+fn test_get_with_distant_duplicates() {
+    let tuple = (1u8, 2i32, 3i64, 4u64, 5f32, 6f64, true, 'x', 9u8);
+    assert_eq!(tuple.get::<u8>(), None);
+    assert_eq!(tuple.get::<char>(), Some(&'x'));
+}
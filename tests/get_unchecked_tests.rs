@@ -14,6 +14,22 @@ fn test_get_unchecked_panic_not_found() {
     let _: &bool = unsafe { tuple.get_unchecked() };
 }
 
+#[test]
+#[should_panic(
+    expected = "Type 'bool' not found in tuple (i32, &str, f64) (arity 3, 0 occurrences)"
+)]
+fn test_get_unchecked_panic_lists_elements() {
+    let tuple = (42i32, "hello", 2.5f64);
+    let _: &bool = unsafe { tuple.get_unchecked() };
+}
+
+#[test]
+#[should_panic(expected = "Type 'u8' not found in tuple (u16,) (arity 1, 0 occurrences)")]
+fn test_get_unchecked_panic_single_element() {
+    let tuple = (1u16,);
+    let _: &u8 = unsafe { tuple.get_unchecked() };
+}
+
 #[test]
 fn test_get_unchecked_multiple_accesses() {
     let tuple = (42i32, "test", 2.5f64);
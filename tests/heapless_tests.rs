@@ -0,0 +1,46 @@
+#![cfg(feature = "heapless")]
+
+use tuple_set::{CapacityExceeded, TupleSet};
+
+#[test]
+fn test_collect_refs_of() {
+    let tuple = (1u16, 'x', 2u16, 3u16);
+    let refs = tuple.collect_refs_of::<u16, 3>().unwrap();
+    assert_eq!(refs, [&1, &2, &3]);
+    assert!(tuple.collect_refs_of::<f32, 0>().unwrap().is_empty());
+}
+
+#[test]
+fn test_collect_refs_of_capacity_exceeded() {
+    let tuple = (1u16, 2u16, 3u16);
+    assert_eq!(
+        tuple.collect_refs_of::<u16, 2>(),
+        Err(CapacityExceeded { required: 3, capacity: 2 })
+    );
+    assert_eq!(
+        tuple.collect_cloned_of::<u16, 1>(),
+        Err(CapacityExceeded { required: 3, capacity: 1 })
+    );
+}
+
+#[test]
+fn test_collect_mut_refs_of() {
+    let mut tuple = (1u16, 'x', 2u16);
+    if let [first, second] = tuple.collect_mut_refs_of::<u16, 2>().unwrap().as_mut_slice() {
+        core::mem::swap(*first, *second);
+    }
+    assert_eq!(tuple, (2, 'x', 1));
+}
+
+#[test]
+fn test_collect_cloned_of() {
+    let tuple = (String::from("a"), 1u8, String::from("b"));
+    let values = tuple.collect_cloned_of::<String, 2>().unwrap();
+    assert_eq!(values, [String::from("a"), String::from("b")]);
+}
+
+#[test]
+fn test_capacity_exceeded_display() {
+    let error = CapacityExceeded { required: 3, capacity: 2 };
+    assert_eq!(error.to_string(), "capacity exceeded: 3 elements required, capacity is 2");
+}
@@ -0,0 +1,22 @@
+use tuple_set::TupleSet;
+
+#[test]
+fn test_index_of_unique() {
+    let tuple = (42i32, "hello", 2.5f64);
+    assert_eq!(tuple.index_of::<i32>(), Some(0));
+    assert_eq!(tuple.index_of::<&str>(), Some(1));
+    assert_eq!(tuple.index_of::<f64>(), Some(2));
+}
+
+#[test]
+fn test_index_of_duplicated_returns_first() {
+    let tuple = ('a', 1u8, 'b', 2u8);
+    assert_eq!(tuple.index_of::<u8>(), Some(1));
+    assert_eq!(tuple.index_of::<u8>(), tuple.indices_of::<u8>().next());
+}
+
+#[test]
+fn test_index_of_missing() {
+    let tuple = (42i32,);
+    assert_eq!(tuple.index_of::<bool>(), None);
+}
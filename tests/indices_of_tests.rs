@@ -0,0 +1,25 @@
+use tuple_set::TupleSet;
+
+#[test]
+fn test_indices_of_every_occurrence() {
+    let tuple = ('a', 1u8, 'b', 2u8, 'c');
+    assert_eq!(tuple.indices_of::<char>().collect::<Vec<_>>(), [0, 2, 4]);
+    assert_eq!(tuple.indices_of::<u8>().collect::<Vec<_>>(), [1, 3]);
+}
+
+#[test]
+fn test_indices_of_unique_and_missing() {
+    let tuple = (42i32, "hello");
+    assert_eq!(tuple.indices_of::<&str>().collect::<Vec<_>>(), [1]);
+    assert_eq!(tuple.indices_of::<bool>().next(), None);
+}
+
+#[test]
+fn test_indices_of_matches_count() {
+    let tuple = (
+        0u16, 1u32, 2u16, 3u64, 4u16, 5u32, 6u16, 7u64, 8u16, 9u32, 10u16, 11u64, 12u16, 13u32,
+        14u16, 15u64,
+    );
+    assert_eq!(tuple.indices_of::<u16>().count(), tuple.count::<u16>());
+    assert_eq!(tuple.indices_of::<u64>().collect::<Vec<_>>(), [3, 7, 11, 15]);
+}
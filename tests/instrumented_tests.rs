@@ -0,0 +1,58 @@
+#![cfg(feature = "alloc")]
+
+extern crate alloc;
+
+use alloc::string::ToString;
+
+use tuple_set::{FieldAccess, Instrumented};
+
+#[test]
+fn test_counts_reads_and_writes() {
+    let mut context = Instrumented::new((3u32, "localhost", true));
+    assert_eq!(context.get::<u32>(), Some(&3));
+    assert_eq!(context.get::<bool>(), Some(&true));
+    assert_eq!(context.get::<bool>(), Some(&true));
+    assert!(context.set(4u32).is_none());
+    assert_eq!(context.map(|flag: &mut bool| !*flag), Some(false));
+
+    assert_eq!(
+        context.summary(),
+        [
+            FieldAccess { position: 0, type_name: "u32", reads: 1, writes: 1 },
+            FieldAccess { position: 1, type_name: "&str", reads: 0, writes: 0 },
+            FieldAccess { position: 2, type_name: "bool", reads: 2, writes: 1 },
+        ]
+    );
+}
+
+#[test]
+fn test_failed_lookups_are_not_counted() {
+    let mut context = Instrumented::new((1u32, 2u32, 'x'));
+    assert_eq!(context.get::<u32>(), None);
+    assert_eq!(context.set(3u32), Some(3));
+    assert_eq!(context.map(|_: &mut u8| ()), None);
+    assert!(context.summary().iter().all(|access| access.reads == 0 && access.writes == 0));
+    assert_eq!(context.into_inner(), (1, 2, 'x'));
+}
+
+#[test]
+fn test_reset() {
+    let mut context = Instrumented::new((3u32,));
+    context.get::<u32>();
+    context.set(4u32);
+    context.reset();
+    assert_eq!(
+        context.summary()[0],
+        FieldAccess { position: 0, type_name: "u32", reads: 0, writes: 0 }
+    );
+}
+
+#[test]
+fn test_display_summary() {
+    let context = Instrumented::new((3u32, 'x'));
+    context.get::<char>();
+    assert_eq!(
+        context.to_string(),
+        "0: u32 (reads: 0, writes: 0)\n1: char (reads: 1, writes: 0)\n"
+    );
+}
@@ -0,0 +1,45 @@
+use tuple_set::{TupleSet, define_tuple};
+
+#[test]
+fn test_iter_of_mut_every_occurrence() {
+    let mut tuple = (1u32, 'x', 2u32, 3u32);
+    tuple.iter_of_mut::<u32>().for_each(|value| *value *= 10);
+    assert_eq!(tuple, (10, 'x', 20, 30));
+    assert_eq!(tuple.iter_of_mut::<char>().count(), 1);
+    assert_eq!(tuple.iter_of_mut::<bool>().count(), 0);
+}
+
+#[test]
+fn test_iter_of_mut_simultaneous_borrows() {
+    let mut tuple = (String::from("a"), 1u8, String::from("b"));
+    let mut strings: Vec<&mut String> = tuple.iter_of_mut().collect();
+    let (first, second) = strings.split_at_mut(1);
+    core::mem::swap(first[0], second[0]);
+    assert_eq!(tuple, (String::from("b"), 1, String::from("a")));
+}
+
+#[test]
+fn test_iter_of_mut_wide_tuple() {
+    let mut stats = (
+        0u32, 0u32, 0u32, 0u32, 0u32, 0u32, 0u32, 0u32, 0u32, 0u32, 0u32, 0u32, 0u32, 0u32, 0u32,
+        0u32, 0u32, 0u32, 0u32, 0u32, 0u32, 0u32, 0u32, 0u32, 0u32, 0u32, 0u32, 0u32, 0u32, 0u32,
+        0u32, 0u32,
+    );
+    let bumped = stats.iter_of_mut::<u32>().map(|counter| *counter += 1).count();
+    assert_eq!(bumped, 32);
+    assert_eq!((stats.0, stats.31), (1, 1));
+}
+
+define_tuple!(
+    Counters =>
+        hits: u64 [hits_mut, set_hits],
+        misses: u64 [misses_mut, set_misses],
+        name: &'static str [name_mut, set_name],
+);
+
+#[test]
+fn test_iter_of_mut_defined_tuple() {
+    let mut counters = Counters::new(1, 2, "cache");
+    counters.iter_of_mut::<u64>().for_each(|value| *value = 0);
+    assert_eq!((*counters.hits(), *counters.misses()), (0, 0));
+}
@@ -0,0 +1,38 @@
+use tuple_set::TupleSet;
+
+#[test]
+fn test_get_last() {
+    let tuple = ('a', 1u8, 'b', 2u8, 'c');
+    assert_eq!(tuple.get_last::<char>(), Some(&'c'));
+    assert_eq!(tuple.get_last::<u8>(), Some(&2));
+    assert_eq!(tuple.get_last::<u16>(), None);
+}
+
+#[test]
+fn test_get_last_unique() {
+    let tuple = (42i32, "hello");
+    assert_eq!(tuple.get_last::<i32>(), tuple.get::<i32>());
+    assert_eq!(tuple.get_last::<i32>(), tuple.get_first::<i32>());
+}
+
+#[test]
+fn test_set_last() {
+    let mut tuple = (String::from("a"), 1u8, String::from("b"));
+    assert!(tuple.set_last(String::from("z")).is_none());
+    assert_eq!(tuple, (String::from("a"), 1, String::from("z")));
+    assert_eq!(tuple.set_last(2u16), Some(2));
+}
+
+#[test]
+fn test_map_last() {
+    let mut tuple = (1i64, 2i64, 3i64);
+    assert_eq!(
+        tuple.map_last(|value: &mut i64| {
+            *value *= 10;
+            *value
+        }),
+        Some(30)
+    );
+    assert_eq!(tuple, (1, 2, 30));
+    assert_eq!(tuple.map_last(|value: &mut bool| *value = true), None);
+}
@@ -0,0 +1,28 @@
+use tuple_set::TupleLayout;
+
+#[test]
+fn test_field_sizes() {
+    assert_eq!(<(u8, u16, u32, u64)>::field_sizes(), [1, 2, 4, 8]);
+    assert_eq!(<(u8,)>::field_sizes(), [1]);
+    assert_eq!(<((), [u8; 3], bool)>::field_sizes(), [0, 3, 1]);
+}
+
+#[test]
+fn test_field_align() {
+    assert_eq!(<(u8, u16, u32, u64)>::field_align(), [1, 2, 4, 8]);
+    assert_eq!(<([u32; 4], u8)>::field_align(), [4, 1]);
+}
+
+#[test]
+fn test_field_sizes_buffer_length() {
+    let total: usize = <(u32, u8, i64)>::field_sizes().as_ref().iter().sum();
+    assert_eq!(total, 13);
+}
+
+#[test]
+fn test_field_sizes_large_tuple() {
+    type Large = (u8, u16, u32, u64, i8, i16, i32, i64, f32, f64, bool, char);
+    let sizes = Large::field_sizes();
+    assert_eq!(sizes, [1, 2, 4, 8, 1, 2, 4, 8, 4, 8, 1, 4]);
+    assert_eq!(Large::field_align()[3], core::mem::align_of::<u64>());
+}
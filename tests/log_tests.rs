@@ -0,0 +1,96 @@
+#![cfg(feature = "log")]
+
+use std::sync::{Mutex, Once};
+
+use tuple_set::TupleSet;
+
+struct Recorder;
+
+static RECORDS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+impl log::Log for Recorder {
+    fn enabled(&self, metadata: &log::Metadata<'_>) -> bool {
+        metadata.level() <= log::Level::Warn
+    }
+
+    fn log(&self, record: &log::Record<'_>) {
+        RECORDS.lock().unwrap().push(record.args().to_string());
+    }
+
+    fn flush(&self) {}
+}
+
+/// Installs the recorder and returns the warnings mentioning `needle`, as
+/// tests run concurrently and share the global logger.
+fn warnings_about(needle: &str) -> Vec<String> {
+    static INIT: Once = Once::new();
+    INIT.call_once(|| {
+        log::set_logger(&Recorder).unwrap();
+        log::set_max_level(log::LevelFilter::Warn);
+    });
+    RECORDS.lock().unwrap().iter().filter(|message| message.contains(needle)).cloned().collect()
+}
+
+#[derive(Debug, PartialEq)]
+struct Missing;
+
+#[derive(Debug, PartialEq)]
+struct Duplicated;
+
+#[derive(Debug, PartialEq)]
+struct Present;
+
+#[test]
+fn test_missing_type_is_reported() {
+    warnings_about("");
+    let tuple = (1u8, "hello", 2.5f64);
+    assert!(tuple.get::<Missing>().is_none());
+
+    let warnings = warnings_about("Missing");
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("(arity 3)"));
+    assert!(warnings[0].contains("found 0 occurrences"));
+}
+
+#[test]
+fn test_duplicated_type_is_reported() {
+    warnings_about("");
+    let mut tuple = (Duplicated, 1u8, Duplicated, 'x');
+    assert_eq!(tuple.set(Duplicated), Some(Duplicated));
+
+    let warnings = warnings_about("Duplicated");
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("(arity 4)"));
+    assert!(warnings[0].contains("found 2 occurrences"));
+}
+
+#[test]
+fn test_successful_lookup_is_silent() {
+    warnings_about("");
+    let mut tuple = (Present, 1u8);
+    assert_eq!(tuple.get::<Present>(), Some(&Present));
+    assert!(tuple.map(|_: &mut Present| ()).is_some());
+
+    assert!(warnings_about("Present").is_empty());
+}
+
+#[derive(Debug, PartialEq)]
+struct Converted;
+
+impl From<u8> for Converted {
+    fn from(_: u8) -> Self {
+        Self
+    }
+}
+
+#[test]
+fn test_provided_lookup_is_reported_without_arity() {
+    warnings_about("");
+    let mut tuple = (1u8, 'x');
+    assert!(!tuple.convert_field::<u8, Converted>());
+
+    let warnings = warnings_about("Converted");
+    assert_eq!(warnings.len(), 1);
+    assert!(!warnings[0].contains("arity"));
+    assert!(warnings[0].contains("found 0 occurrences"));
+}
@@ -0,0 +1,30 @@
+use tuple_set::TupleSet;
+
+#[test]
+fn test_map_all_applies_to_every_occurrence() {
+    let mut tuple = (1u64, "a", 2u64, "b", 3u64);
+    assert_eq!(tuple.map_all(|value: &mut u64| *value += 1), 3);
+    assert_eq!(tuple, (2, "a", 3, "b", 4));
+}
+
+#[test]
+fn test_map_all_in_positional_order() {
+    let mut tuple = ('a', 0u8, 'b', 'c');
+    let mut seen = [' '; 3];
+    let mut index = 0;
+    tuple.map_all(|value: &mut char| {
+        seen[index] = *value;
+        *value = value.to_ascii_uppercase();
+        index += 1;
+    });
+    assert_eq!(seen, ['a', 'b', 'c']);
+    assert_eq!(tuple, ('A', 0, 'B', 'C'));
+}
+
+#[test]
+fn test_map_all_missing() {
+    let mut tuple = (42i32, "hello");
+    let mut called = false;
+    assert_eq!(tuple.map_all(|_: &mut bool| called = true), 0);
+    assert!(!called);
+}
@@ -0,0 +1,53 @@
+use core::{
+    future::Future,
+    pin::pin,
+    task::{Context, Poll, Waker},
+};
+
+use tuple_set::TupleSet;
+
+/// Polls `future` to completion, without an executor.
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut context = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+/// Future pending once before completing.
+async fn yield_now() {
+    let mut yielded = false;
+    core::future::poll_fn(|_| {
+        if yielded {
+            Poll::Ready(())
+        } else {
+            yielded = true;
+            Poll::Pending
+        }
+    })
+    .await;
+}
+
+#[test]
+fn test_map_async_across_await_points() {
+    let mut tuple = (1u32, "hello", 2.5f64);
+    let result = block_on(tuple.map_async(async |x: &mut f64| {
+        *x *= 2.0;
+        yield_now().await;
+        *x += 1.0;
+        *x
+    }));
+    assert_eq!(result, Some(6.0));
+    assert_eq!(tuple, (1, "hello", 6.0));
+}
+
+#[test]
+fn test_map_async_not_found_or_duplicated() {
+    let mut tuple = (1u8, 2u8, true);
+    assert_eq!(block_on(tuple.map_async(async |x: &mut u8| *x += 1)), None);
+    assert_eq!(block_on(tuple.map_async(async |x: &mut i64| *x)), None);
+    assert_eq!(tuple, (1, 2, true));
+}
@@ -68,3 +68,12 @@ fn test_map_preserves_other_values() {
     assert_eq!(tuple.1, "hello");
     assert_eq!(tuple.2, 2.5);
 }
+
+#[test]
+fn test_map_skips_closure_when_duplicates() {
+    let mut tuple = (1u8, 2i32, 3i64, 4u64, 5f32, 6f64, true, 'x', 9u8);
+    let mut called = false;
+    assert_eq!(tuple.map(|_: &mut u8| called = true), None);
+    assert!(!called);
+    assert_eq!(tuple, (1, 2, 3, 4, 5.0, 6.0, true, 'x', 9));
+}
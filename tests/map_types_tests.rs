@@ -0,0 +1,40 @@
+extern crate alloc;
+
+use alloc::string::String;
+use core::num::TryFromIntError;
+
+use tuple_set::{MapTypes, TryMapTypes};
+
+#[test]
+fn test_map_types() {
+    let upgraded: (u64, String, f64) = (7u32, "hello", 2.5f32).map_types();
+    assert_eq!(upgraded, (7, String::from("hello"), 2.5));
+}
+
+#[test]
+fn test_map_types_identity() {
+    let same: (i32, bool) = (42i32, true).map_types();
+    assert_eq!(same, (42, true));
+}
+
+#[test]
+fn test_try_map_types_success() {
+    let narrowed: Result<(u8, u16, i8), TryFromIntError> = (1u64, 2i32, -3i64).try_map_types();
+    assert_eq!(narrowed, Ok((1, 2, -3)));
+}
+
+#[test]
+fn test_try_map_types_failure() {
+    let narrowed: Result<(u8, u16), TryFromIntError> = (1u64, 70_000i32).try_map_types();
+    assert!(narrowed.is_err());
+}
+
+#[test]
+fn test_map_types_large_tuple() {
+    type Wide = (u32, u32, u32, u32, u32, u32, u32, u32, u32, u32, u32, u32, u64);
+
+    let source = (1u8, 2u8, 3u8, 4u8, 5u8, 6u8, 7u8, 8u8, 9u8, 10u8, 11u8, 12u8, 13u8);
+    let target: Wide = source.map_types();
+    assert_eq!(target.0, 1);
+    assert_eq!(target.12, 13);
+}
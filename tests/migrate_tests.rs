@@ -0,0 +1,37 @@
+extern crate alloc;
+
+use alloc::{string::String, vec, vec::Vec};
+
+use tuple_set::TupleSet;
+
+#[test]
+fn test_migrate_to_superset() {
+    let v1 = (3u32, String::from("localhost"));
+    let v2: (String, bool, u32, Vec<u8>) = v1.migrate();
+    assert_eq!(v2, (String::from("localhost"), false, 3, Vec::new()));
+}
+
+#[test]
+fn test_migrate_reorders() {
+    let tuple = (1u8, 'x', vec![1, 2]);
+    let reordered: (Vec<i32>, char, u8) = tuple.migrate();
+    assert_eq!(reordered, (vec![1, 2], 'x', 1));
+}
+
+#[test]
+fn test_migrate_drops_missing_fields() {
+    let tuple = (1u8, 2.5f64);
+    let narrowed: (u8, bool) = tuple.migrate();
+    assert_eq!(narrowed, (1, false));
+}
+
+#[test]
+fn test_migrate_skips_duplicates() {
+    let tuple = (1u8, 2u8, 'x');
+    let migrated: (u8, char) = tuple.migrate();
+    assert_eq!(migrated, (0, 'x'));
+
+    let tuple = (1u8, 'x');
+    let migrated: (u8, u8, char) = tuple.migrate();
+    assert_eq!(migrated, (0, 0, 'x'));
+}
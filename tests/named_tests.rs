@@ -0,0 +1,41 @@
+#![cfg(feature = "unstable-named")]
+
+use tuple_set::{Named, TupleSet};
+
+type Timeouts = (Named<"connect", u64>, Named<"read", u64>, bool);
+
+fn timeouts() -> Timeouts {
+    (Named::new(5), Named::new(30), true)
+}
+
+#[test]
+fn test_get_named_disambiguates_duplicates() {
+    let timeouts = timeouts();
+    assert_eq!(timeouts.get_named::<"connect", u64>(), Some(&5));
+    assert_eq!(timeouts.get_named::<"read", u64>(), Some(&30));
+    assert_eq!(timeouts.get_named::<"read", u32>(), None);
+    assert_eq!(timeouts.get::<bool>(), Some(&true));
+}
+
+#[test]
+fn test_set_and_map_named() {
+    let mut timeouts = timeouts();
+    assert_eq!(timeouts.set_named::<"read", u64>(60), None);
+    assert_eq!(timeouts.map_named::<"connect", u64, _, _>(|timeout| *timeout + 1), Some(6));
+    assert_eq!(timeouts.set_named::<"write", u64>(1), Some(1));
+    assert_eq!((*timeouts.0, *timeouts.1), (5, 60));
+}
+
+#[test]
+fn test_duplicate_names_are_ambiguous() {
+    let tuple = (Named::<"key", u8>::new(1), Named::<"key", u8>::new(2));
+    assert_eq!(tuple.get_named::<"key", u8>(), None);
+}
+
+#[test]
+fn test_named_wrapper() {
+    assert_eq!(Named::<"label", String>::NAME, "label");
+    let mut named = Named::<"label", String>::from(String::from("ab"));
+    named.push('c');
+    assert_eq!(named.into_inner(), "abc");
+}
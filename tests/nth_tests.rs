@@ -0,0 +1,38 @@
+use tuple_set::TupleSet;
+
+#[test]
+fn test_get_nth() {
+    let tuple = ('a', 1u8, 'b', 2u8, 'c');
+    assert_eq!(tuple.get_nth::<char>(0), Some(&'a'));
+    assert_eq!(tuple.get_nth::<char>(1), Some(&'b'));
+    assert_eq!(tuple.get_nth::<char>(2), Some(&'c'));
+    assert_eq!(tuple.get_nth::<char>(3), None);
+    assert_eq!(tuple.get_nth::<u16>(0), None);
+}
+
+#[test]
+fn test_get_nth_matches_first_and_last() {
+    let tuple = (1u8, 2u8, 3u8);
+    assert_eq!(tuple.get_nth::<u8>(0), tuple.get_first::<u8>());
+    assert_eq!(tuple.get_nth::<u8>(2), tuple.get_last::<u8>());
+}
+
+#[test]
+fn test_set_nth() {
+    let mut tuple = (String::from("a"), 1u8, String::from("b"), String::from("c"));
+    assert!(tuple.set_nth(1, String::from("z")).is_none());
+    assert_eq!(tuple.2, "z");
+    assert_eq!((tuple.0.as_str(), tuple.3.as_str()), ("a", "c"));
+    assert_eq!(tuple.set_nth(1, 2u8), Some(2));
+    assert_eq!(tuple.set_nth(0, 3u16), Some(3));
+}
+
+#[test]
+fn test_map_nth_each_occurrence() {
+    let mut tuple = (1i64, 'x', 2i64, 3i64);
+    for n in 0..3 {
+        assert_eq!(tuple.map_nth(n, |value: &mut i64| *value *= 10), Some(()));
+    }
+    assert_eq!(tuple, (10, 'x', 20, 30));
+    assert_eq!(tuple.map_nth(3, |value: &mut i64| *value), None);
+}
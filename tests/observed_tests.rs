@@ -0,0 +1,51 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use tuple_set::ObservedTuple;
+
+#[test]
+fn test_observed_set_reports_old_and_new() {
+    let mut changes = Vec::new();
+    let mut context = ObservedTuple::new((3u32, 'a'), |mutation| {
+        let old = mutation.old.and_then(|old| old.downcast_ref::<char>()).copied();
+        let new = mutation.new.downcast_ref::<char>().copied();
+        changes.push((mutation.position, mutation.type_name, old, new));
+    });
+    assert!(context.set('b').is_none());
+    assert_eq!(context.into_inner(), (3, 'b'));
+    assert_eq!(changes, [(1, "char", Some('a'), Some('b'))]);
+}
+
+#[test]
+fn test_observed_map_reports_new() {
+    let mut changes = Vec::new();
+    let mut context = ObservedTuple::new((3u32, 'a'), |mutation| {
+        assert!(mutation.old.is_none());
+        changes.push(*mutation.new.downcast_ref::<u32>().unwrap());
+    });
+    assert_eq!(context.map(|x: &mut u32| *x * 2), Some(6));
+    assert_eq!(context.map(|x: &mut u32| *x += 1), Some(()));
+    assert_eq!(context.into_inner(), (4, 'a'));
+    assert_eq!(changes, [3, 4]);
+}
+
+#[test]
+fn test_observed_failures_do_not_notify() {
+    let mut calls = 0;
+    let mut context = ObservedTuple::new((1u32, 2u32, 'a'), |_| calls += 1);
+    assert_eq!(context.set(5u32), Some(5));
+    assert_eq!(context.set(true), Some(true));
+    assert_eq!(context.map(|x: &mut u32| *x), None);
+    assert_eq!(context.into_inner(), (1, 2, 'a'));
+    assert_eq!(calls, 0);
+}
+
+#[test]
+fn test_observed_read_access() {
+    use tuple_set::TupleSet;
+
+    let context = ObservedTuple::new((3u32, "idle"), |_| {});
+    assert_eq!(context.get::<&str>(), Some(&"idle"));
+    assert_eq!(context.count::<u32>(), 1);
+}
@@ -0,0 +1,46 @@
+use core::cell::{Cell, OnceCell};
+
+use tuple_set::TupleSet;
+
+#[test]
+fn test_get_or_init_field() {
+    let context = (OnceCell::<u32>::new(), OnceCell::<&str>::new(), 2.5f64);
+    assert_eq!(context.get_or_init_field(|| 42u32), Some(&42));
+    assert_eq!(context.get_or_init_field(|| "lazy"), Some(&"lazy"));
+    assert_eq!(context.0.get(), Some(&42));
+}
+
+#[test]
+fn test_get_or_init_field_runs_once() {
+    let calls = Cell::new(0);
+    let context = (OnceCell::<u32>::new(),);
+    for _ in 0..3 {
+        context.get_or_init_field(|| {
+            calls.set(calls.get() + 1);
+            1u32
+        });
+    }
+    assert_eq!(calls.get(), 1);
+}
+
+#[test]
+fn test_get_initialized() {
+    let context = (OnceCell::<u32>::new(), 2.5f64);
+    assert_eq!(context.get_initialized::<u32>(), None);
+    context.0.set(7).unwrap();
+    assert_eq!(context.get_initialized::<u32>(), Some(&7));
+}
+
+#[test]
+fn test_once_cell_not_found_or_duplicated() {
+    let calls = Cell::new(0);
+    let context = (OnceCell::<u32>::new(), OnceCell::<u32>::new());
+    let value = context.get_or_init_field(|| {
+        calls.set(calls.get() + 1);
+        1u32
+    });
+    assert_eq!(value, None);
+    assert_eq!(calls.get(), 0);
+    assert_eq!(context.get_or_init_field(|| true), None);
+    assert_eq!(context.get_initialized::<bool>(), None);
+}
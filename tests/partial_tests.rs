@@ -0,0 +1,54 @@
+use tuple_set::Partial;
+
+fn scale(factor: u8, value: u32, label: &'static str) -> String {
+    format!("{label}={}", u32::from(factor) * value)
+}
+
+#[test]
+fn test_partial_invokes_once_complete() {
+    let mut partial = Partial::<_, (u8, u32, &str)>::new(scale);
+    assert!(!partial.is_complete());
+    assert_eq!(partial.provide("x"), Ok(None));
+    assert_eq!(partial.provide(2u8), Ok(None));
+    assert_eq!(partial.provide(21u32), Ok(Some(String::from("x=42"))));
+    assert!(!partial.is_complete());
+    assert_eq!(partial.call(), None);
+}
+
+#[test]
+fn test_partial_rejects_foreign_types() {
+    let mut partial = Partial::<_, (u8, u32, &str)>::new(scale);
+    assert_eq!(partial.provide(1i64), Err(1i64));
+    assert_eq!(partial.provide(true), Err(true));
+}
+
+#[test]
+fn test_partial_replaces_and_resumes() {
+    let mut partial = Partial::<_, (u8, u32, &str)>::new(scale);
+    assert_eq!(partial.provide(3u8), Ok(None));
+    assert_eq!(partial.provide(5u8), Ok(None));
+    assert_eq!(partial.provide("y"), Ok(None));
+
+    let (function, slots) = partial.into_parts();
+    assert_eq!(slots, (Some(5u8), None, Some("y")));
+
+    let mut resumed = Partial::<_, (u8, u32, &str)>::from_patch(function, slots);
+    assert_eq!(resumed.provide(4u32), Ok(Some(String::from("y=20"))));
+}
+
+#[test]
+fn test_partial_call_keeps_incomplete_arguments() {
+    let mut calls = 0;
+    {
+        let mut partial = Partial::<_, (u16, bool)>::new(|value: u16, flag: bool| {
+            calls += 1;
+            if flag { value } else { 0 }
+        });
+        assert_eq!(partial.provide(true), Ok(None));
+        assert_eq!(partial.call(), None);
+        assert_eq!(partial.provide(7u16), Ok(Some(7)));
+        assert_eq!(partial.provide(false), Ok(None));
+        assert_eq!(partial.provide(9u16), Ok(Some(0)));
+    }
+    assert_eq!(calls, 2);
+}
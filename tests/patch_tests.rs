@@ -0,0 +1,36 @@
+extern crate alloc;
+
+use alloc::{string::String, vec, vec::Vec};
+
+use tuple_set::{Diffable, Patchable};
+
+#[test]
+fn test_apply_patch() {
+    let mut tuple = (1u8, String::from("a"), vec![1, 2]);
+    tuple.apply_patch((Some(2), None, Some(Vec::new())));
+    assert_eq!(tuple, (2, String::from("a"), Vec::new()));
+}
+
+#[test]
+fn test_apply_empty_patch() {
+    let mut tuple = (1u8, 'x');
+    tuple.apply_patch((None, None));
+    assert_eq!(tuple, (1, 'x'));
+}
+
+#[test]
+fn test_diff_as_patch() {
+    let old = (1u8, String::from("a"), true);
+    let new = (1u8, String::from("b"), false);
+    assert_eq!(old.diff_as_patch(&new), (None, Some(String::from("b")), Some(false)));
+    assert_eq!(old.diff_as_patch(&old), (None, None, None));
+}
+
+#[test]
+fn test_diff_roundtrip() {
+    let mut state = (1u8, 2u16, 3u32, 4u64, 'a', "hello");
+    let target = (1u8, 20u16, 3u32, 40u64, 'b', "hello");
+    let patch = state.diff_as_patch(&target);
+    state.apply_patch(patch);
+    assert_eq!(state, target);
+}
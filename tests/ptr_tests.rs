@@ -0,0 +1,45 @@
+use tuple_set::TupleSet;
+
+#[test]
+fn test_as_ptr_of() {
+    let tuple = (42i32, "hello", 2.5f64);
+    let ptr = tuple.as_ptr_of::<f64>().unwrap();
+    assert_eq!(ptr, &raw const tuple.2);
+    assert_eq!(unsafe { *ptr }, 2.5);
+}
+
+#[test]
+fn test_as_mut_ptr_of() {
+    let mut tuple = (42i32, "hello", 2.5f64);
+    let ptr = tuple.as_mut_ptr_of::<&str>().unwrap();
+    unsafe { ptr.write("world") };
+    assert_eq!(tuple.1, "world");
+}
+
+#[test]
+fn test_as_ptr_of_not_found() {
+    let mut tuple = (42i32, "hello", 2.5f64);
+    assert!(tuple.as_ptr_of::<bool>().is_none());
+    assert!(tuple.as_mut_ptr_of::<bool>().is_none());
+}
+
+#[test]
+fn test_as_ptr_of_with_duplicates() {
+    let mut tuple = (42i32, "hello", 100i32);
+    assert!(tuple.as_ptr_of::<i32>().is_none());
+    assert!(tuple.as_mut_ptr_of::<i32>().is_none());
+}
+
+#[test]
+fn test_as_ptr_of_unchecked_first_occurrence() {
+    let tuple = (42i32, "hello", 100i32);
+    let ptr = unsafe { tuple.as_ptr_of_unchecked::<i32>() };
+    assert_eq!(ptr, &raw const tuple.0);
+}
+
+#[test]
+#[should_panic(expected = "not found")]
+fn test_as_mut_ptr_of_unchecked_panic() {
+    let mut tuple = (42i32, "hello");
+    let _ = unsafe { tuple.as_mut_ptr_of_unchecked::<bool>() };
+}
@@ -0,0 +1,64 @@
+#![cfg(feature = "pyo3")]
+
+use pyo3::{
+    IntoPyObject, Python,
+    exceptions::{PyKeyError, PyValueError},
+    types::{PyAnyMethods, PyDict, PyDictMethods},
+};
+use tuple_set::{PyKeyed, TupleView};
+
+#[test]
+fn test_keyed_round_trip() {
+    Python::initialize();
+    Python::attach(|py| {
+        let tuple = (42u32, String::from("hello"), true);
+        let dict = PyKeyed::new(tuple.clone()).into_pyobject(py).unwrap();
+        assert_eq!(dict.len(), 3);
+        assert_eq!(
+            dict.get_item("alloc::string::String").unwrap().unwrap().extract::<String>().unwrap(),
+            "hello"
+        );
+
+        let decoded: PyKeyed<(u32, String, bool)> = dict.extract().unwrap();
+        assert_eq!(decoded.into_inner(), tuple);
+    });
+}
+
+#[test]
+fn test_keyed_errors() {
+    Python::initialize();
+    Python::attach(|py| {
+        let duplicated = PyKeyed::new((1u8, 2u8)).into_pyobject(py).unwrap_err();
+        assert!(duplicated.is_instance_of::<PyValueError>(py));
+
+        let dict = PyDict::new(py);
+        dict.set_item("u8", 1u8).unwrap();
+        let missing = dict.extract::<PyKeyed<(u8, bool)>>().unwrap_err();
+        assert!(missing.is_instance_of::<PyKeyError>(py));
+
+        dict.set_item("bool", true).unwrap();
+        dict.set_item("i64", 3i64).unwrap();
+        let unknown = dict.extract::<PyKeyed<(u8, bool)>>().unwrap_err();
+        assert!(unknown.is_instance_of::<PyKeyError>(py));
+        assert!(unknown.to_string().contains("i64"));
+
+        let not_a_dict = 3u8.into_pyobject(py).unwrap();
+        assert!(not_a_dict.extract::<PyKeyed<(u8, bool)>>().is_err());
+    });
+}
+
+#[test]
+fn test_tuple_view_from_python() {
+    Python::initialize();
+    Python::attach(|py| {
+        let view = TupleView::new(py, (8080u16, String::from("localhost"))).unwrap();
+        let view = view.into_pyobject(py).unwrap();
+        assert_eq!(view.len().unwrap(), 2);
+
+        let port = view.call_method1("get", ("u16",)).unwrap();
+        assert_eq!(port.extract::<u16>().unwrap(), 8080);
+
+        let error = view.call_method1("get", ("u8",)).unwrap_err();
+        assert!(error.is_instance_of::<PyKeyError>(py));
+    });
+}
@@ -0,0 +1,59 @@
+#![cfg(feature = "alloc")]
+
+use tuple_set::Recorded;
+
+#[test]
+fn test_records_set_and_map() {
+    let mut state = Recorded::new((1u32, String::from("a"), true));
+    assert!(state.set(false).is_none());
+    assert_eq!(state.map(|name: &mut String| name.push('b')), Some(()));
+
+    let revisions: Vec<_> =
+        state.revisions().map(|revision| (revision.position, revision.type_name)).collect();
+    assert_eq!(revisions, [(2, "bool"), (1, "alloc::string::String")]);
+    let old = state.revisions().last().unwrap().old;
+    assert_eq!(old.downcast_ref::<String>().unwrap(), "a");
+}
+
+#[test]
+fn test_failed_lookups_are_not_recorded() {
+    let mut state = Recorded::new((1u32, 2u32, 'x'));
+    assert_eq!(state.set(3u32), Some(3));
+    assert_eq!(state.map(|flag: &mut bool| *flag), None);
+    assert_eq!(state.revisions().len(), 0);
+    assert!(!state.undo());
+}
+
+#[test]
+fn test_undo_in_reverse_order() {
+    let mut state = Recorded::new((0u8, 'a'));
+    state.set(1u8);
+    state.set('b');
+    state.set(2u8);
+
+    assert!(state.undo());
+    assert_eq!(*state, (1, 'b'));
+    assert!(state.undo());
+    assert_eq!(*state, (1, 'a'));
+    assert!(state.undo());
+    assert_eq!(*state, (0, 'a'));
+    assert!(!state.undo());
+}
+
+#[test]
+fn test_rollback_to() {
+    let mut state = Recorded::new((0i32,));
+    for step in 1..=5 {
+        state.map(|value: &mut i32| *value += step);
+    }
+    assert_eq!(state.0, 15);
+
+    assert_eq!(state.rollback_to(2), 3);
+    assert_eq!(state.0, 3);
+    assert_eq!(state.revisions().len(), 2);
+    assert_eq!(state.rollback_to(4), 0);
+
+    state.clear_history();
+    assert_eq!(state.rollback_to(0), 0);
+    assert_eq!(state.into_inner(), (3,));
+}
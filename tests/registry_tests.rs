@@ -0,0 +1,49 @@
+#![cfg(feature = "alloc")]
+
+extern crate alloc;
+
+use alloc::string::{String, ToString};
+
+use tuple_set::{MissingConstructor, Registry};
+
+#[derive(Debug, PartialEq)]
+struct Port(u16);
+
+#[test]
+fn test_construct_tuples() {
+    let mut registry = Registry::new();
+    assert!(registry.register(|| Port(8080)).is_none());
+    assert!(registry.register_default::<String>().is_none());
+    registry.register(|| 3u32);
+
+    let context: (Port, String, u32) = registry.construct().unwrap();
+    assert_eq!(context, (Port(8080), String::new(), 3));
+
+    // Repeated types are constructed once per occurrence
+    let pair: (u32, u32) = registry.construct().unwrap();
+    assert_eq!(pair, (3, 3));
+}
+
+#[test]
+fn test_replace_and_unregister() {
+    let mut registry = Registry::new();
+    registry.register(|| 1u8);
+    let previous = registry.register(|| 2u8).unwrap();
+    assert_eq!(previous(), 1);
+    assert_eq!(registry.construct_one::<u8>(), Some(2));
+
+    assert!(registry.contains::<u8>());
+    assert!(registry.unregister::<u8>().is_some());
+    assert!(!registry.contains::<u8>());
+    assert_eq!(registry.construct_one::<u8>(), None);
+}
+
+#[test]
+fn test_missing_constructor() {
+    let mut registry = Registry::new();
+    registry.register(|| true);
+
+    let error = registry.construct::<(bool, Port, u8)>().unwrap_err();
+    assert_eq!(error, MissingConstructor::of::<Port>());
+    assert_eq!(error.to_string(), "no constructor registered for type `registry_tests::Port`");
+}
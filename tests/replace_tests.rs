@@ -0,0 +1,26 @@
+use tuple_set::TupleSet;
+
+#[test]
+fn test_replace_returns_previous() {
+    let mut tuple = (vec![1u8, 2], 'x', 3u32);
+    assert_eq!(tuple.replace(vec![3u8]), Ok(vec![1, 2]));
+    assert_eq!(tuple.replace('y'), Ok('x'));
+    assert_eq!(tuple, (vec![3], 'y', 3));
+}
+
+#[test]
+fn test_replace_enables_restore() {
+    let mut tuple = (String::from("idle"), 0u8);
+    let previous = tuple.replace(String::from("busy")).unwrap();
+    assert_eq!(tuple.0, "busy");
+    assert_eq!(tuple.replace(previous).as_deref(), Ok("busy"));
+    assert_eq!(tuple.0, "idle");
+}
+
+#[test]
+fn test_replace_not_found_or_duplicated() {
+    let mut tuple = (1u8, 2u8, 'x');
+    assert_eq!(tuple.replace(3u8), Err(3));
+    assert_eq!(tuple.replace(true), Err(true));
+    assert_eq!(tuple, (1, 2, 'x'));
+}
@@ -0,0 +1,44 @@
+#![cfg(feature = "rkyv")]
+
+use rkyv::{Archived, rancor::Error};
+use tuple_set::RkyvTuple;
+
+#[test]
+fn test_round_trip() {
+    let record = RkyvTuple::new((1u8, String::from("name"), Some(3i64)));
+    let bytes = rkyv::to_bytes::<Error>(&record).unwrap();
+    let decoded = rkyv::from_bytes::<RkyvTuple<(u8, String, Option<i64>)>, Error>(&bytes).unwrap();
+    assert_eq!(decoded, record);
+}
+
+#[test]
+fn test_access_without_deserializing() {
+    let record = RkyvTuple::new((7u16, String::from("sensor"), false));
+    let bytes = rkyv::to_bytes::<Error>(&record).unwrap();
+    let archived = rkyv::access::<Archived<RkyvTuple<(u16, String, bool)>>, Error>(&bytes).unwrap();
+
+    assert_eq!(archived.head, 7);
+    assert_eq!(archived.tail.head, "sensor");
+    assert_eq!(archived.get::<Archived<String>>().map(|name| name.as_str()), Some("sensor"));
+    assert_eq!(archived.get::<Archived<bool>>(), Some(&false));
+    assert!(archived.get::<Archived<u64>>().is_none());
+}
+
+#[test]
+fn test_duplicated_archived_types() {
+    let record = RkyvTuple::new((1u32, 2u32, 'x'));
+    let bytes = rkyv::to_bytes::<Error>(&record).unwrap();
+    let archived = rkyv::access::<Archived<RkyvTuple<(u32, u32, char)>>, Error>(&bytes).unwrap();
+    assert!(archived.get::<Archived<u32>>().is_none());
+    assert_eq!(archived.get::<Archived<char>>().map(|value| value.to_native()), Some('x'));
+}
+
+type Wide = RkyvTuple<(u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8)>;
+
+#[test]
+fn test_beyond_rkyv_tuple_arity() {
+    let record = Wide::new((0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15));
+    let bytes = rkyv::to_bytes::<Error>(&record).unwrap();
+    let decoded = rkyv::from_bytes::<Wide, Error>(&bytes).unwrap();
+    assert_eq!((decoded.0, decoded.7, decoded.15), (0, 7, 15));
+}
@@ -0,0 +1,42 @@
+use tuple_set::{SliceTooShort, TupleSet};
+
+#[test]
+fn test_scatter_all_occurrences() {
+    let tuple = (String::from("a"), 1u8, String::from("b"));
+    let mut names = [String::new(), String::new()];
+    assert_eq!(tuple.scatter_to_slice(&mut names), Ok(2));
+    assert_eq!(names, [String::from("a"), String::from("b")]);
+}
+
+#[test]
+fn test_scatter_slice_too_short() {
+    let tuple = (1u32, 2u32, 3u32);
+    let mut values = [0u32; 2];
+    assert_eq!(
+        tuple.scatter_to_slice(&mut values),
+        Err(SliceTooShort { required: 3, available: 2 })
+    );
+    assert_eq!(values, [0, 0]);
+}
+
+#[test]
+fn test_scatter_missing_type() {
+    let tuple = (1u32, 'x');
+    assert_eq!(tuple.scatter_to_slice::<f64>(&mut []), Ok(0));
+}
+
+#[test]
+fn test_scatter_fill_round_trip() {
+    let tuple = (1i16, true, 2i16);
+    let mut values = [0i16; 2];
+    tuple.scatter_to_slice(&mut values).unwrap();
+    let mut copy = (0i16, false, 0i16);
+    copy.fill_from_iter(values);
+    assert_eq!(copy, (1, false, 2));
+}
+
+#[test]
+fn test_slice_too_short_display() {
+    let error = SliceTooShort { required: 3, available: 1 };
+    assert_eq!(error.to_string(), "slice too short: 3 elements required, 1 available");
+}
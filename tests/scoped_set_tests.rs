@@ -0,0 +1,50 @@
+extern crate alloc;
+
+use alloc::string::String;
+
+use tuple_set::TupleSet;
+
+#[test]
+fn test_scoped_set_restores_on_drop() {
+    let mut config = (3u32, String::from("production"));
+    {
+        let guard = config.scoped_set(String::from("test")).unwrap();
+        assert_eq!(guard.1, "test");
+    }
+    assert_eq!(config.1, "production");
+}
+
+#[test]
+fn test_scoped_set_allows_access_to_tuple() {
+    let mut config = (3u32, 'x');
+    {
+        let mut guard = config.scoped_set(5u32).unwrap();
+        assert_eq!(guard.get::<u32>(), Some(&5));
+        assert_eq!(*guard.previous(), 3);
+        guard.set('y');
+    }
+    // Only the scoped field is restored
+    assert_eq!(config, (3, 'y'));
+}
+
+#[test]
+fn test_nested_scoped_set() {
+    let mut config = (1u32, 'a');
+    {
+        let mut outer = config.scoped_set(2u32).unwrap();
+        {
+            let inner = outer.scoped_set(3u32).unwrap();
+            assert_eq!(inner.0, 3);
+        }
+        assert_eq!(outer.0, 2);
+    }
+    assert_eq!(config.0, 1);
+}
+
+#[test]
+fn test_scoped_set_not_found_or_duplicated() {
+    let mut config = (1u32, 2u32);
+    assert_eq!(config.scoped_set(3u32).err(), Some(3));
+    assert_eq!(config.scoped_set(true).err(), Some(true));
+    assert_eq!(config, (1, 2));
+}
@@ -0,0 +1,96 @@
+#![cfg(feature = "serde")]
+
+use tuple_set::{Auto, DeserializePositional, Keyed, Positional, SerdeTuple, SerializePositional};
+
+#[test]
+fn test_keyed_round_trip() {
+    let tuple = (42u32, String::from("hello"), true);
+    let json = serde_json::to_string(&SerdeTuple::<_, Keyed>::new(tuple.clone())).unwrap();
+    assert_eq!(json, r#"{"u32":42,"alloc::string::String":"hello","bool":true}"#);
+
+    // Keys may appear in any order
+    let reordered = r#"{"bool":true,"u32":42,"alloc::string::String":"hello"}"#;
+    let decoded: SerdeTuple<(u32, String, bool), Keyed> = serde_json::from_str(reordered).unwrap();
+    assert_eq!(decoded.into_inner(), tuple);
+}
+
+#[test]
+fn test_keyed_errors() {
+    let duplicated = SerdeTuple::<_, Keyed>::new((1u8, 2u8));
+    let error = serde_json::to_string(&duplicated).unwrap_err();
+    assert!(error.to_string().contains("type `u8` appears multiple times"));
+
+    let missing = serde_json::from_str::<SerdeTuple<(u8, bool), Keyed>>(r#"{"u8":1}"#);
+    assert!(missing.unwrap_err().to_string().contains("missing field `bool`"));
+
+    let unknown = serde_json::from_str::<SerdeTuple<(u8,), Keyed>>(r#"{"u8":1,"i64":2}"#);
+    assert!(unknown.unwrap_err().to_string().contains("unknown type name `i64`"));
+
+    let repeated = serde_json::from_str::<SerdeTuple<(u8,), Keyed>>(r#"{"u8":1,"u8":2}"#);
+    assert!(repeated.unwrap_err().to_string().contains("duplicate field `u8`"));
+}
+
+#[test]
+fn test_positional_round_trip() {
+    let tuple = (1u8, 2u8, 'x');
+    let json = serde_json::to_string(&SerdeTuple::<_, Positional>::new(tuple)).unwrap();
+    assert_eq!(json, r#"[1,2,"x"]"#);
+
+    let decoded: SerdeTuple<(u8, u8, char), Positional> = serde_json::from_str(&json).unwrap();
+    assert_eq!(*decoded, tuple);
+}
+
+#[test]
+fn test_auto_follows_human_readability() {
+    let tuple = (7u16, false);
+    let wrapped = SerdeTuple::<_, Auto>::new(tuple);
+
+    let json = serde_json::to_string(&wrapped).unwrap();
+    assert_eq!(json, r#"{"u16":7,"bool":false}"#);
+    let decoded: SerdeTuple<(u16, bool)> = serde_json::from_str(&json).unwrap();
+    assert_eq!(decoded.into_inner(), tuple);
+
+    let mut buffer = [0u8; 16];
+    let bytes = postcard::to_slice(&wrapped, &mut buffer).unwrap();
+    assert_eq!(bytes, [7, 0]);
+    let decoded: SerdeTuple<(u16, bool)> = postcard::from_bytes(bytes).unwrap();
+    assert_eq!(decoded.into_inner(), tuple);
+}
+
+#[test]
+fn test_positional_beyond_serde_arities() {
+    fn round_trip<T: Copy + SerializePositional + for<'de> DeserializePositional<'de>>(
+        tuple: T,
+    ) -> (String, T, T) {
+        let json = serde_json::to_string(&SerdeTuple::<_, Positional>::new(tuple)).unwrap();
+        let from_json: SerdeTuple<T, Positional> = serde_json::from_str(&json).unwrap();
+
+        let mut buffer = [0u8; 64];
+        let bytes =
+            postcard::to_slice(&SerdeTuple::<_, Positional>::new(tuple), &mut buffer).unwrap();
+        let from_bytes: SerdeTuple<T, Positional> = postcard::from_bytes(bytes).unwrap();
+        (json, from_json.into_inner(), from_bytes.into_inner())
+    }
+
+    let (json, from_json, from_bytes) = round_trip((
+        0u8, 1u8, 2u8, 3u8, 4u8, 5u8, 6u8, 7u8, 8u8, 9u8, 10u8, 11u8, 12u8, 13u8, 14u8, 15u8, 16u8,
+        17u8, 18u8, 19u8, 20u8, 21u8, 22u8, 23u8, 24u8, 25u8, 26u8, 27u8, 28u8, 29u8, 30u8, 31u8,
+        32u8, 33u8, 34u8, 35u8, 36u8, 37u8, 38u8, true,
+    ));
+    assert!(json.starts_with("[0,1,2,"));
+    assert!(json.ends_with(",37,38,true]"));
+    for decoded in [from_json, from_bytes] {
+        assert_eq!((decoded.0, decoded.17, decoded.38, decoded.39), (0, 17, 38, true));
+    }
+}
+
+#[test]
+fn test_positional_errors() {
+    let short = serde_json::from_str::<SerdeTuple<(u8, bool), Positional>>("[1]");
+    assert!(
+        short.unwrap_err().to_string().contains("invalid length 1, expected a tuple of size 2")
+    );
+
+    let long = serde_json::from_str::<SerdeTuple<(u8,), Positional>>("[1,2]");
+    assert!(long.is_err());
+}
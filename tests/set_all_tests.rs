@@ -0,0 +1,28 @@
+use std::rc::Rc;
+
+use tuple_set::TupleSet;
+
+#[test]
+fn test_set_all_broadcasts_to_every_occurrence() {
+    let mut tuple = (Some(1u32), 'x', None::<u32>, Some(2u32));
+    assert_eq!(tuple.set_all(Some(9u32)), 3);
+    assert_eq!(tuple, (Some(9), 'x', Some(9), Some(9)));
+}
+
+#[test]
+fn test_set_all_unique_and_missing() {
+    let mut tuple = (42i32, "hello");
+    assert_eq!(tuple.set_all(7i32), 1);
+    assert_eq!(tuple.set_all(true), 0);
+    assert_eq!(tuple, (7, "hello"));
+}
+
+#[test]
+fn test_set_all_drops_previous_values() {
+    let old = Rc::new("old");
+    let new = Rc::new("new");
+    let mut tuple = (old.clone(), 0u8, old.clone());
+    assert_eq!(tuple.set_all(new.clone()), 2);
+    assert_eq!(Rc::strong_count(&old), 1);
+    assert_eq!(Rc::strong_count(&new), 3);
+}
@@ -0,0 +1,24 @@
+use tuple_set::TupleSet;
+
+#[test]
+fn test_set_from_into_converts() {
+    let mut tuple = (String::from("old"), 1u32, 'x');
+    assert!(tuple.set_from_into::<String, _>("new").is_none());
+    assert!(tuple.set_from_into::<u32, _>(7u8).is_none());
+    assert_eq!(tuple, (String::from("new"), 7, 'x'));
+}
+
+#[test]
+fn test_set_from_into_identity() {
+    let mut tuple = (1i64, 'x');
+    assert!(tuple.set_from_into::<i64, _>(5i64).is_none());
+    assert_eq!(tuple.0, 5);
+}
+
+#[test]
+fn test_set_from_into_returns_unconverted_value() {
+    let mut tuple = (1u64, 2u64, 'x');
+    assert_eq!(tuple.set_from_into::<u64, _>(3u8), Some(3u8));
+    assert_eq!(tuple.set_from_into::<String, _>("missing"), Some("missing"));
+    assert_eq!(tuple, (1, 2, 'x'));
+}
@@ -0,0 +1,34 @@
+use std::cell::Cell;
+
+use tuple_set::TupleSet;
+
+#[test]
+fn test_set_with_unique() {
+    let mut tuple = (1u8, String::from("old"), 'x');
+    assert!(tuple.set_with(|| String::from("new")));
+    assert_eq!(tuple.1, "new");
+}
+
+#[test]
+fn test_set_with_missing_type_skips_constructor() {
+    let calls = Cell::new(0);
+    let mut tuple = (1u8, 'x');
+    assert!(!tuple.set_with(|| {
+        calls.set(calls.get() + 1);
+        0u64
+    }));
+    assert_eq!(calls.get(), 0);
+    assert_eq!(tuple, (1, 'x'));
+}
+
+#[test]
+fn test_set_with_duplicate_type_skips_constructor() {
+    let calls = Cell::new(0);
+    let mut tuple = (1u8, 2u8);
+    assert!(!tuple.set_with(|| {
+        calls.set(calls.get() + 1);
+        3u8
+    }));
+    assert_eq!(calls.get(), 0);
+    assert_eq!(tuple, (1, 2));
+}
@@ -0,0 +1,39 @@
+extern crate alloc;
+
+use alloc::{string::String, vec, vec::Vec};
+
+use tuple_set::TupleSet;
+
+#[test]
+fn test_snapshot() {
+    let state = (1u32, String::from("idle"), vec![1u8]);
+    let saved: (Vec<u8>, u32) = state.snapshot().unwrap();
+    assert_eq!(saved, (vec![1], 1));
+}
+
+#[test]
+fn test_snapshot_and_restore() {
+    let mut state = (1u32, String::from("idle"), 2.5f64);
+    let saved = state.snapshot::<(String, f64)>().unwrap();
+    state.set(String::from("busy"));
+    state.set(0.0f64);
+    state.set(2u32);
+
+    assert!(state.restore(saved).is_none());
+    assert_eq!(state, (2, String::from("idle"), 2.5));
+}
+
+#[test]
+fn test_snapshot_not_found_or_duplicated() {
+    let state = (1u32, 2u32, 'x');
+    assert!(state.snapshot::<(char, bool)>().is_none());
+    assert!(state.snapshot::<(u32,)>().is_none());
+}
+
+#[test]
+fn test_restore_is_all_or_nothing() {
+    let mut state = (1u32, 'x');
+    let rejected = state.restore(('y', true));
+    assert_eq!(rejected, Some(('y', true)));
+    assert_eq!(state, (1, 'x'));
+}
@@ -0,0 +1,50 @@
+use tuple_set::TupleSet;
+
+#[derive(Debug, PartialEq)]
+struct Physics(f32);
+
+#[derive(Debug, PartialEq)]
+struct Audio(u8);
+
+#[test]
+fn test_split_by_types_disjoint_halves() {
+    let mut context = (Physics(0.0), 10u32, Audio(3), "scene");
+    let ((physics, frame), (audio,)) =
+        context.split_by_types::<(Physics, u32), (Audio,)>().unwrap();
+    physics.0 += 9.8;
+    *frame += 1;
+    audio.0 -= 1;
+    assert_eq!(context, (Physics(9.8), 11, Audio(2), "scene"));
+}
+
+#[test]
+fn test_split_by_types_overlapping_lists() {
+    let mut context = (Physics(0.0), Audio(3));
+    assert!(context.split_by_types::<(Physics,), (Audio, Physics)>().is_none());
+    assert!(context.split_by_types::<(Physics, Physics), (Audio,)>().is_none());
+}
+
+#[test]
+fn test_split_by_types_missing_or_duplicated_fields() {
+    let mut context = (Physics(0.0), 1u8, 2u8);
+    assert!(context.split_by_types::<(Physics,), (u8,)>().is_none());
+    assert!(context.split_by_types::<(Physics,), (Audio,)>().is_none());
+    assert!(context.split_by_types::<(u16,), (Physics,)>().is_none());
+}
+
+tuple_set::define_tuple!(
+    Engine =>
+        physics: Physics [physics_mut, set_physics],
+        audio: Audio [audio_mut, set_audio],
+        frame: u64 [frame_mut, set_frame],
+);
+
+#[test]
+fn test_split_by_types_on_defined_tuple() {
+    let mut engine = Engine::new(Physics(1.0), Audio(2), 3);
+    let ((physics, frame), (audio,)) = engine.split_by_types::<(Physics, u64), (Audio,)>().unwrap();
+    physics.0 *= 2.0;
+    audio.0 += 1;
+    *frame += 1;
+    assert_eq!(engine.into_inner(), (Physics(2.0), Audio(3), 4));
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "strict")]
+
+use tuple_set::{ContainsUnique, StrictTupleSet};
+
+fn bump<C: ContainsUnique<u32, I>, I>(context: &mut C) -> u32 {
+    context.map_strict(|value: &mut u32| {
+        *value += 1;
+        *value
+    })
+}
+
+#[test]
+fn test_strict_get() {
+    let mut tuple = (1u8, "two", 3.0f64);
+    assert_eq!(*tuple.get_strict::<u8, _>(), 1);
+    assert_eq!(*tuple.get_strict::<&str, _>(), "two");
+
+    *tuple.get_strict_mut::<f64, _>() *= 2.0;
+    assert_eq!(tuple.2, 6.0);
+}
+
+#[test]
+fn test_strict_set_and_map() {
+    let mut tuple = (1u32, false);
+    assert!(!tuple.set_strict(true));
+    assert_eq!(bump(&mut tuple), 2);
+    assert_eq!(bump(&mut tuple), 3);
+    assert_eq!(tuple, (3, true));
+}
+
+#[test]
+fn test_strict_matches_checked_accessors() {
+    use tuple_set::TupleSet;
+
+    let tuple = ('a', 10i64, "text");
+    assert_eq!(tuple.get::<i64>(), Some(tuple.get_strict::<i64, _>()));
+    assert_eq!(tuple.get::<char>(), Some(tuple.get_strict::<char, _>()));
+}
@@ -0,0 +1,72 @@
+#![cfg(feature = "std")]
+
+use std::thread;
+
+use tuple_set::SyncTuple;
+
+#[test]
+fn test_read_and_write() {
+    let registry = SyncTuple::new((42i32, "hello"));
+    assert_eq!(*registry.read::<i32>().unwrap(), 42);
+    *registry.write::<&str>().unwrap() = "world";
+    assert_eq!(*registry.read::<&str>().unwrap(), "world");
+}
+
+#[test]
+fn test_not_found_and_duplicates() {
+    let registry = SyncTuple::new((1i32, 2i32, 'x'));
+    assert!(registry.read::<i32>().is_none());
+    assert!(registry.write::<i32>().is_none());
+    assert!(registry.read::<bool>().is_none());
+}
+
+#[test]
+fn test_independent_fields() {
+    let registry = SyncTuple::new((0u32, 0u64));
+    let mut small = registry.write::<u32>().unwrap();
+    // A different field can be locked while the first one is held
+    *registry.write::<u64>().unwrap() = 7;
+    *small = 3;
+    drop(small);
+    assert_eq!(registry.into_inner(), (3, 7));
+}
+
+#[test]
+fn test_concurrent_writers() {
+    let registry = SyncTuple::new((0u64, 0u32));
+    thread::scope(|scope| {
+        for _ in 0..4 {
+            scope.spawn(|| {
+                for _ in 0..100 {
+                    *registry.write::<u64>().unwrap() += 1;
+                    *registry.write::<u32>().unwrap() += 2;
+                }
+            });
+        }
+    });
+    assert_eq!(registry.into_inner(), (400, 800));
+}
+
+#[test]
+fn test_poisoned_field() {
+    let registry = SyncTuple::new((0u64, 'x'));
+    let result = thread::scope(|scope| {
+        scope
+            .spawn(|| {
+                *registry.write::<u64>().unwrap() = 1;
+                let _guard = registry.write::<u64>();
+                panic!("writer failed");
+            })
+            .join()
+    });
+    assert!(result.is_err());
+
+    assert_eq!(registry.is_poisoned::<u64>(), Some(true));
+    assert_eq!(registry.is_poisoned::<char>(), Some(false));
+    assert_eq!(registry.is_poisoned::<bool>(), None);
+    // The poisoned field stays accessible
+    assert_eq!(*registry.read::<u64>().unwrap(), 1);
+
+    assert_eq!(registry.clear_poison::<u64>(), Some(()));
+    assert_eq!(registry.is_poisoned::<u64>(), Some(false));
+}
@@ -0,0 +1,47 @@
+use tuple_set::{Tagged, TupleSet};
+
+struct Primary;
+struct Secondary;
+
+type Ports = (Tagged<Primary, u16>, Tagged<Secondary, u16>, &'static str);
+
+fn ports() -> Ports {
+    (Tagged::new(80), Tagged::new(8080), "localhost")
+}
+
+#[test]
+fn test_get_tagged_disambiguates_duplicates() {
+    let ports = ports();
+    assert_eq!(ports.get_tagged::<Primary, u16>(), Some(&80));
+    assert_eq!(ports.get_tagged::<Secondary, u16>(), Some(&8080));
+    assert_eq!(ports.get_tagged::<Primary, u32>(), None);
+    assert_eq!(ports.get::<&str>(), Some(&"localhost"));
+}
+
+#[test]
+fn test_set_and_map_tagged() {
+    let mut ports = ports();
+    assert_eq!(ports.set_tagged::<Primary, u16>(443), None);
+    assert_eq!(
+        ports.map_tagged::<Secondary, u16, _, _>(|port| core::mem::replace(port, 8443)),
+        Some(8080)
+    );
+    assert_eq!((*ports.0, *ports.1), (443, 8443));
+    assert_eq!(ports.set_tagged::<Primary, u32>(1), Some(1));
+}
+
+#[test]
+fn test_duplicate_tagged_types_are_ambiguous() {
+    let tuple = (Tagged::<Primary, u8>::new(1), Tagged::<Primary, u8>::new(2));
+    assert_eq!(tuple.get_tagged::<Primary, u8>(), None);
+}
+
+#[test]
+fn test_tagged_is_transparent() {
+    assert_eq!(size_of::<Tagged<Primary, u64>>(), size_of::<u64>());
+    let mut tagged = Tagged::<Primary, String>::from(String::from("abc"));
+    tagged.push('d');
+    assert_eq!(tagged.len(), 4);
+    assert_eq!(tagged.clone(), Tagged::new(String::from("abcd")));
+    assert_eq!(tagged.into_inner(), "abcd");
+}
@@ -0,0 +1,61 @@
+use tuple_set::{SetErrorKind, TupleSet};
+
+#[test]
+fn test_try_get() {
+    let tuple = (42i32, "hello", 2.5f64);
+    assert_eq!(tuple.try_get::<i32>(), Ok(&42));
+    assert_eq!(tuple.try_get::<&str>(), Ok(&"hello"));
+
+    let error = tuple.try_get::<bool>().unwrap_err();
+    assert!(error.is_not_found());
+    assert!(!error.is_duplicate());
+    assert_eq!(error.count(), 0);
+    assert_eq!(error.type_name(), "bool");
+}
+
+#[test]
+fn test_try_get_duplicate() {
+    let tuple = (1u8, 'x', 2u8, 3u8);
+    let error = tuple.try_get::<u8>().unwrap_err();
+    assert!(error.is_duplicate());
+    assert_eq!(error.count(), 3);
+    assert!(error.to_string().starts_with("type `u8` appears 3 times in tuple"));
+}
+
+#[test]
+fn test_try_set() {
+    let mut tuple = (42i32, "hello", 7i32);
+    assert_eq!(tuple.try_set("world"), Ok(()));
+    assert_eq!(tuple.1, "world");
+
+    let error = tuple.try_set(100i32).unwrap_err();
+    assert_eq!(error.kind(), SetErrorKind::Duplicate { count: 2 });
+    assert_eq!(*error.value(), 100);
+    assert!(error.lookup_error().is_duplicate());
+    assert_eq!(tuple, (42, "world", 7));
+
+    let (value, error) = tuple.try_set(true).unwrap_err().into_parts();
+    assert!(value);
+    assert!(error.is_not_found());
+}
+
+#[test]
+fn test_set_error_is_error() {
+    let mut tuple = (1u8, 2u8);
+    let error: Box<dyn std::error::Error> = Box::new(tuple.try_set(3u8).unwrap_err());
+    assert!(error.to_string().starts_with("cannot set value: type `u8` appears 2 times in tuple"));
+    assert!(error.source().unwrap().to_string().starts_with("type `u8` appears 2 times in tuple"));
+}
+
+#[cfg(feature = "diagnostics")]
+#[test]
+fn test_lookup_error_positions() {
+    let mut tuple = (1u8, 'x', 2u8, 3u8);
+    let error = tuple.try_get::<u8>().unwrap_err();
+    assert!(error.positions().eq([0, 2, 3]));
+    assert_eq!(error.to_string(), "type `u8` appears 3 times in tuple at positions 0, 2, 3");
+
+    let error = tuple.try_set(1.5f32).unwrap_err();
+    assert_eq!(error.kind(), SetErrorKind::NotFound);
+    assert_eq!(error.lookup_error().positions().len(), 0);
+}
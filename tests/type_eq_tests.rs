@@ -0,0 +1,63 @@
+#![cfg(feature = "algebra")]
+
+use tuple_set::{Field, TypeEq};
+
+fn increment<S, I>(tuple: &mut S)
+where
+    S: Field<u32, I>,
+{
+    *tuple.field_mut() += 1;
+}
+
+fn first_word<'a, S, I>(tuple: &S) -> &'a str
+where
+    S: Field<&'a str, I>,
+{
+    tuple.field().split(' ').next().unwrap_or_default()
+}
+
+#[test]
+fn test_type_eq_casts() {
+    let mut value = 3u8;
+    assert_eq!(*TypeEq::<u8>::cast_ref(&value), 3);
+    *TypeEq::<u8>::cast_mut(&mut value) = 4;
+    assert_eq!(TypeEq::<u8>::cast(value), 4);
+}
+
+#[test]
+fn test_field_access() {
+    let mut tuple = (1u8, 2u32, 'c');
+    let value: &u32 = tuple.field();
+    assert_eq!(*value, 2);
+
+    increment(&mut tuple);
+    assert_eq!(tuple, (1, 3, 'c'));
+
+    assert_eq!(tuple.replace_field('d'), 'c');
+    let last: char = tuple.into_field();
+    assert_eq!(last, 'd');
+}
+
+#[test]
+fn test_field_with_borrowed_types() {
+    let text = String::from("hello world");
+    let mut words = Vec::new();
+    let mut tuple = (text.as_str(), &mut words, 7u32);
+
+    assert_eq!(first_word(&tuple), "hello");
+    Field::<&mut Vec<&str>, _>::field_mut(&mut tuple).push("pushed");
+    increment(&mut tuple);
+    assert_eq!(tuple.2, 8);
+    assert_eq!(words, ["pushed"]);
+}
+
+#[test]
+fn test_field_in_long_tuple() {
+    let mut tuple = (0u8, 1u16, 2u32, 3u64, 4i8, 5i16, 6i32, 7i64, 8f32, 9f64, 'a', true);
+    let value: &mut f32 = tuple.field_mut();
+    *value += 0.5;
+    assert_eq!(tuple.8, 8.5);
+
+    let flag: &bool = tuple.field();
+    assert!(*flag);
+}
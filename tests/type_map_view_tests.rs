@@ -0,0 +1,49 @@
+use core::any::TypeId;
+
+use tuple_set::TypeMapView;
+
+#[derive(Debug, PartialEq)]
+struct Config(&'static str);
+
+#[test]
+fn test_type_map_view_unique_types() {
+    let resources = (Config("prod"), 8080u16, true);
+    let map = TypeMapView::new(&resources);
+    assert_eq!(map.len(), 3);
+    assert!(!map.is_empty());
+    assert!(map.keys().eq([TypeId::of::<Config>(), TypeId::of::<u16>(), TypeId::of::<bool>()]));
+    assert_eq!(map.get::<Config>(), Some(&Config("prod")));
+    assert_eq!(map.get::<u16>(), Some(&8080));
+    assert!(map.values_of::<bool>().eq(&[true]));
+}
+
+#[test]
+fn test_type_map_view_duplicated_types() {
+    let resources = (1u8, 'a', 2u8, 3u8);
+    let map = TypeMapView::new(&resources);
+    assert_eq!(map.len(), 2);
+    assert!(map.keys().eq([TypeId::of::<u8>(), TypeId::of::<char>()]));
+    assert!(map.contains_key::<u8>());
+    assert_eq!(map.get::<u8>(), None);
+    assert!(map.values_of::<u8>().eq(&[1, 2, 3]));
+}
+
+#[test]
+fn test_type_map_view_missing_type() {
+    let resources = (1u8, 'a');
+    let map = TypeMapView::new(&resources);
+    assert!(!map.contains_key::<u32>());
+    assert_eq!(map.get::<u32>(), None);
+    assert_eq!(map.values_of::<u32>().count(), 0);
+}
+
+#[test]
+fn test_type_map_view_outlives_view() {
+    let resources = (Config("dev"), 1u8);
+    let config = {
+        let map = TypeMapView::new(&resources);
+        map.get::<Config>().unwrap()
+    };
+    assert_eq!(config, &Config("dev"));
+    assert!(core::ptr::eq(TypeMapView::new(&resources).into_inner(), &resources));
+}
@@ -0,0 +1,94 @@
+use core::any::TypeId;
+
+use tuple_set::{OverlapEntry, TupleTypes, TypeOverlap};
+
+#[test]
+fn test_type_ids() {
+    assert_eq!(<(i32,)>::type_ids(), [TypeId::of::<i32>()]);
+    assert_eq!(
+        <(i32, &str, i32)>::type_ids(),
+        [TypeId::of::<i32>(), TypeId::of::<&str>(), TypeId::of::<i32>()]
+    );
+}
+
+#[test]
+fn test_disjoint_from() {
+    assert!(<(u8, u16)>::is_disjoint_from::<(u32, u64, bool)>());
+    assert!(<(u8,)>::is_disjoint_from::<(i8,)>());
+}
+
+#[test]
+fn test_not_disjoint_from() {
+    assert!(!<(u8, u16)>::is_disjoint_from::<(u32, u16)>());
+    assert!(!<(u8,)>::is_disjoint_from::<(u8,)>());
+}
+
+#[test]
+fn test_disjoint_from_in_generic_code() {
+    fn check<A: TupleTypes, B: TupleTypes>() -> bool {
+        A::is_disjoint_from::<B>()
+    }
+
+    assert!(check::<(bool, char), (i64, f64)>());
+    assert!(!check::<(bool, char), (char,)>());
+}
+
+#[test]
+fn test_type_names() {
+    assert_eq!(<(i32,)>::type_names(), ["i32"]);
+    assert_eq!(
+        <(i32, &str, Option<u8>)>::type_names(),
+        ["i32", "&str", "core::option::Option<u8>"]
+    );
+}
+
+#[test]
+fn test_write_signature() {
+    let mut signature = String::new();
+    <(i32, &str, f64)>::write_signature(&mut signature).unwrap();
+    assert_eq!(signature, "(i32, &str, f64)");
+
+    signature.clear();
+    <(bool,)>::write_signature(&mut signature).unwrap();
+    assert_eq!(signature, "(bool,)");
+}
+
+#[test]
+fn test_write_signature_into_formatter() {
+    struct Shape<T>(core::marker::PhantomData<T>);
+
+    impl<T: TupleTypes> core::fmt::Display for Shape<T> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            T::write_signature(f)
+        }
+    }
+
+    let shape = Shape::<(u8, Option<char>)>(core::marker::PhantomData);
+    assert_eq!(format!("shape: {shape}"), "shape: (u8, core::option::Option<char>)");
+}
+
+#[test]
+fn test_type_overlap_counts() {
+    let overlap = TypeOverlap::<(u8, &str, u8), (u8, char, char)>::new();
+    assert_eq!(
+        overlap.entries().collect::<Vec<_>>(),
+        [
+            OverlapEntry { type_name: "u8", left: 2, right: 1 },
+            OverlapEntry { type_name: "&str", left: 1, right: 0 },
+            OverlapEntry { type_name: "char", left: 0, right: 2 },
+        ]
+    );
+    assert_eq!(overlap.shared().map(|entry| entry.type_name).collect::<Vec<_>>(), ["u8"]);
+}
+
+#[test]
+fn test_type_overlap_disjoint_and_display() {
+    let overlap = TypeOverlap::of(&(1i32,), &(true, 'x'));
+    assert_eq!(overlap.shared().count(), 0);
+    assert_eq!(overlap.left_only().count(), 1);
+    assert_eq!(overlap.right_only().count(), 2);
+    assert_eq!(
+        overlap.to_string(),
+        "i32: 1 left, 0 right\nbool: 0 left, 1 right\nchar: 0 left, 1 right\n"
+    );
+}
@@ -0,0 +1,36 @@
+use tuple_set::UnorderedEq;
+
+#[derive(Debug, PartialEq)]
+struct Host(&'static str);
+
+#[derive(Debug, PartialEq)]
+struct Port(u16);
+
+#[test]
+fn test_unordered_eq_permutations() {
+    let context = (Host("localhost"), Port(80), true);
+    assert!(context.unordered_eq(&(true, Port(80), Host("localhost"))));
+    assert!(context.unordered_eq(&(Port(80), Host("localhost"), true)));
+    assert!(context.unordered_eq(&(Host("localhost"), Port(80), true)));
+}
+
+#[test]
+fn test_unordered_eq_different_values() {
+    let context = (Host("localhost"), Port(80));
+    assert!(!context.unordered_eq(&(Port(8080), Host("localhost"))));
+    assert!(!context.unordered_eq(&(Port(80), Host("example.com"))));
+}
+
+#[test]
+fn test_unordered_eq_different_types() {
+    let context = (Host("localhost"), Port(80));
+    assert!(!context.unordered_eq(&(Port(80),)));
+    assert!(!context.unordered_eq(&(Port(80), Host("localhost"), true)));
+    assert!(!context.unordered_eq(&(Port(80), 'x')));
+}
+
+#[test]
+fn test_unordered_eq_duplicated_types() {
+    assert!(!(1u8, 2u8).unordered_eq(&(2u8, 1u8)));
+    assert!(!(1u8, true).unordered_eq(&(1u8, 1u8)));
+}
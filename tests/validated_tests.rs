@@ -0,0 +1,60 @@
+#![cfg(feature = "alloc")]
+
+use tuple_set::{ValidatedTuple, ValidationError};
+
+fn non_empty(value: &&str) -> Result<(), &'static str> {
+    if value.is_empty() { Err("must not be empty") } else { Ok(()) }
+}
+
+#[test]
+fn test_set_without_validator() {
+    let mut config = ValidatedTuple::new((3u32, "localhost"));
+    assert_eq!(config.set(""), Ok(()));
+    assert_eq!(*config, (3, ""));
+}
+
+#[test]
+fn test_set_with_validator() {
+    let mut config = ValidatedTuple::new((3u32, "localhost"));
+    assert!(config.set_validator(non_empty).is_none());
+    assert_eq!(config.set("example.com"), Ok(()));
+    assert_eq!(
+        config.set(""),
+        Err(ValidationError::Invalid { value: "", reason: "must not be empty" })
+    );
+    assert_eq!(config.into_inner(), (3, "example.com"));
+}
+
+#[test]
+fn test_map_with_validator() {
+    let mut config = ValidatedTuple::new((3u32, "localhost"));
+    config.set_validator::<u32>(|x| if *x < 5 { Ok(()) } else { Err("too large") });
+    assert_eq!(config.map(|x: &mut u32| *x += 1), Some(Ok(())));
+    assert_eq!(config.map(|x: &mut u32| *x += 1), Some(Err("too large")));
+    assert_eq!(config.0, 4);
+    assert_eq!(config.map(|x: &mut bool| *x), None);
+}
+
+#[test]
+fn test_not_unique() {
+    let mut config = ValidatedTuple::new((1u32, 2u32));
+    assert!(config.set_validator::<u32>(|_| Ok(())).is_some());
+    assert!(config.set_validator::<bool>(|_| Ok(())).is_some());
+    assert_eq!(config.set(3u32), Err(ValidationError::NotUnique(3)));
+}
+
+#[test]
+fn test_capturing_validator() {
+    let allowed = ["localhost", "example.com"];
+    let mut config = ValidatedTuple::new((3u32, "localhost"));
+    config.set_validator(
+        move |host: &&str| {
+            if allowed.contains(host) { Ok(()) } else { Err("unknown host") }
+        },
+    );
+    assert_eq!(config.set("example.com"), Ok(()));
+    assert_eq!(
+        config.set("example.org"),
+        Err(ValidationError::Invalid { value: "example.org", reason: "unknown host" })
+    );
+}
@@ -0,0 +1,62 @@
+use tuple_set::{CachedView, ViewTargets};
+
+#[test]
+fn test_view_resolves_unique_fields() {
+    let mut tuple = (1u8, 2u32, "three");
+    assert!(CachedView::<_, (u32, &str)>::new(&mut tuple).is_some());
+    assert!(CachedView::<_, (u32, bool)>::new(&mut tuple).is_none());
+
+    let mut duplicated = (1u8, 2u8, 3u32);
+    assert!(CachedView::<_, (u8,)>::new(&mut duplicated).is_none());
+    assert!(CachedView::<_, (u32,)>::new(&mut duplicated).is_some());
+}
+
+#[test]
+fn test_view_get_and_set() {
+    let mut tuple = (1u8, 2u32, "three", 4.0f64);
+    let mut view = CachedView::<_, (f64, u8)>::new(&mut tuple).unwrap();
+
+    assert_eq!(*view.get::<u8>(), 1);
+    assert_eq!(*view.get::<f64>(), 4.0);
+    *view.get_mut::<u8>() += 10;
+    assert_eq!(view.set(8.0f64), 4.0);
+    assert_eq!(view.into_inner(), &mut (11, 2, "three", 8.0));
+}
+
+#[test]
+fn test_view_rebind() {
+    let mut tuples: Vec<(u16, bool, u64)> = (0..8).map(|i| (i, false, 0)).collect();
+    let (first, rest) = tuples.split_first_mut().unwrap();
+
+    let mut view = CachedView::<_, (u16, bool, u64)>::new(first).unwrap();
+    for tuple in rest {
+        let index = *view.get::<u16>();
+        view.set(index % 2 == 0);
+        view.set(u64::from(index) * 3);
+        view = view.rebind(tuple);
+    }
+
+    assert_eq!(tuples[3], (3, false, 9));
+    assert_eq!(tuples[4], (4, true, 12));
+    assert_eq!(tuples[7], (7, false, 0));
+}
+
+#[test]
+#[should_panic(expected = "is not among the targets")]
+fn test_view_panics_on_untargeted_type() {
+    let mut tuple = (1u8, 2u32);
+    let view = CachedView::<_, (u8,)>::new(&mut tuple).unwrap();
+    let _ = view.get::<u32>();
+}
+
+#[test]
+fn test_targets_slot_and_offsets() {
+    assert_eq!(<(u8, u32)>::slot::<u32>(), Some(1));
+    assert_eq!(<(u8, u32)>::slot::<bool>(), None);
+
+    let tuple = (1u8, 2u32);
+    let offsets = <(u32, u8)>::resolve(&tuple).unwrap();
+    let base = (&raw const tuple).addr();
+    assert_eq!(offsets[0], (&raw const tuple.1).addr() - base);
+    assert_eq!(offsets[1], (&raw const tuple.0).addr() - base);
+}
@@ -0,0 +1,32 @@
+use tuple_set::TupleSet;
+
+#[test]
+fn test_read_volatile_of() {
+    let registers = (0xDEADu32, 0x1u8, 0x2u16);
+    assert_eq!(registers.read_volatile_of::<u32>(), Some(0xDEAD));
+    assert_eq!(registers.read_volatile_of::<u8>(), Some(0x1));
+    assert_eq!(registers.read_volatile_of::<u16>(), Some(0x2));
+}
+
+#[test]
+fn test_write_volatile_of() {
+    let mut registers = (0u32, 0u8, 0u16);
+    assert!(registers.write_volatile_of(0xBEEFu32).is_none());
+    assert!(registers.write_volatile_of(0x7u8).is_none());
+    assert_eq!(registers, (0xBEEF, 0x7, 0));
+}
+
+#[test]
+fn test_volatile_not_found() {
+    let mut registers = (0u32, 0u8);
+    assert_eq!(registers.read_volatile_of::<u64>(), None);
+    assert_eq!(registers.write_volatile_of(5u64), Some(5));
+}
+
+#[test]
+fn test_volatile_with_duplicates() {
+    let mut registers = (1u32, 2u32);
+    assert_eq!(registers.read_volatile_of::<u32>(), None);
+    assert_eq!(registers.write_volatile_of(5u32), Some(5));
+    assert_eq!(registers, (1, 2));
+}
@@ -0,0 +1,35 @@
+#![cfg(feature = "wasm")]
+
+// Only the paths not creating JavaScript values are exercised, as those
+// require a WebAssembly host.
+
+use tuple_set::{BridgeError, JsBridge};
+use wasm_bindgen::JsValue;
+
+#[test]
+fn test_unknown_type_names() {
+    let mut bridge = JsBridge::new((3u32, String::from("localhost")));
+    assert_eq!(bridge.get_by_name("u8"), Err(BridgeError::NotFound));
+    assert_eq!(bridge.set_by_name("String", JsValue::UNDEFINED), Err(BridgeError::NotFound));
+    assert_eq!(bridge.into_inner().0, 3);
+}
+
+#[test]
+fn test_ambiguous_type_names() {
+    let mut bridge = JsBridge::new((1u8, true, 2u8));
+    assert_eq!(bridge.get_by_name("u8"), Err(BridgeError::Ambiguous { count: 2 }));
+    assert_eq!(
+        bridge.set_by_name("u8", JsValue::UNDEFINED),
+        Err(BridgeError::Ambiguous { count: 2 })
+    );
+    assert_eq!(*bridge, (1, true, 2));
+}
+
+#[test]
+fn test_bridge_error_display() {
+    assert_eq!(BridgeError::NotFound.to_string(), "type name not found in tuple");
+    assert_eq!(
+        BridgeError::InvalidValue { type_name: "u8" }.to_string(),
+        "value cannot be converted into `u8`"
+    );
+}
@@ -0,0 +1,54 @@
+use tuple_set::{ZipFn, ZipWith};
+
+struct Pair;
+
+impl<A, B> ZipFn<A, B> for Pair {
+    type Output = (A, B);
+
+    fn call(&mut self, a: A, b: B) -> (A, B) {
+        (a, b)
+    }
+}
+
+struct Counted(usize);
+
+impl ZipFn<u8, u8> for Counted {
+    type Output = usize;
+
+    fn call(&mut self, a: u8, b: u8) -> usize {
+        self.0 += 1;
+        usize::from(a) * usize::from(b) + self.0
+    }
+}
+
+#[test]
+fn test_zip_with_generic_function() {
+    let zipped = (1u8, "a", true).zip_with(('x', 2.5f32, None::<u8>), Pair);
+    assert_eq!(zipped, ((1, 'x'), ("a", 2.5), (true, None)));
+}
+
+#[test]
+fn test_zip_with_stateful_function() {
+    // The function is called in positional order
+    assert_eq!((1u8, 2u8, 3u8).zip_with((4u8, 5u8, 6u8), Counted(0)), (5, 12, 21));
+}
+
+#[test]
+fn test_zip_with_single_element() {
+    assert_eq!((String::from("a"),).zip_with((1u8,), Pair), ((String::from("a"), 1),));
+}
+
+#[test]
+fn test_zip_and_zip3() {
+    use tuple_set::{Push, Zip, Zip3};
+
+    assert_eq!((1u8, "a").zip((2u16, "b")), ((1, 2), ("a", "b")));
+    assert_eq!((1u8,).zip3((2u16,), (3u32,)), ((1, 2, 3),));
+
+    let zipped = (1u8, 'a').zip3((2u8, 'b'), (3u8, 'c')).zip_with((4u8, 'd'), Push);
+    assert_eq!(zipped, ((1, 2, 3, 4), ('a', 'b', 'c', 'd')));
+
+    // Iterators keep their own `zip` with the trait in scope
+    let pairs: Vec<_> = [1, 2].iter().zip([3, 4]).collect();
+    assert_eq!(pairs, [(&1, 3), (&2, 4)]);
+}